@@ -33,10 +33,17 @@ impl<'ctx, 'load_ctx, T: Prototypical, L: Loader<T>> ProtoChildBuilder<'ctx, 'lo
     }
 
     /// Add the child with the given path to the parent.
+    ///
+    /// If the path [refers to an ID], the child is not loaded by this prototype
+    /// and is instead resolved once a prototype with that ID is registered.
+    ///
+    /// [refers to an ID]: ProtoPath::from_id
     pub fn add_child_path(&mut self, child_path: ProtoPath) -> Result<Handle<T>, L::Error> {
-        self.context
-            .child_paths_mut()
-            .push(child_path.asset_path().to_owned());
+        if child_path.id().is_none() {
+            self.context
+                .child_paths_mut()
+                .push(child_path.asset_path().to_owned());
+        }
 
         self.context.increment_index();
 
@@ -1,7 +1,7 @@
 use std::fmt::{Debug, Formatter};
 use std::slice::Iter;
 
-use crate::children::PrototypicalChild;
+use crate::children::{ChildForm, PrototypicalChild};
 use crate::proto::Prototypical;
 
 /// A collection of [children] for a [prototype].
@@ -19,6 +19,10 @@ use crate::proto::Prototypical;
 /// [`ProtoEntity`]: crate::tree::ProtoEntity
 pub struct Children<T: Prototypical> {
     children: Vec<T::Child>,
+    /// The inserted [forms], indexing into `children`.
+    ///
+    /// [forms]: ChildForm
+    forms: Vec<ChildForm<usize>>,
 }
 
 impl<T: Prototypical> Children<T> {
@@ -32,19 +36,60 @@ impl<T: Prototypical> Children<T> {
             child.handle().is_strong(),
             "child inserted with weak handle"
         );
-        self.children.push(child)
+        self.forms.push(ChildForm::Single(self.children.len()));
+        self.children.push(child);
+    }
+
+    /// Insert a new [`ChildForm`] into the collection.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any child's handle is weak.
+    pub fn insert_form(&mut self, form: ChildForm<T::Child>) {
+        let form = form.map_into(|child| {
+            debug_assert!(
+                child.handle().is_strong(),
+                "child inserted with weak handle"
+            );
+            self.children.push(child);
+            self.children.len() - 1
+        });
+        self.forms.push(form);
     }
 
     /// Iterate over the children in the order they were inserted.
+    ///
+    /// This includes every child contained in a [`ChildForm`],
+    /// regardless of whether or not it may be selected.
     pub fn iter(&self) -> Iter<'_, T::Child> {
         self.children.iter()
     }
+
+    /// Iterate over the [forms] in the order they were inserted.
+    ///
+    /// [forms]: ChildForm
+    pub fn forms(&self) -> impl Iterator<Item = ChildForm<&T::Child>> + '_ {
+        self.forms
+            .iter()
+            .map(|form| form.map(|index| &self.children[*index]))
+    }
+
+    /// The number of children, including every child contained in a [`ChildForm`].
+    pub fn len(&self) -> usize {
+        self.children.len()
+    }
+
+    /// Returns true if there are no children.
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
 }
 
 impl<T: Prototypical> Default for Children<T> {
     fn default() -> Self {
         Self {
             children: Vec::new(),
+            forms: Vec::new(),
         }
     }
 }
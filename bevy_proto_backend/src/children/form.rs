@@ -0,0 +1,311 @@
+use crate::proto::ProtoRng;
+
+/// Determines which [children] are spawned for a [prototype].
+///
+/// Most children are simply [`ChildForm::Single`], meaning they are always spawned.
+/// The remaining forms allow children to be selected randomly.
+/// These are resolved whenever an [`EntityTree`] is built, using a generator seeded
+/// from the [`ProtoRng`] resource.
+///
+/// [children]: crate::children::PrototypicalChild
+/// [prototype]: crate::proto::Prototypical
+/// [`EntityTree`]: crate::tree::EntityTree
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChildForm<C> {
+    /// Always spawn the given child.
+    Single(C),
+    /// Spawn exactly one of the given forms, chosen randomly using their weights.
+    ///
+    /// Forms with a weight of zero (or less) are never selected.
+    OneOf(Vec<(f32, ChildForm<C>)>),
+    /// Spawn the given form the given number of times.
+    Repeat(u32, Box<ChildForm<C>>),
+    /// Spawn the given form a random number of times between a minimum and maximum (inclusive).
+    Range(u32, u32, Box<ChildForm<C>>),
+    /// Spawn the given form with the given probability (between `0.0` and `1.0`).
+    Chance(f32, Box<ChildForm<C>>),
+}
+
+impl<C> ChildForm<C> {
+    /// Returns true if this is a [`ChildForm::Single`].
+    pub fn is_single(&self) -> bool {
+        matches!(self, Self::Single(_))
+    }
+
+    /// Map each child in this form, preserving its structure.
+    pub fn map<U>(&self, mut f: impl FnMut(&C) -> U) -> ChildForm<U> {
+        self.map_inner(&mut f)
+    }
+
+    /// Map each child in this form by value, preserving its structure.
+    pub fn map_into<U>(self, mut f: impl FnMut(C) -> U) -> ChildForm<U> {
+        self.map_into_inner(&mut f)
+    }
+
+    /// Map each child in this form, preserving its structure.
+    ///
+    /// Children mapped to `None` are removed along with any form that no longer
+    /// contains a child.
+    /// If no children remain, `None` is returned.
+    pub fn try_filter_map<U, E>(
+        &self,
+        mut f: impl FnMut(&C) -> Result<Option<U>, E>,
+    ) -> Result<Option<ChildForm<U>>, E> {
+        self.try_filter_map_inner(&mut f)
+    }
+
+    /// Returns true if the given form has the same structure as this one
+    /// and all of its children match according to the given function.
+    pub fn matches<U>(&self, other: &ChildForm<U>, f: impl Fn(&C, &U) -> bool) -> bool {
+        self.matches_inner(other, &f)
+    }
+
+    /// Returns true if any child in this form satisfies the given predicate.
+    pub fn any(&self, f: impl Fn(&C) -> bool) -> bool {
+        self.any_inner(&f)
+    }
+
+    /// Randomly select the children to spawn, pushing them into `selected`
+    /// in the order they should be spawned.
+    pub fn select<'a>(&'a self, rng: &mut ProtoRng, selected: &mut Vec<&'a C>) {
+        match self {
+            Self::Single(child) => selected.push(child),
+            Self::OneOf(forms) => {
+                let total: f32 = forms.iter().map(|(weight, _)| weight.max(0.0)).sum();
+                if total <= 0.0 {
+                    return;
+                }
+
+                let mut target = rng.next_f32() * total;
+                let mut last = None;
+                for (weight, form) in forms {
+                    if *weight <= 0.0 {
+                        continue;
+                    }
+
+                    if target < *weight {
+                        form.select(rng, selected);
+                        return;
+                    }
+
+                    target -= weight;
+                    last = Some(form);
+                }
+
+                // Fallback in case of floating point error
+                if let Some(form) = last {
+                    form.select(rng, selected);
+                }
+            }
+            Self::Repeat(count, form) => {
+                for _ in 0..*count {
+                    form.select(rng, selected);
+                }
+            }
+            Self::Range(min, max, form) => {
+                for _ in 0..rng.range(*min, *max) {
+                    form.select(rng, selected);
+                }
+            }
+            Self::Chance(probability, form) => {
+                if rng.next_f32() < *probability {
+                    form.select(rng, selected);
+                }
+            }
+        }
+    }
+
+    fn any_inner<F: Fn(&C) -> bool>(&self, f: &F) -> bool {
+        match self {
+            Self::Single(child) => f(child),
+            Self::OneOf(forms) => forms.iter().any(|(_, form)| form.any_inner(f)),
+            Self::Repeat(_, form) | Self::Range(_, _, form) | Self::Chance(_, form) => {
+                form.any_inner(f)
+            }
+        }
+    }
+
+    fn map_inner<U, F: FnMut(&C) -> U>(&self, f: &mut F) -> ChildForm<U> {
+        match self {
+            Self::Single(child) => ChildForm::Single(f(child)),
+            Self::OneOf(forms) => ChildForm::OneOf(
+                forms
+                    .iter()
+                    .map(|(weight, form)| (*weight, form.map_inner(f)))
+                    .collect(),
+            ),
+            Self::Repeat(count, form) => ChildForm::Repeat(*count, Box::new(form.map_inner(f))),
+            Self::Range(min, max, form) => {
+                ChildForm::Range(*min, *max, Box::new(form.map_inner(f)))
+            }
+            Self::Chance(probability, form) => {
+                ChildForm::Chance(*probability, Box::new(form.map_inner(f)))
+            }
+        }
+    }
+
+    fn map_into_inner<U, F: FnMut(C) -> U>(self, f: &mut F) -> ChildForm<U> {
+        match self {
+            Self::Single(child) => ChildForm::Single(f(child)),
+            Self::OneOf(forms) => ChildForm::OneOf(
+                forms
+                    .into_iter()
+                    .map(|(weight, form)| (weight, form.map_into_inner(f)))
+                    .collect(),
+            ),
+            Self::Repeat(count, form) => ChildForm::Repeat(count, Box::new(form.map_into_inner(f))),
+            Self::Range(min, max, form) => {
+                ChildForm::Range(min, max, Box::new(form.map_into_inner(f)))
+            }
+            Self::Chance(probability, form) => {
+                ChildForm::Chance(probability, Box::new(form.map_into_inner(f)))
+            }
+        }
+    }
+
+    fn try_filter_map_inner<U, E, F: FnMut(&C) -> Result<Option<U>, E>>(
+        &self,
+        f: &mut F,
+    ) -> Result<Option<ChildForm<U>>, E> {
+        Ok(match self {
+            Self::Single(child) => f(child)?.map(ChildForm::Single),
+            Self::OneOf(forms) => {
+                let mut mapped = Vec::with_capacity(forms.len());
+                for (weight, form) in forms {
+                    if let Some(form) = form.try_filter_map_inner(f)? {
+                        mapped.push((*weight, form));
+                    }
+                }
+
+                if mapped.is_empty() {
+                    None
+                } else {
+                    Some(ChildForm::OneOf(mapped))
+                }
+            }
+            Self::Repeat(count, form) => form
+                .try_filter_map_inner(f)?
+                .map(|form| ChildForm::Repeat(*count, Box::new(form))),
+            Self::Range(min, max, form) => form
+                .try_filter_map_inner(f)?
+                .map(|form| ChildForm::Range(*min, *max, Box::new(form))),
+            Self::Chance(probability, form) => form
+                .try_filter_map_inner(f)?
+                .map(|form| ChildForm::Chance(*probability, Box::new(form))),
+        })
+    }
+
+    fn matches_inner<U, F: Fn(&C, &U) -> bool>(&self, other: &ChildForm<U>, f: &F) -> bool {
+        match (self, other) {
+            (Self::Single(a), ChildForm::Single(b)) => f(a, b),
+            (Self::OneOf(a), ChildForm::OneOf(b)) => {
+                a.len() == b.len()
+                    && a.iter()
+                        .zip(b.iter())
+                        .all(|((weight_a, a), (weight_b, b))| {
+                            weight_a == weight_b && a.matches_inner(b, f)
+                        })
+            }
+            (Self::Repeat(count_a, a), ChildForm::Repeat(count_b, b)) => {
+                count_a == count_b && a.matches_inner(b, f)
+            }
+            (Self::Range(min_a, max_a, a), ChildForm::Range(min_b, max_b, b)) => {
+                min_a == min_b && max_a == max_b && a.matches_inner(b, f)
+            }
+            (Self::Chance(probability_a, a), ChildForm::Chance(probability_b, b)) => {
+                probability_a == probability_b && a.matches_inner(b, f)
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn select(form: &ChildForm<&'static str>, seed: u64) -> Vec<&'static str> {
+        let mut rng = ProtoRng::from_seed(seed);
+        let mut selected = Vec::new();
+        form.select(&mut rng, &mut selected);
+        selected.into_iter().copied().collect()
+    }
+
+    fn single(child: &'static str) -> Box<ChildForm<&'static str>> {
+        Box::new(ChildForm::Single(child))
+    }
+
+    #[test]
+    fn should_select_one_of() {
+        let form = ChildForm::OneOf(vec![
+            (1.0, ChildForm::Single("a")),
+            (0.0, ChildForm::Single("never")),
+            (1.0, ChildForm::Single("b")),
+        ]);
+
+        let mut seen = Vec::new();
+        for seed in 0..100 {
+            let selected = select(&form, seed);
+            assert_eq!(1, selected.len());
+            assert_ne!("never", selected[0]);
+            if !seen.contains(&selected[0]) {
+                seen.push(selected[0]);
+            }
+        }
+        assert_eq!(2, seen.len());
+
+        let empty = ChildForm::OneOf(vec![(0.0, ChildForm::Single("never"))]);
+        assert!(select(&empty, 0).is_empty());
+    }
+
+    #[test]
+    fn should_select_repeat() {
+        let form = ChildForm::Repeat(3, single("a"));
+        assert_eq!(vec!["a", "a", "a"], select(&form, 0));
+    }
+
+    #[test]
+    fn should_select_range() {
+        let form = ChildForm::Range(1, 3, single("a"));
+
+        let mut counts = Vec::new();
+        for seed in 0..100 {
+            let count = select(&form, seed).len();
+            assert!((1..=3).contains(&count));
+            if !counts.contains(&count) {
+                counts.push(count);
+            }
+        }
+        assert_eq!(3, counts.len());
+    }
+
+    #[test]
+    fn should_select_chance() {
+        let never = ChildForm::Chance(0.0, single("a"));
+        let always = ChildForm::Chance(1.0, single("a"));
+        let sometimes = ChildForm::Chance(0.5, single("a"));
+
+        let mut hits = 0;
+        for seed in 0..100 {
+            assert!(select(&never, seed).is_empty());
+            assert_eq!(vec!["a"], select(&always, seed));
+            hits += select(&sometimes, seed).len();
+        }
+        assert!(hits > 0 && hits < 100);
+    }
+
+    #[test]
+    fn should_select_same_children_for_same_seed() {
+        let form = ChildForm::Repeat(
+            5,
+            Box::new(ChildForm::OneOf(vec![
+                (1.0, ChildForm::Range(0, 2, single("a"))),
+                (1.0, ChildForm::Chance(0.5, single("b"))),
+            ])),
+        );
+
+        for seed in 0..20 {
+            assert_eq!(select(&form, seed), select(&form, seed));
+        }
+    }
+}
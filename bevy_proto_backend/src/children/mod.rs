@@ -4,8 +4,10 @@
 
 pub use builder::*;
 pub use collection::*;
+pub use form::*;
 pub use prototypical_child::*;
 
 mod builder;
 mod collection;
+mod form;
 mod prototypical_child;
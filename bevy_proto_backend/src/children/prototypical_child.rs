@@ -3,6 +3,7 @@ use std::hash::Hash;
 
 use bevy::prelude::Handle;
 
+use crate::path::ProtoPath;
 use crate::proto::Prototypical;
 
 /// The child type for a [prototype].
@@ -28,6 +29,16 @@ pub trait PrototypicalChild<T: Prototypical> {
     fn merge_key(&self) -> Option<&Self::Key> {
         None
     }
+
+    /// The path this child was referenced by, if any.
+    ///
+    /// This is used to resolve children that [refer to an ID],
+    /// so it only needs to be defined if such children are supported.
+    ///
+    /// [refer to an ID]: ProtoPath::from_id
+    fn path(&self) -> Option<&ProtoPath> {
+        None
+    }
 }
 
 /// Type alias for [`PrototypicalChild::Key`].
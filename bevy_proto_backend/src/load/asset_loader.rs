@@ -65,3 +65,60 @@ impl<T: Prototypical, L: Loader<T>, C: Config<T>> AssetLoader for ProtoAssetLoad
         self.loader.extensions()
     }
 }
+
+/// Asset loader for files containing multiple prototypes.
+///
+/// Each entry is added as its own labeled asset.
+pub(crate) struct ProtoBundleAssetLoader<T: Prototypical, L: Loader<T>, C: Config<T>>(
+    ProtoAssetLoader<T, L, C>,
+);
+
+impl<T: Prototypical, L: Loader<T>, C: Config<T>> ProtoBundleAssetLoader<T, L, C> {
+    pub fn new(loader: L, world: &mut World) -> Self {
+        Self(ProtoAssetLoader::new(loader, world))
+    }
+}
+
+impl<T: Prototypical, L: Loader<T>, C: Config<T>> AssetLoader for ProtoBundleAssetLoader<T, L, C> {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, anyhow::Result<(), anyhow::Error>> {
+        Box::pin(async {
+            let registry = self.0.registry.read();
+            let bundle_path = load_context.path().to_path_buf();
+            let mut ctx = ProtoLoadContext::<T, L>::new(&registry, &self.0.loader, load_context);
+
+            // 1. Deserialize the bundle
+            let entries = L::deserialize_bundle(bytes, &mut ctx)?;
+            // Attribute the children of the last entry
+            ctx.set_label(None);
+
+            for (label, prototype) in entries {
+                // 2. Preprocess
+                ctx.set_label(Some(label.clone()));
+                let (prototype, meta, mut dependency_paths) = ctx.preprocess_proto(prototype)?;
+                dependency_paths.append(&mut ctx.take_entry_child_paths(&label));
+                // Siblings are loaded as part of this file
+                dependency_paths.retain(|path| path.path() != bundle_path);
+
+                // 3. Register
+                self.0
+                    .proto_registry
+                    .write()
+                    .queue(prototype.id().clone(), &meta.handle);
+
+                // 4. Finish!
+                let asset = LoadedAsset::new(prototype).with_dependencies(dependency_paths);
+                ctx.set_labeled_asset(&label, asset);
+            }
+
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        self.0.loader.bundle_extensions()
+    }
+}
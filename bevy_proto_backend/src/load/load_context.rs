@@ -6,6 +6,7 @@ use std::path::Path;
 use bevy::asset::{Asset, AssetIo, AssetPath, HandleId, LoadContext, LoadedAsset};
 use bevy::prelude::Handle;
 use bevy::reflect::TypeRegistryInternal;
+use bevy::utils::HashMap;
 
 use crate::children::ProtoChildBuilder;
 use crate::deps::DependenciesBuilder;
@@ -22,7 +23,10 @@ pub struct ProtoLoadContext<'a, 'ctx, T: Prototypical, L: Loader<T>> {
     loader: &'a L,
     load_context: Option<&'a mut LoadContext<'ctx>>,
     child_paths: Vec<AssetPath<'static>>,
+    /// The child paths of each bundle entry that has been processed so far.
+    entry_child_paths: HashMap<String, Vec<AssetPath<'static>>>,
     index_path: IndexPath,
+    label: Option<String>,
     _phantom: PhantomData<T>,
 }
 
@@ -37,7 +41,9 @@ impl<'a, 'ctx, T: Prototypical, L: Loader<T>> ProtoLoadContext<'a, 'ctx, T, L> {
             loader,
             load_context: Some(load_context),
             child_paths: Vec::new(),
+            entry_child_paths: HashMap::new(),
             index_path: IndexPath::default(),
+            label: None,
             _phantom: Default::default(),
         }
     }
@@ -69,7 +75,9 @@ impl<'a, 'ctx, T: Prototypical, L: Loader<T>> ProtoLoadContext<'a, 'ctx, T, L> {
             loader: self.loader,
             load_context: self.load_context.take(),
             child_paths: Vec::new(),
+            entry_child_paths: HashMap::new(),
             index_path: IndexPath::default(),
+            label: self.label.clone(),
             _phantom: Default::default(),
         };
 
@@ -93,6 +101,33 @@ impl<'a, 'ctx, T: Prototypical, L: Loader<T>> ProtoLoadContext<'a, 'ctx, T, L> {
         self.loader
     }
 
+    /// The label of the bundle entry currently being processed, if any.
+    ///
+    /// See [`Loader::deserialize_bundle`] for details.
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// Set the label of the bundle entry currently being processed.
+    ///
+    /// The root prototype is given this label as-is,
+    /// while its inline children use it as a prefix for their own labels.
+    ///
+    /// Any children collected so far are attributed to the previous entry.
+    pub fn set_label(&mut self, label: Option<String>) {
+        let child_paths = std::mem::take(&mut self.child_paths);
+        match &self.label {
+            Some(previous) => self
+                .entry_child_paths
+                .entry(previous.clone())
+                .or_default()
+                .extend(child_paths),
+            None => self.child_paths = child_paths,
+        }
+
+        self.label = label;
+    }
+
     pub(crate) fn increment_index(&mut self) {
         self.index_path.increment();
     }
@@ -116,13 +151,19 @@ impl<'a, 'ctx, T: Prototypical, L: Loader<T>> ProtoLoadContext<'a, 'ctx, T, L> {
         &mut self.child_paths
     }
 
+    /// Takes the child paths of the bundle entry with the given label.
+    pub(crate) fn take_entry_child_paths(&mut self, label: &str) -> Vec<AssetPath<'static>> {
+        self.entry_child_paths.remove(label).unwrap_or_default()
+    }
+
     pub(crate) fn meta(&self) -> ProtoLoadMeta<T> {
-        let label = if self.index_path.is_root() {
+        let label = match (&self.label, self.index_path.is_root()) {
             // Root prototype
-            None
-        } else {
+            (label, true) => label.clone(),
             // Descendant prototype
-            Some(self.index_path.to_string())
+            (None, false) => Some(self.index_path.to_string()),
+            // Descendant prototype within a bundle
+            (Some(label), false) => Some(format!("{}/{}", label, self.index_path)),
         };
 
         let path = AssetPath::new(self.base_path().to_owned(), label);
@@ -150,6 +191,15 @@ impl<'a, 'ctx, T: Prototypical, L: Loader<T>> ProtoLoadContext<'a, 'ctx, T, L> {
             schematic.preload_dependencies(id, &mut deps)?;
         }
 
+        if let Some(variants) = prototype.variants_mut() {
+            for schematics in variants.iter_mut() {
+                for (_, schematic) in schematics.iter_mut() {
+                    let id = SchematicId::new(meta.handle.id(), schematic.type_info().type_id());
+                    schematic.preload_dependencies(id, &mut deps)?;
+                }
+            }
+        }
+
         prototype.dependencies_mut().combine(deps.build());
 
         // 2. Track prototype dependencies
@@ -162,7 +212,12 @@ impl<'a, 'ctx, T: Prototypical, L: Loader<T>> ProtoLoadContext<'a, 'ctx, T, L> {
 
         // 3. Track template dependencies
         if let Some(templates) = prototype.templates() {
-            dependency_paths.extend(templates.iter().map(|(path, _)| path.into()));
+            dependency_paths.extend(
+                templates
+                    .iter()
+                    .filter(|(path, _)| path.id().is_none())
+                    .map(|(path, _)| path.into()),
+            );
         }
 
         Ok((prototype, meta, dependency_paths))
@@ -1,5 +1,5 @@
 use crate::load::ProtoLoadContext;
-use crate::proto::Prototypical;
+use crate::proto::{ProtoLoadError, Prototypical};
 use crate::schematics::SchematicError;
 use bevy::asset::{AssetPath, Handle};
 use bevy::prelude::FromWorld;
@@ -12,7 +12,7 @@ use std::hash::{Hash, Hasher};
 /// [prototype]: Prototypical
 pub trait Loader<T: Prototypical>: FromWorld + Clone + Send + Sync + 'static {
     /// Error type returned by this loader during deserialization and loading.
-    type Error: Error + From<SchematicError> + Send + Sync;
+    type Error: Error + From<SchematicError> + From<ProtoLoadError> + Send + Sync;
 
     /// Deserialize the given slice of bytes into an instance of `T`.
     fn deserialize(bytes: &[u8], ctx: &mut ProtoLoadContext<T, Self>) -> Result<T, Self::Error>;
@@ -27,13 +27,12 @@ pub trait Loader<T: Prototypical>: FromWorld + Clone + Send + Sync + 'static {
     ///
     /// ```
     /// # use bevy::prelude::Resource;
-    /// # use bevy_proto_backend::proto::{Config, Prototypical};
+    /// # use bevy_proto_backend::proto::{Config, ProtoLoadError, Prototypical};
     /// # use bevy_proto_backend::load::{Loader, ProtoLoadContext};
-    /// # use bevy_proto_backend::schematics::SchematicError;
     /// # #[derive(Default, Clone)]
     /// struct MyPrototypeLoader;
     /// impl<T: Prototypical> Loader<T> for MyPrototypeLoader {
-    /// #  type Error = SchematicError;
+    /// #  type Error = ProtoLoadError;
     ///   fn extensions(&self) -> &[&'static str] {
     ///     &[
     ///       // Most Specific (Longest) //
@@ -51,6 +50,39 @@ pub trait Loader<T: Prototypical>: FromWorld + Clone + Send + Sync + 'static {
     /// ```
     fn extensions(&self) -> &[&'static str];
 
+    /// A list of supported extensions for [bundle] files.
+    ///
+    /// These follow the same rules as [`extensions`] and must not overlap with them.
+    ///
+    /// By default, this returns an empty list, meaning bundles are not supported.
+    ///
+    /// [bundle]: Loader::deserialize_bundle
+    /// [`extensions`]: Loader::extensions
+    fn bundle_extensions(&self) -> &[&'static str] {
+        &[]
+    }
+
+    /// Deserialize the given slice of bytes into a bundle of labeled instances of `T`.
+    ///
+    /// Each entry is registered as its own asset using the returned label
+    /// (e.g. `units/orcs.prototypes.ron#Grunt`).
+    ///
+    /// Before each entry is deserialized, [`ProtoLoadContext::set_label`] should be called
+    /// so that any of its inline children are given unique labels.
+    ///
+    /// This is only called for files matching one of the [`bundle_extensions`].
+    ///
+    /// By default, this returns [`ProtoLoadError::UnsupportedBundle`].
+    ///
+    /// [`bundle_extensions`]: Loader::bundle_extensions
+    fn deserialize_bundle(
+        bytes: &[u8],
+        ctx: &mut ProtoLoadContext<T, Self>,
+    ) -> Result<Vec<(String, T)>, Self::Error> {
+        let _ = bytes;
+        Err(ProtoLoadError::UnsupportedBundle(ctx.load_context().path().to_path_buf()).into())
+    }
+
     /// Callback for when a [prototype] is loaded.
     ///
     /// This is called right after deserialization, but before any preprocessing.
@@ -24,12 +24,17 @@ impl ProtoPath {
     ///   * `Template.prototype.ron`
     /// * Extensionless Relative Paths
     ///   * `Template`
+    /// * Labeled Paths
+    ///   * `Units.prototypes.ron#Grunt`
+    ///   * `#Grunt` (an entry within the same file)
     ///
     /// Note that "Extensionless Relative Paths" require that the extension is configured
     /// in the respective [`Config`].
     /// Its extension will be chosen based on the first match with the path context's
     /// [base path].
     ///
+    /// The path portion of a labeled path must include its extension.
+    ///
     /// [path context]: ProtoPathContext
     /// [`Config`]: crate::proto::Config
     /// [base path]: ProtoPathContext::base_path
@@ -44,10 +49,24 @@ impl ProtoPath {
         //   a. "Template.prototype.ron"
         // 4. Extensionless Name-only Relative Paths
         //   a. "Template"
+        // 5. Labeled Paths
+        //   a. "Units.prototypes.ron#Grunt"
+        //   b. "#Grunt"
 
         let path = path.as_ref();
         let base_path = ctx.base_path();
 
+        // 5
+        if let Some((path, label)) = path.to_str().and_then(|path| path.rsplit_once('#')) {
+            let path = if path.is_empty() {
+                base_path.to_path_buf()
+            } else {
+                Self::new(path, ctx)?.path().to_path_buf()
+            };
+
+            return Ok(ProtoPath(AssetPath::new(path, Some(label.to_string()))));
+        }
+
         // 1
         if path.has_root() {
             return Ok(ProtoPath::from(
@@ -85,6 +104,24 @@ impl ProtoPath {
         Err(PathError::InvalidExtension(base_path.to_path_buf()))
     }
 
+    /// Creates a [`ProtoPath`] that refers to a prototype by its ID rather than by its file.
+    ///
+    /// These paths are never loaded directly.
+    /// Instead, they resolve to whichever registered prototype has the given ID,
+    /// which allows files to be moved without breaking the prototypes that reference them.
+    pub fn from_id<I: ToString>(id: I) -> Self {
+        Self(AssetPath::new(PathBuf::new(), Some(id.to_string())))
+    }
+
+    /// The ID this path refers to, if it was created with [`ProtoPath::from_id`].
+    pub fn id(&self) -> Option<&str> {
+        if self.0.path().as_os_str().is_empty() {
+            self.0.label()
+        } else {
+            None
+        }
+    }
+
     /// Get the underlying [`AssetPath`].
     pub fn asset_path(&self) -> &AssetPath<'static> {
         &self.0
@@ -105,7 +142,9 @@ impl ProtoPath {
 
 impl Debug for ProtoPath {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        if let Some(label) = self.0.label() {
+        if let Some(id) = self.id() {
+            write!(f, "Id({:?})", id)
+        } else if let Some(label) = self.0.label() {
             write!(f, "{:?}#{}", self.0.path(), label)
         } else {
             write!(f, "{:?}", self.0.path())
@@ -220,3 +259,84 @@ impl AsRef<Path> for ProtoPath {
         self.0.path()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::asset::{AssetIo, FileAssetIo};
+
+    use super::*;
+
+    struct TestContext {
+        base_path: PathBuf,
+        io: FileAssetIo,
+    }
+
+    impl TestContext {
+        /// Create a context for a prototype at `units/Base.prototype.ron`
+        /// in an asset folder containing the given files.
+        fn new(name: &str, files: &[&str]) -> Self {
+            let folder = std::env::temp_dir()
+                .join("bevy_proto_backend_tests")
+                .join(format!("{name}-{}", std::process::id()));
+            let _ = std::fs::remove_dir_all(&folder);
+            for file in files {
+                let file = folder.join(file);
+                std::fs::create_dir_all(file.parent().unwrap()).unwrap();
+                std::fs::write(file, "").unwrap();
+            }
+
+            Self {
+                base_path: PathBuf::from("units/Base.prototype.ron"),
+                io: FileAssetIo::new(folder, &None),
+            }
+        }
+    }
+
+    impl ProtoPathContext for TestContext {
+        fn base_path(&self) -> &Path {
+            &self.base_path
+        }
+
+        fn asset_io(&self) -> &dyn AssetIo {
+            &self.io
+        }
+
+        fn extensions(&self) -> &[&'static str] {
+            &["prototype.ron"]
+        }
+    }
+
+    #[test]
+    fn should_parse_label_within_same_file() {
+        let ctx = TestContext::new("label_same_file", &[]);
+
+        let path = ProtoPath::new("#Grunt", &ctx).unwrap();
+
+        assert_eq!(Path::new("units/Base.prototype.ron"), path.path());
+        assert_eq!(Some("Grunt"), path.label());
+        assert_eq!(None, path.id());
+    }
+
+    #[test]
+    fn should_parse_label_of_other_file() {
+        let ctx = TestContext::new(
+            "label_other_file",
+            &["units/orcs.prototypes.ron", "bosses.prototypes.ron"],
+        );
+
+        let relative = ProtoPath::new("orcs.prototypes.ron#Grunt", &ctx).unwrap();
+        assert_eq!(Path::new("units/orcs.prototypes.ron"), relative.path());
+        assert_eq!(Some("Grunt"), relative.label());
+
+        let absolute = ProtoPath::new("/bosses.prototypes.ron#Warlord", &ctx).unwrap();
+        assert_eq!(Path::new("bosses.prototypes.ron"), absolute.path());
+        assert_eq!(Some("Warlord"), absolute.label());
+    }
+
+    #[test]
+    fn should_reject_label_of_missing_file() {
+        let ctx = TestContext::new("label_missing_file", &[]);
+
+        assert!(ProtoPath::new("missing.prototypes.ron#Grunt", &ctx).is_err());
+    }
+}
@@ -3,13 +3,18 @@ use std::marker::PhantomData;
 use crate::assets::ProtoAssetEvent;
 use bevy::app::{App, Plugin};
 use bevy::asset::AddAsset;
-use bevy::prelude::{FromWorld, Update};
+use bevy::prelude::{FromWorld, IntoSystemConfigs, Update};
 use parking_lot::Mutex;
 
 use crate::impls;
-use crate::load::{Loader, ProtoAssetLoader};
-use crate::proto::{Config, ProtoStorage, Prototypical};
-use crate::registration::{on_proto_asset_event, ProtoRegistry};
+use crate::load::{Loader, ProtoAssetLoader, ProtoBundleAssetLoader};
+use crate::proto::{
+    insert_deferred_prototypes, Config, ProtoDeferredInserts, ProtoRng, ProtoSpawnFailed,
+    ProtoSpawned, ProtoStorage, Prototypical,
+};
+use crate::registration::{
+    on_proto_asset_event, reload_proto_instances, ProtoInstanceCache, ProtoRegistry,
+};
 use crate::tree::{AccessOp, ChildAccess, EntityAccess, ProtoEntity};
 
 /// Plugin to add support for the given [prototype] `P`.
@@ -18,6 +23,7 @@ use crate::tree::{AccessOp, ChildAccess, EntityAccess, ProtoEntity};
 pub struct ProtoBackendPlugin<T: Prototypical, L: Loader<T>, C: Config<T>> {
     config: Mutex<Option<C>>,
     loader: Mutex<Option<L>>,
+    reload_instances: bool,
     _phantom: PhantomData<T>,
 }
 
@@ -26,6 +32,7 @@ impl<T: Prototypical, L: Loader<T>, C: Config<T>> ProtoBackendPlugin<T, L, C> {
         Self {
             config: Mutex::new(None),
             loader: Mutex::new(None),
+            reload_instances: false,
             _phantom: Default::default(),
         }
     }
@@ -41,6 +48,23 @@ impl<T: Prototypical, L: Loader<T>, C: Config<T>> ProtoBackendPlugin<T, L, C> {
         self.loader = Mutex::new(Some(loader));
         self
     }
+
+    /// Enable automatic reloading of existing [prototype] instances.
+    ///
+    /// When enabled, any entity tracked by a [`ProtoInstance`] will have its
+    /// stale schematics removed and the reloaded ones applied whenever its
+    /// prototype is [modified].
+    /// This includes the child entities of that prototype.
+    ///
+    /// This is disabled by default.
+    ///
+    /// [prototype]: Prototypical
+    /// [`ProtoInstance`]: crate::proto::ProtoInstance
+    /// [modified]: ProtoAssetEvent::Modified
+    pub fn with_instance_reloading(mut self, enabled: bool) -> Self {
+        self.reload_instances = enabled;
+        self
+    }
 }
 
 impl<T: Prototypical, L: Loader<T>, C: Config<T>> Plugin for ProtoBackendPlugin<T, L, C> {
@@ -64,7 +88,9 @@ impl<T: Prototypical, L: Loader<T>, C: Config<T>> Plugin for ProtoBackendPlugin<
         }
 
         app.init_resource::<ProtoRegistry<T, C>>()
-            .init_resource::<ProtoStorage<T>>();
+            .init_resource::<ProtoStorage<T>>()
+            .init_resource::<ProtoRng>()
+            .init_resource::<ProtoDeferredInserts<T>>();
 
         // === Assets === //
         let loader = self
@@ -72,15 +98,36 @@ impl<T: Prototypical, L: Loader<T>, C: Config<T>> Plugin for ProtoBackendPlugin<
             .lock()
             .take()
             .unwrap_or_else(|| <L as FromWorld>::from_world(&mut app.world));
+        if !loader.bundle_extensions().is_empty() {
+            let bundle_loader =
+                ProtoBundleAssetLoader::<T, L, C>::new(loader.clone(), &mut app.world);
+            app.add_asset_loader(bundle_loader);
+        }
+
         let asset_loader = ProtoAssetLoader::<T, L, C>::new(loader, &mut app.world);
 
         app.add_asset_loader(asset_loader).add_asset::<T>();
 
         // === Events === //
-        app.add_event::<ProtoAssetEvent<T>>();
+        app.add_event::<ProtoAssetEvent<T>>()
+            .add_event::<ProtoSpawned<T>>()
+            .add_event::<ProtoSpawnFailed<T>>();
 
         // === Systems === //
-        app.add_systems(Update, on_proto_asset_event::<T, C>);
+        app.add_systems(
+            Update,
+            (
+                on_proto_asset_event::<T, C>,
+                insert_deferred_prototypes::<T, C>.after(on_proto_asset_event::<T, C>),
+            ),
+        );
+
+        if self.reload_instances {
+            app.init_resource::<ProtoInstanceCache<T>>().add_systems(
+                Update,
+                reload_proto_instances::<T, C>.after(on_proto_asset_event::<T, C>),
+            );
+        }
     }
 }
 
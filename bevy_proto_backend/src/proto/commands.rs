@@ -1,13 +1,24 @@
+use std::cell::OnceCell;
 use std::marker::PhantomData;
+use std::path::PathBuf;
+use std::sync::Arc;
 
-use bevy::asset::Assets;
+use bevy::asset::{Assets, HandleId};
+use bevy::ecs::event::Events;
 use bevy::ecs::system::{Command, EntityCommands, SystemParam};
-use bevy::prelude::{Commands, Entity, Mut, World};
+use bevy::prelude::{error, AppTypeRegistry, Commands, Entity, Mut, World};
+use bevy::reflect::TypeRegistryInternal;
+use bevy::utils::HashMap;
 
-use crate::proto::{Config, Prototypical};
+use crate::proto::{
+    Config, ProtoDeferredInsertCommand, ProtoError, ProtoInstance, ProtoParams, ProtoRng,
+    ProtoSpawnError, ProtoSpawnFailed, ProtoSpawned, Prototypical,
+};
 use crate::registration::ProtoRegistry;
-use crate::schematics::{DynamicSchematic, SchematicContext, SchematicId};
-use crate::tree::EntityTreeNode;
+use crate::schematics::{
+    CompiledSchematic, DynamicSchematic, SchematicContext, SchematicError, SchematicId, Schematics,
+};
+use crate::tree::{EntityTree, EntityTreeNode, PlannedSchematic, ProtoTree, SpawnPlan};
 
 /// A system parameter similar to [`Commands`], but catered towards [prototypes].
 ///
@@ -31,6 +42,173 @@ impl<'w, 's, T: Prototypical, C: Config<T>> ProtoCommands<'w, 's, T, C> {
         entity
     }
 
+    /// Spawn the prototype with the given [ID], using the given values for its [parameters].
+    ///
+    /// Parameters not given will use the values set by inheriting prototypes
+    /// or fall back to their defaults.
+    ///
+    /// [ID]: Prototypical::id
+    /// [parameters]: Prototypical::params
+    pub fn spawn_with<I: Into<T::Id>>(
+        &mut self,
+        id: I,
+        params: impl Into<ProtoParams>,
+    ) -> ProtoEntityCommands<'w, 's, '_, T, C> {
+        let mut entity = ProtoEntityCommands::new(self.commands.spawn_empty().id(), self);
+        entity.insert_with(id, params);
+        entity
+    }
+
+    /// Spawn the prototype with the given [ID], without panicking on failure.
+    ///
+    /// If the prototype can't be spawned (e.g. it isn't registered, one of its schematics
+    /// fails, or an [`EntityAccess`] doesn't point to an existing entity),
+    /// a [`ProtoSpawnFailed`] event is sent instead.
+    ///
+    /// Note that the entity is not despawned on failure and may contain
+    /// any schematics applied before the failure occurred.
+    ///
+    /// [ID]: Prototypical::id
+    /// [`EntityAccess`]: crate::tree::EntityAccess
+    /// [`ProtoSpawnFailed`]: crate::proto::ProtoSpawnFailed
+    pub fn try_spawn<I: Into<T::Id>>(&mut self, id: I) -> ProtoEntityCommands<'w, 's, '_, T, C> {
+        let mut entity = ProtoEntityCommands::new(self.commands.spawn_empty().id(), self);
+        entity.try_insert(id);
+        entity
+    }
+
+    /// Spawn the prototype with the given [ID], using the given seed.
+    ///
+    /// The seed determines which random [children] and [variants] are selected,
+    /// so spawning with the same seed always results in the same entities.
+    /// By default, the seed is generated using the [`ProtoRng`] resource.
+    ///
+    /// [ID]: Prototypical::id
+    /// [children]: crate::children::ChildForm
+    /// [variants]: Prototypical::variants
+    pub fn spawn_seeded<I: Into<T::Id>>(
+        &mut self,
+        id: I,
+        seed: u64,
+    ) -> ProtoEntityCommands<'w, 's, '_, T, C> {
+        let mut entity = ProtoEntityCommands::new(self.commands.spawn_empty().id(), self);
+        entity.insert_seeded(id, seed);
+        entity
+    }
+
+    /// Spawn the given number of instances of the prototype with the given [ID].
+    ///
+    /// This is much faster than calling [`spawn`] repeatedly since the prototype's
+    /// schematics are only resolved once for the entire batch.
+    /// Each instance still receives its own seed, so random [children] and [variants]
+    /// are selected independently.
+    ///
+    /// Like [`Commands::spawn_batch`], this does not return the spawned entities.
+    /// They can instead be queried using their [`ProtoInstance`] component.
+    ///
+    /// [ID]: Prototypical::id
+    /// [`spawn`]: Self::spawn
+    /// [children]: crate::children::ChildForm
+    /// [variants]: Prototypical::variants
+    pub fn spawn_batch<I: Into<T::Id>>(&mut self, id: I, count: usize) {
+        self.add(ProtoBatchCommand::<T, C>::new(id.into(), count));
+    }
+
+    /// Spawn the given number of instances of the prototype with the given [ID],
+    /// using the given values for its [parameters].
+    ///
+    /// See [`spawn_batch`] for details on batching and [`spawn_with`] for details on parameters.
+    ///
+    /// [ID]: Prototypical::id
+    /// [parameters]: Prototypical::params
+    /// [`spawn_batch`]: Self::spawn_batch
+    /// [`spawn_with`]: Self::spawn_with
+    pub fn spawn_batch_with<I: Into<T::Id>>(
+        &mut self,
+        id: I,
+        count: usize,
+        params: impl Into<ProtoParams>,
+    ) {
+        self.add(ProtoBatchCommand::<T, C>::new(id.into(), count).with_params(params.into()));
+    }
+
+    /// Spawn the given number of instances of the prototype with the given [ID],
+    /// without panicking on failure.
+    ///
+    /// If the batch can't be spawned, a single [`ProtoSpawnFailed`] event is sent instead,
+    /// with no entity set.
+    /// As with [`try_spawn`], instances that were already spawned are not despawned.
+    ///
+    /// See [`spawn_batch`] for details on batching.
+    ///
+    /// [ID]: Prototypical::id
+    /// [`ProtoSpawnFailed`]: crate::proto::ProtoSpawnFailed
+    /// [`try_spawn`]: Self::try_spawn
+    /// [`spawn_batch`]: Self::spawn_batch
+    pub fn try_spawn_batch<I: Into<T::Id>>(&mut self, id: I, count: usize) {
+        self.add(ProtoBatchCommand::<T, C>::new(id.into(), count).with_fallible(true));
+    }
+
+    /// Spawn an instance of the prototype with the given [ID] for each of the given overrides.
+    ///
+    /// See [`spawn_batch`] for details on batching and
+    /// [`ProtoEntityCommands::insert_with_overrides`] for details on overrides.
+    ///
+    /// [ID]: Prototypical::id
+    /// [`spawn_batch`]: Self::spawn_batch
+    pub fn spawn_batch_with_overrides<I, O>(&mut self, id: I, overrides: O)
+    where
+        I: Into<T::Id>,
+        O: IntoIterator<Item = Schematics>,
+    {
+        self.add(ProtoBatchCommand::<T, C>::from_overrides(
+            id.into(),
+            overrides.into_iter().collect(),
+        ));
+    }
+
+    /// Spawn the prototype with the given [ID] once it's ready.
+    ///
+    /// Unlike [`spawn`], this does not panic if the prototype hasn't finished loading.
+    /// Instead, the entity is reserved immediately and the prototype is inserted
+    /// as soon as it has been registered.
+    ///
+    /// Once inserted, a [`ProtoSpawned`] event is sent.
+    /// If the prototype fails to load or be inserted, a [`ProtoSpawnFailed`] event is sent instead.
+    /// Note that prototypes are only known by ID once their file has been parsed,
+    /// so an ID that is still unknown once all prototypes have finished loading
+    /// is also considered a failure.
+    ///
+    /// [ID]: Prototypical::id
+    /// [`spawn`]: Self::spawn
+    /// [`ProtoSpawned`]: crate::proto::ProtoSpawned
+    /// [`ProtoSpawnFailed`]: crate::proto::ProtoSpawnFailed
+    pub fn spawn_when_ready<I: Into<T::Id>>(
+        &mut self,
+        id: I,
+    ) -> ProtoEntityCommands<'w, 's, '_, T, C> {
+        let mut entity = ProtoEntityCommands::new(self.commands.spawn_empty().id(), self);
+        entity.insert_when_ready(id);
+        entity
+    }
+
+    /// Load the prototype at the given path and spawn it once it's ready.
+    ///
+    /// The prototype is loaded the same way as [`PrototypesMut::load`].
+    ///
+    /// See [`spawn_when_ready`] for details.
+    ///
+    /// [`PrototypesMut::load`]: crate::proto::PrototypesMut::load
+    /// [`spawn_when_ready`]: Self::spawn_when_ready
+    pub fn spawn_path_when_ready<P: Into<PathBuf>>(
+        &mut self,
+        path: P,
+    ) -> ProtoEntityCommands<'w, 's, '_, T, C> {
+        let mut entity = ProtoEntityCommands::new(self.commands.spawn_empty().id(), self);
+        entity.insert_path_when_ready(path);
+        entity
+    }
+
     /// Spawn an empty entity.
     ///
     /// This internally calls [`Commands::spawn_empty`].
@@ -144,6 +322,94 @@ impl<'w, 's, 'a, T: Prototypical, C: Config<T>> ProtoEntityCommands<'w, 's, 'a,
         self
     }
 
+    /// Inserts the prototype with the given [ID] onto the entity, without panicking on failure.
+    ///
+    /// See [`ProtoCommands::try_spawn`] for details.
+    ///
+    /// [ID]: Prototypical::id
+    pub fn try_insert<I: Into<T::Id>>(&mut self, id: I) -> &mut Self {
+        let id = id.into();
+        self.proto_commands
+            .add(ProtoInsertCommand::<T, C>::new(id, Some(self.entity)).with_fallible(true));
+        self
+    }
+
+    /// Inserts the prototype with the given [ID] onto the entity once it's ready.
+    ///
+    /// See [`ProtoCommands::spawn_when_ready`] for details.
+    ///
+    /// [ID]: Prototypical::id
+    pub fn insert_when_ready<I: Into<T::Id>>(&mut self, id: I) -> &mut Self {
+        let id = id.into();
+        self.proto_commands
+            .add(ProtoDeferredInsertCommand::<T>::new(id, self.entity));
+        self
+    }
+
+    /// Loads the prototype at the given path and inserts it onto the entity once it's ready.
+    ///
+    /// See [`ProtoCommands::spawn_path_when_ready`] for details.
+    pub fn insert_path_when_ready<P: Into<PathBuf>>(&mut self, path: P) -> &mut Self {
+        self.proto_commands
+            .add(ProtoDeferredInsertCommand::<T>::from_path(
+                path,
+                self.entity,
+            ));
+        self
+    }
+
+    /// Inserts the prototype with the given [ID] onto the entity,
+    /// using the given values for its [parameters].
+    ///
+    /// [ID]: Prototypical::id
+    /// [parameters]: Prototypical::params
+    pub fn insert_with<I: Into<T::Id>>(
+        &mut self,
+        id: I,
+        params: impl Into<ProtoParams>,
+    ) -> &mut Self {
+        let id = id.into();
+        self.proto_commands
+            .add(ProtoInsertCommand::<T, C>::new(id, Some(self.entity)).with_params(params.into()));
+        self
+    }
+
+    /// Inserts the prototype with the given [ID] onto the entity,
+    /// replacing or patching some of its schematics for this entity only.
+    ///
+    /// Each of the given [`Schematics`] replaces the schematic of the same type
+    /// defined by the prototype (or any of its templates).
+    /// [Patches] are instead applied on top of the inherited schematic.
+    ///
+    /// Overrides only affect the root entity—not any of its children.
+    /// They are stored on its [`ProtoInstance`] so that they are applied again
+    /// when the prototype is reloaded.
+    ///
+    /// [ID]: Prototypical::id
+    /// [Patches]: DynamicSchematic::is_patch
+    pub fn insert_with_overrides<I: Into<T::Id>>(
+        &mut self,
+        id: I,
+        overrides: Schematics,
+    ) -> &mut Self {
+        let id = id.into();
+        self.proto_commands
+            .add(ProtoInsertCommand::<T, C>::new(id, Some(self.entity)).with_overrides(overrides));
+        self
+    }
+
+    /// Inserts the prototype with the given [ID] onto the entity, using the given seed.
+    ///
+    /// See [`ProtoCommands::spawn_seeded`] for details.
+    ///
+    /// [ID]: Prototypical::id
+    pub fn insert_seeded<I: Into<T::Id>>(&mut self, id: I, seed: u64) -> &mut Self {
+        let id = id.into();
+        self.proto_commands
+            .add(ProtoInsertCommand::<T, C>::new(id, Some(self.entity)).with_seed(seed));
+        self
+    }
+
     /// Removes the prototype with the given [ID] from the entity.
     ///
     /// [ID]: Prototypical::id
@@ -171,6 +437,7 @@ impl<'w, 's, 'a, T: Prototypical, C: Config<T>> ProtoEntityCommands<'w, 's, 'a,
 /// [prototype]: Prototypical
 pub struct ProtoInsertCommand<T: Prototypical, C: Config<T>> {
     data: ProtoCommandData<T, C>,
+    send_spawned: bool,
 }
 
 impl<T: Prototypical, C: Config<T>> ProtoInsertCommand<T, C> {
@@ -179,20 +446,196 @@ impl<T: Prototypical, C: Config<T>> ProtoInsertCommand<T, C> {
             data: ProtoCommandData {
                 id,
                 entity,
+                params: None,
+                overrides: None,
+                seed: None,
+                fallible: false,
                 _phantom: PhantomData,
             },
+            send_spawned: false,
         }
     }
+
+    /// Set the values used for the prototype's [parameters].
+    ///
+    /// [parameters]: Prototypical::params
+    pub fn with_params(mut self, params: ProtoParams) -> Self {
+        self.data.params = Some(params);
+        self
+    }
+
+    /// Set the schematics that replace (or patch) those of the prototype for the root entity.
+    ///
+    /// See [`ProtoEntityCommands::insert_with_overrides`] for details.
+    pub fn with_overrides(mut self, overrides: Schematics) -> Self {
+        self.data.overrides = Some(Arc::new(overrides));
+        self
+    }
+
+    /// Set the params and overrides to those the given instance was spawned with.
+    pub(crate) fn with_instance(mut self, instance: &ProtoInstance) -> Self {
+        self.data.params = instance.params().cloned();
+        self.data.overrides = instance.overrides_arc().cloned();
+        self
+    }
+
+    /// Set the seed used to select the prototype's random children and variants.
+    ///
+    /// See [`ProtoCommands::spawn_seeded`] for details.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.data.seed = Some(seed);
+        self
+    }
+
+    /// Set whether failures should send a [`ProtoSpawnFailed`] event rather than panic.
+    ///
+    /// See [`ProtoCommands::try_spawn`] for details.
+    pub fn with_fallible(mut self, fallible: bool) -> Self {
+        self.data.fallible = fallible;
+        self
+    }
+
+    /// Set whether a [`ProtoSpawned`] event should be sent once the prototype has been inserted.
+    ///
+    /// [`ProtoSpawned`]: crate::proto::ProtoSpawned
+    pub fn with_spawned_event(mut self, send_spawned: bool) -> Self {
+        self.send_spawned = send_spawned;
+        self
+    }
 }
 
 impl<T: Prototypical, C: Config<T>> Command for ProtoInsertCommand<T, C> {
-    fn apply(self, world: &mut World) {
-        self.data.assert_is_registered(world);
+    fn apply(mut self, world: &mut World) {
+        let result = self
+            .data
+            .check_is_registered(world)
+            .and_then(|_| self.data.check_entity(world))
+            .and_then(|_| {
+                self.data.track_instance(world);
 
-        self.data
-            .for_each_schematic(world, true, |schematic, id, context| {
-                schematic.apply(id, context).unwrap();
+                let root = self.data.root(world);
+                self.data
+                    .for_each_schematic(world, &[root], true, |schematic, id, context| {
+                        schematic.apply(id, context)
+                    })
             });
+
+        match (result, self.data.entity) {
+            (Err(error), _) => self.data.fail(world, error),
+            (Ok(_), Some(entity)) if self.send_spawned => {
+                world
+                    .resource_mut::<Events<ProtoSpawned<T>>>()
+                    .send(ProtoSpawned {
+                        id: self.data.id,
+                        entity,
+                    });
+            }
+            _ => {}
+        }
+    }
+}
+
+/// A [command] to spawn many instances of a [prototype] at once.
+///
+/// Unlike spawning each instance with its own [`ProtoInsertCommand`],
+/// the prototype's schematics are only resolved once for the entire batch
+/// and the root entities are spawned together using [`World::spawn_batch`].
+/// Schematics that can be [compiled] are then inserted into every instance at once,
+/// while the rest are still applied to each instance individually.
+///
+/// [command]: Command
+/// [prototype]: Prototypical
+/// [compiled]: crate::schematics::Schematic::compile
+pub struct ProtoBatchCommand<T: Prototypical, C: Config<T>> {
+    data: ProtoCommandData<T, C>,
+    count: usize,
+    overrides: Vec<Arc<Schematics>>,
+}
+
+impl<T: Prototypical, C: Config<T>> ProtoBatchCommand<T, C> {
+    /// Create a command that spawns the given number of instances.
+    pub fn new(id: T::Id, count: usize) -> Self {
+        Self {
+            data: ProtoCommandData {
+                id,
+                entity: None,
+                params: None,
+                overrides: None,
+                seed: None,
+                fallible: false,
+                _phantom: PhantomData,
+            },
+            count,
+            overrides: Vec::new(),
+        }
+    }
+
+    /// Create a command that spawns one instance for each of the given overrides.
+    ///
+    /// See [`ProtoEntityCommands::insert_with_overrides`] for details.
+    pub fn from_overrides(id: T::Id, overrides: Vec<Schematics>) -> Self {
+        let mut command = Self::new(id, overrides.len());
+        command.overrides = overrides.into_iter().map(Arc::new).collect();
+        command
+    }
+
+    /// Set the values used for the [parameters] of every instance.
+    ///
+    /// [parameters]: Prototypical::params
+    pub fn with_params(mut self, params: ProtoParams) -> Self {
+        self.data.params = Some(params);
+        self
+    }
+
+    /// Set whether failures should send a [`ProtoSpawnFailed`] event rather than panic.
+    ///
+    /// See [`ProtoCommands::try_spawn_batch`] for details.
+    pub fn with_fallible(mut self, fallible: bool) -> Self {
+        self.data.fallible = fallible;
+        self
+    }
+}
+
+impl<T: Prototypical, C: Config<T>> Command for ProtoBatchCommand<T, C> {
+    fn apply(mut self, world: &mut World) {
+        if let Err(error) = self.data.check_is_registered(world) {
+            return self.data.fail(world, error);
+        }
+
+        let handle = world
+            .resource::<ProtoRegistry<T, C>>()
+            .get_tree_by_id(&self.data.id)
+            .unwrap()
+            .handle();
+
+        let seeds: Vec<u64> = {
+            let mut rng = world.resource_mut::<ProtoRng>();
+            (0..self.count).map(|_| rng.next_u64()).collect()
+        };
+
+        let entities: Vec<Entity> = world
+            .spawn_batch(seeds.iter().enumerate().map(|(index, seed)| {
+                ProtoInstance::new(handle, 0)
+                    .with_seed(*seed)
+                    .with_params(self.data.params.clone())
+                    .with_overrides(self.overrides.get(index).cloned())
+            }))
+            .collect();
+
+        let roots: Vec<ProtoRoot> = entities
+            .into_iter()
+            .zip(seeds)
+            .enumerate()
+            .map(|(index, (entity, seed))| ProtoRoot {
+                entity: Some(entity),
+                seed,
+                overrides: self.overrides.get(index).map(Arc::as_ref),
+            })
+            .collect();
+
+        if let Err(error) = self.data.apply_batch(world, &roots) {
+            self.data.fail(world, error);
+        }
     }
 }
 
@@ -210,6 +653,10 @@ impl<T: Prototypical, C: Config<T>> ProtoRemoveCommand<T, C> {
             data: ProtoCommandData {
                 id,
                 entity,
+                params: None,
+                overrides: None,
+                seed: None,
+                fallible: false,
                 _phantom: PhantomData,
             },
         }
@@ -217,130 +664,933 @@ impl<T: Prototypical, C: Config<T>> ProtoRemoveCommand<T, C> {
 }
 
 impl<T: Prototypical, C: Config<T>> Command for ProtoRemoveCommand<T, C> {
-    fn apply(self, world: &mut World) {
-        self.data.assert_is_registered(world);
+    fn apply(mut self, world: &mut World) {
+        let result = self.data.check_is_registered(world).and_then(|_| {
+            let root = self.data.root(world);
+            self.data
+                .for_each_schematic(world, &[root], false, |schematic, id, context| {
+                    schematic.remove(id, context)
+                })?;
 
-        self.data
-            .for_each_schematic(world, false, |schematic, id, context| {
-                schematic.remove(id, context).unwrap();
-            });
+            self.data.untrack_instance(world);
+            Ok(())
+        });
+
+        if let Err(error) = result {
+            self.data.fail(world, error);
+        }
     }
 }
 
 struct ProtoCommandData<T: Prototypical, C: Config<T>> {
     id: T::Id,
     entity: Option<Entity>,
+    params: Option<ProtoParams>,
+    overrides: Option<Arc<Schematics>>,
+    seed: Option<u64>,
+    fallible: bool,
     _phantom: PhantomData<C>,
 }
 
 impl<T: Prototypical, C: Config<T>> ProtoCommandData<T, C> {
-    /// Asserts that the given prototype is registered, panicking if it isn't.
-    fn assert_is_registered(&self, world: &World) {
+    /// Checks that the given prototype is registered.
+    ///
+    /// If the ID is missing a namespace, it's resolved within the [configured namespace]
+    /// and replaced with the ID of the registered prototype.
+    ///
+    /// [configured namespace]: Config::namespace
+    fn check_is_registered(&mut self, world: &World) -> Result<(), ProtoSpawnError> {
+        let namespace = world.resource::<C>().namespace();
         let registry = world.resource::<ProtoRegistry<T, C>>();
-        let is_registered = registry.contains(&self.id);
-
-        if !is_registered {
-            if registry.load_queue().read().is_queued(&self.id) {
-                panic!(
-                    "could not apply command for prototype {:?}: is still loading (use `Prototypes::is_ready` to check load status)",
-                    &self.id
-                );
-            } else {
-                panic!(
-                    "could not apply command for prototype {:?}: is not loaded",
-                    &self.id
-                );
+
+        if let Some(id) = registry.resolve_id(&self.id, namespace) {
+            self.id = id.clone();
+            Ok(())
+        } else if registry
+            .load_queue()
+            .read()
+            .is_queued_in(&self.id, namespace)
+        {
+            Err(ProtoSpawnError::StillLoading(self.id.to_string()))
+        } else {
+            Err(ProtoSpawnError::NotLoaded(self.id.to_string()))
+        }
+    }
+
+    /// Checks that the root entity (if any) exists.
+    fn check_entity(&self, world: &World) -> Result<(), ProtoSpawnError> {
+        match self.entity {
+            Some(entity) if world.get_entity(entity).is_none() => {
+                Err(ProtoSpawnError::MissingEntity(entity))
             }
+            _ => Ok(()),
         }
     }
 
-    fn for_each_entity<F>(&self, world: &mut World, is_apply: bool, callback: F)
-    where
-        F: Fn(&EntityTreeNode, &mut SchematicContext, &Assets<T>, &mut C),
-    {
+    /// Handles an error that occurred while applying this command.
+    ///
+    /// Fallible commands send a [`ProtoSpawnFailed`] event, while all others panic.
+    fn fail(&self, world: &mut World, error: ProtoSpawnError) {
+        if !self.fallible {
+            panic!(
+                "could not apply command for prototype {:?}: {}",
+                self.id, error
+            );
+        }
+
+        world
+            .resource_mut::<Events<ProtoSpawnFailed<T>>>()
+            .send(ProtoSpawnFailed {
+                id: Some(self.id.clone()),
+                entity: self.entity,
+                error,
+            });
+    }
+
+    /// Returns the seed used to select the random children and variants of the prototype.
+    ///
+    /// If no seed was explicitly given, entities that are already tracked as an instance
+    /// reuse their existing seed so that the same children and variants are selected.
+    /// Otherwise, a new seed is generated from the [`ProtoRng`] resource.
+    fn seed(&self, world: &mut World) -> u64 {
+        self.seed
+            .or_else(|| {
+                self.entity
+                    .and_then(|entity| world.get::<ProtoInstance>(entity))
+                    .map(ProtoInstance::seed)
+            })
+            .unwrap_or_else(|| world.resource_mut::<ProtoRng>().next_u64())
+    }
+
+    /// Returns the root entity this command applies to.
+    fn root(&self, world: &mut World) -> ProtoRoot<'_> {
+        ProtoRoot {
+            entity: self.entity,
+            seed: self.seed(world),
+            overrides: self.overrides.as_deref(),
+        }
+    }
+
+    /// Marks the root entity (if any) as an instance of the given prototype.
+    ///
+    /// Any existing instance marker is replaced, keeping its seed unless a seed was explicitly given.
+    /// The params and overrides are stored so that the instance can be refreshed on reload.
+    fn track_instance(&self, world: &mut World) {
+        let Some(entity) = self.entity else {
+            return;
+        };
+
+        let seed = self.seed(world);
+        let handle = self.handle(world);
+
+        world.entity_mut(entity).insert(
+            ProtoInstance::new(handle, 0)
+                .with_seed(seed)
+                .with_params(self.params.clone())
+                .with_overrides(self.overrides.clone()),
+        );
+    }
+
+    /// Stops tracking the root entity (if any) as an instance of the given prototype.
+    ///
+    /// Entities tracked as an instance of a different prototype are left untouched.
+    fn untrack_instance(&self, world: &mut World) {
+        let Some(entity) = self.entity else {
+            return;
+        };
+
+        let handle = self.handle(world);
+        let is_tracked = world
+            .get::<ProtoInstance>(entity)
+            .is_some_and(|instance| instance.handle() == handle);
+
+        if is_tracked {
+            world.entity_mut(entity).remove::<ProtoInstance>();
+        }
+    }
+
+    /// Returns the asset handle ID of the registered prototype.
+    fn handle(&self, world: &World) -> HandleId {
+        world
+            .resource::<ProtoRegistry<T, C>>()
+            .get_tree_by_id(&self.id)
+            .unwrap()
+            .handle()
+    }
+
+    /// Runs the given function with the registered [`ProtoTree`] of the prototype,
+    /// along with the resources needed to process it.
+    fn scope<R>(
+        &self,
+        world: &mut World,
+        f: impl FnOnce(&mut World, &ProtoTree<T>, &Assets<T>, &mut C) -> R,
+    ) -> R {
         world.resource_scope(|world: &mut World, registry: Mut<ProtoRegistry<T, C>>| {
             world.resource_scope(|world: &mut World, mut config: Mut<C>| {
                 world.resource_scope(|world, prototypes: Mut<Assets<T>>| {
-                    let entity_tree = registry
-                        .get_tree_by_id(&self.id)
-                        .unwrap()
-                        .to_entity_tree(self.entity, world);
-
-                    for node in entity_tree.iter() {
-                        entity_tree.set_current(node);
-
-                        let mut context = SchematicContext::new(world, &entity_tree);
-
-                        #[cfg(feature = "auto_name")]
-                        if let Some(mut entity) = context.entity_mut() {
-                            if is_apply && !entity.contains::<bevy::core::Name>() {
-                                entity.insert(bevy::core::Name::new(format!(
-                                    "{} (Prototype)",
-                                    node.id()
-                                )));
-                            }
-                        }
-
-                        callback(node, &mut context, &prototypes, &mut config);
-                    }
+                    let tree = registry.get_tree_by_id(&self.id).unwrap();
+                    f(world, tree, &prototypes, &mut config)
                 })
             })
-        });
+        })
+    }
+
+    /// Creates the [`SchematicContext`] for the given node of the given tree.
+    fn context<'a, 'b>(
+        &self,
+        world: &'a mut World,
+        entity_tree: &'a EntityTree<'b>,
+        node: &EntityTreeNode,
+        is_apply: bool,
+    ) -> SchematicContext<'a, 'b> {
+        entity_tree.set_current(node);
+
+        let mut context = SchematicContext::new(world, entity_tree).with_fallible(self.fallible);
+
+        #[cfg(feature = "auto_name")]
+        if let Some(mut entity) = context.entity_mut() {
+            if is_apply && !entity.contains::<bevy::core::Name>() {
+                entity.insert(bevy::core::Name::new(format!("{} (Prototype)", node.id())));
+            }
+        }
+
+        context
+    }
+
+    fn for_each_entity<F>(
+        &self,
+        world: &mut World,
+        roots: &[ProtoRoot],
+        is_apply: bool,
+        callback: F,
+    ) -> Result<(), ProtoSpawnError>
+    where
+        F: Fn(
+            &ProtoRoot,
+            &EntityTreeNode,
+            &mut SchematicContext,
+            &Assets<T>,
+            &mut C,
+        ) -> Result<(), ProtoSpawnError>,
+    {
+        self.scope(world, |world, tree, prototypes, config| {
+            for root in roots {
+                let entity_tree = tree.to_entity_tree(root.entity, root.seed, world);
+
+                for node in entity_tree.iter() {
+                    let mut context = self.context(world, &entity_tree, node, is_apply);
+                    callback(root, node, &mut context, prototypes, config)?;
+                }
+            }
+
+            Ok(())
+        })
     }
 
-    /// Helper function to loop over the [schematics] for the given [prototype] and entity.
+    /// Helper function to loop over the [schematics] for the given [prototype] and entities.
+    ///
+    /// The schematics of each node are taken from its [`SpawnPlan`],
+    /// which is compiled the first time its tree is spawned.
+    ///
+    /// When applying, [compiled] schematics are inserted directly instead of
+    /// being passed to the callback.
     ///
     /// [schematics]: DynamicSchematic
     /// [prototype]: Prototypical
-    fn for_each_schematic<F>(&self, world: &mut World, is_apply: bool, callback: F)
+    /// [compiled]: crate::schematics::Schematic::compile
+    fn for_each_schematic<F>(
+        &self,
+        world: &mut World,
+        roots: &[ProtoRoot],
+        is_apply: bool,
+        callback: F,
+    ) -> Result<(), ProtoSpawnError>
     where
-        F: Fn(&DynamicSchematic, SchematicId, &mut SchematicContext),
+        F: Fn(&DynamicSchematic, SchematicId, &mut SchematicContext) -> Result<(), SchematicError>,
     {
-        self.for_each_entity(world, is_apply, |node, context, prototypes, config| {
-            let on_before_prototype = if is_apply {
-                Config::<T>::on_before_apply_prototype
-            } else {
-                Config::<T>::on_before_remove_prototype
-            };
-            let on_after_prototype = if is_apply {
-                Config::<T>::on_after_apply_prototype
-            } else {
-                Config::<T>::on_after_remove_prototype
-            };
-            let on_before_schematic = if is_apply {
-                Config::<T>::on_before_apply_schematic
-            } else {
-                Config::<T>::on_before_remove_schematic
-            };
-            let on_after_schematic = if is_apply {
-                Config::<T>::on_after_apply_schematic
-            } else {
-                Config::<T>::on_after_remove_schematic
+        let type_registry = world.resource::<AppTypeRegistry>().clone();
+        let hooks = Hooks::<T, C>::new(is_apply);
+        // Spawn-time params only apply to the root node,
+        // so its plan can only be shared within this command
+        let root_plan = OnceCell::new();
+
+        self.for_each_entity(
+            world,
+            roots,
+            is_apply,
+            |root, node, context, prototypes, config| {
+                let plan = self.plan(node, &root_plan, prototypes, &type_registry);
+                let steps = StepProcessor {
+                    root,
+                    node,
+                    plan,
+                    prototypes,
+                    hooks: &hooks,
+                    callback: &callback,
+                };
+
+                for (handle_id, schematics) in plan.iter() {
+                    steps.begin(handle_id, context, config)?;
+                    steps.process_all(handle_id, schematics, context, config)?;
+                    steps.finish(handle_id, context, config)?;
+                }
+
+                Ok(())
+            },
+        )
+    }
+
+    /// Applies the prototype to all of the given roots at once.
+    ///
+    /// The nodes of every tree that share the same [`SpawnPlan`] are processed together,
+    /// so that their [compiled] schematics can be inserted into all of their entities
+    /// at once using [`CompiledSchematic::insert_batch`].
+    /// All other schematics are applied to each entity individually.
+    ///
+    /// Schematics are still processed in the same order as when spawning individually:
+    /// each run of consecutive compiled schematics is inserted into every entity at once
+    /// before the schematics that follow it are applied.
+    ///
+    /// [compiled]: crate::schematics::Schematic::compile
+    fn apply_batch(&self, world: &mut World, roots: &[ProtoRoot]) -> Result<(), ProtoSpawnError> {
+        let type_registry = world.resource::<AppTypeRegistry>().clone();
+        let hooks = Hooks::<T, C>::new(true);
+        let callback =
+            |schematic: &DynamicSchematic, id: SchematicId, context: &mut SchematicContext| {
+                schematic.apply(id, context)
             };
+        let root_plan = OnceCell::new();
+
+        self.scope(world, |world, tree, prototypes, config| {
+            let entity_trees = roots
+                .iter()
+                .map(|root| tree.to_entity_tree(root.entity, root.seed, world))
+                .collect::<Vec<_>>();
+
+            // Group the nodes of every tree by their plan, keeping the order they are processed in
+            let mut groups: Vec<(&SpawnPlan, Vec<(usize, &EntityTreeNode)>)> = Vec::new();
+            for (index, entity_tree) in entity_trees.iter().enumerate() {
+                for node in entity_tree.iter() {
+                    let plan = self.plan(node, &root_plan, prototypes, &type_registry);
+                    match groups
+                        .iter_mut()
+                        .find(|(group_plan, _)| std::ptr::eq(*group_plan, plan))
+                    {
+                        Some((_, members)) => members.push((index, node)),
+                        None => groups.push((plan, vec![(index, node)])),
+                    }
+                }
+            }
+
+            for (plan, members) in &groups {
+                let plan: &SpawnPlan = plan;
+                let steps = members
+                    .iter()
+                    .map(|(index, node)| StepProcessor {
+                        root: &roots[*index],
+                        node,
+                        plan,
+                        prototypes,
+                        hooks: &hooks,
+                        callback: &callback,
+                    })
+                    .collect::<Vec<_>>();
+
+                for (handle_id, schematics) in plan.iter() {
+                    for (step, (index, node)) in steps.iter().zip(members) {
+                        let mut context = self.context(world, &entity_trees[*index], node, true);
+                        step.begin(handle_id, &mut context, config)?;
+                    }
+
+                    let proto = prototypes.get(&prototypes.get_handle(*handle_id)).unwrap();
+
+                    for run in compiled_runs(schematics) {
+                        let is_compiled = run[0].compiled.is_some();
+
+                        for planned in run {
+                            let (Some(compiled), Some(schematic)) =
+                                (&planned.compiled, planned.get(proto))
+                            else {
+                                continue;
+                            };
+
+                            let id = SchematicId::new(*handle_id, schematic.type_info().type_id());
+
+                            let targets = steps
+                                .iter()
+                                .zip(members)
+                                .filter(|(step, (_, node))| {
+                                    node.entity().is_some()
+                                        && !step.is_overridden(&planned.type_name)
+                                })
+                                .map(|(_, member)| member)
+                                .collect::<Vec<_>>();
+
+                            for (index, node) in &targets {
+                                let mut context =
+                                    self.context(world, &entity_trees[*index], node, true);
+                                (hooks.before_schematic)(
+                                    config,
+                                    schematic,
+                                    id.clone(),
+                                    &mut context,
+                                );
+                            }
+
+                            let entities = targets
+                                .iter()
+                                .filter_map(|(_, node)| node.entity())
+                                .collect::<Vec<_>>();
+                            compiled.insert_batch(&entities, world).map_err(|error| {
+                                ProtoSpawnError::Schematic {
+                                    type_name: schematic.type_info().type_name().to_string(),
+                                    error,
+                                }
+                            })?;
+
+                            for (index, node) in &targets {
+                                let mut context =
+                                    self.context(world, &entity_trees[*index], node, true);
+                                (hooks.after_schematic)(
+                                    config,
+                                    schematic,
+                                    id.clone(),
+                                    &mut context,
+                                );
+                            }
+                        }
+
+                        // Everything else is applied individually,
+                        // including compiled schematics for nodes without an entity
+                        for (step, (index, node)) in steps.iter().zip(members) {
+                            if is_compiled && node.entity().is_some() {
+                                continue;
+                            }
+
+                            let mut context =
+                                self.context(world, &entity_trees[*index], node, true);
+                            step.process_all(handle_id, run, &mut context, config)?;
+                        }
+                    }
 
-            for handle_id in node.prototypes() {
+                    for (step, (index, node)) in steps.iter().zip(members) {
+                        let mut context = self.context(world, &entity_trees[*index], node, true);
+                        step.finish(handle_id, &mut context, config)?;
+                    }
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Returns the [`SpawnPlan`] for the given node, compiling it if necessary.
+    fn plan<'a>(
+        &self,
+        node: &EntityTreeNode<'a>,
+        root_plan: &'a OnceCell<SpawnPlan>,
+        prototypes: &Assets<T>,
+        type_registry: &AppTypeRegistry,
+    ) -> &'a SpawnPlan {
+        let compile = || self.compile_plan(node, prototypes, &type_registry.read());
+        if node.is_root() && self.params.is_some() {
+            root_plan.get_or_init(compile)
+        } else {
+            node.plan().get_or_init(compile)
+        }
+    }
+
+    /// Compiles the [`SpawnPlan`] for the given node.
+    ///
+    /// The selected variant and overrides are not included since they may differ between instances.
+    fn compile_plan(
+        &self,
+        node: &EntityTreeNode,
+        prototypes: &Assets<T>,
+        type_registry: &TypeRegistryInternal,
+    ) -> SpawnPlan {
+        let spawn_params = self.params.as_ref().filter(|_| node.is_root());
+        let params = resolve_params(node, prototypes, spawn_params);
+        let mut variants = HashMap::new();
+
+        let steps = node
+            .prototypes()
+            .map(|handle_id| {
                 let handle = prototypes.get_handle(*handle_id);
                 let proto = prototypes.get(&handle).unwrap();
 
-                if proto.requires_entity() && context.entity().is_none() {
-                    panic!(
-                        "could not apply command for prototype {:?}: requires entity",
-                        proto.id()
-                    );
+                let substituted = params.get(handle_id).and_then(|params| {
+                    proto
+                        .schematics_with_params(params, type_registry)
+                        .unwrap_or_else(|err| {
+                            error!("{}", err);
+                            None
+                        })
+                });
+
+                let schematics: Vec<_> = match substituted {
+                    Some((schematics, substituted_variants)) => {
+                        if let Some(substituted_variants) = substituted_variants {
+                            variants.insert(*handle_id, substituted_variants);
+                        }
+
+                        schematics
+                            .into_iter()
+                            .map(|(type_name, schematic)| (type_name, Some(schematic)))
+                            .collect()
+                    }
+                    None => proto
+                        .schematics()
+                        .iter()
+                        .map(|(type_name, _)| (type_name.clone(), None))
+                        .collect(),
+                };
+
+                let schematics: Vec<PlannedSchematic> = schematics
+                    .into_iter()
+                    .filter(|(type_name, _)| !node.is_removed(handle_id, type_name))
+                    .filter_map(|(type_name, schematic)| {
+                        let source = schematic
+                            .as_ref()
+                            .or_else(|| proto.schematics().get_by_name(&type_name))?;
+
+                        let schematic = if source.is_patch() {
+                            match resolve_patch(
+                                node,
+                                prototypes,
+                                Some(*handle_id),
+                                source,
+                                &type_name,
+                            ) {
+                                Ok(patched) => Some(patched),
+                                Err(err) => {
+                                    error!(
+                                        "could not apply patch for schematic {:?} in prototype {:?}: {}",
+                                        type_name,
+                                        proto.id(),
+                                        err
+                                    );
+                                    return None;
+                                }
+                            }
+                        } else {
+                            schematic
+                        };
+
+                        let mut planned = PlannedSchematic {
+                            type_name,
+                            schematic,
+                            compiled: None,
+                        };
+
+                        let compiled = planned.get(proto).and_then(|source| {
+                            let id = SchematicId::new(*handle_id, source.type_info().type_id());
+                            source.compile(id).unwrap_or_else(|err| {
+                                error!(
+                                    "could not compile schematic {:?} in prototype {:?}: {}",
+                                    planned.type_name,
+                                    proto.id(),
+                                    err
+                                );
+                                None
+                            })
+                        });
+                        planned.compiled = compiled;
+
+                        Some(planned)
+                    })
+                    .collect();
+
+                (*handle_id, schematics)
+            })
+            .collect();
+
+        SpawnPlan::new(steps, variants)
+    }
+}
+
+/// A root entity that a [`ProtoCommandData`] is applied to.
+struct ProtoRoot<'a> {
+    entity: Option<Entity>,
+    seed: u64,
+    overrides: Option<&'a Schematics>,
+}
+
+/// The [`Config`] callbacks used while applying or removing a prototype.
+struct Hooks<T: Prototypical, C: Config<T>> {
+    is_apply: bool,
+    before_prototype: fn(&mut C, &T, &mut SchematicContext),
+    after_prototype: fn(&mut C, &T, &mut SchematicContext),
+    before_schematic: fn(&mut C, &DynamicSchematic, SchematicId, &mut SchematicContext),
+    after_schematic: fn(&mut C, &DynamicSchematic, SchematicId, &mut SchematicContext),
+}
+
+impl<T: Prototypical, C: Config<T>> Hooks<T, C> {
+    fn new(is_apply: bool) -> Self {
+        if is_apply {
+            Self {
+                is_apply,
+                before_prototype: C::on_before_apply_prototype,
+                after_prototype: C::on_after_apply_prototype,
+                before_schematic: C::on_before_apply_schematic,
+                after_schematic: C::on_after_apply_schematic,
+            }
+        } else {
+            Self {
+                is_apply,
+                before_prototype: C::on_before_remove_prototype,
+                after_prototype: C::on_after_remove_prototype,
+                before_schematic: C::on_before_remove_schematic,
+                after_schematic: C::on_after_remove_schematic,
+            }
+        }
+    }
+}
+
+/// Processes the steps of a node's [`SpawnPlan`] for a single root.
+///
+/// Each step corresponds to one of the node's prototypes and is processed in two parts:
+/// [`begin`](Self::begin) checks the prototype and calls its `before` hook,
+/// while [`finish`](Self::finish) processes its schematics and calls its `after` hook.
+struct StepProcessor<'a, 'tree, T: Prototypical, C: Config<T>, F> {
+    root: &'a ProtoRoot<'a>,
+    node: &'a EntityTreeNode<'tree>,
+    plan: &'a SpawnPlan,
+    prototypes: &'a Assets<T>,
+    hooks: &'a Hooks<T, C>,
+    callback: &'a F,
+}
+
+impl<'a, 'tree, T: Prototypical, C: Config<T>, F> StepProcessor<'a, 'tree, T, C, F>
+where
+    F: Fn(&DynamicSchematic, SchematicId, &mut SchematicContext) -> Result<(), SchematicError>,
+{
+    fn prototype(&self, handle_id: &HandleId) -> &'a T {
+        self.prototypes
+            .get(&self.prototypes.get_handle(*handle_id))
+            .unwrap()
+    }
+
+    /// The overrides for this node, if any.
+    fn overrides(&self) -> Option<&'a Schematics> {
+        // Overrides only apply to the root entity
+        self.root.overrides.filter(|_| self.node.is_root())
+    }
+
+    /// Returns true if the schematic with the given type name is overridden for this node.
+    fn is_overridden(&self, type_name: &str) -> bool {
+        self.overrides()
+            .is_some_and(|overrides| overrides.contains_by_name(type_name))
+    }
+
+    /// Begin processing the prototype with the given handle.
+    fn begin(
+        &self,
+        handle_id: &HandleId,
+        context: &mut SchematicContext,
+        config: &mut C,
+    ) -> Result<(), ProtoSpawnError> {
+        let proto = self.prototype(handle_id);
+
+        if proto.requires_entity() && context.entity().is_none() {
+            return Err(ProtoError::RequiresEntity {
+                id: proto.id().to_string(),
+            }
+            .into());
+        }
+
+        (self.hooks.before_prototype)(config, proto, context);
+        Ok(())
+    }
+
+    /// Process the given schematics of the prototype with the given handle in order.
+    fn process_all(
+        &self,
+        handle_id: &HandleId,
+        schematics: &[PlannedSchematic],
+        context: &mut SchematicContext,
+        config: &mut C,
+    ) -> Result<(), ProtoSpawnError> {
+        let proto = self.prototype(handle_id);
+
+        for planned in schematics {
+            if self.is_overridden(&planned.type_name) {
+                continue;
+            }
+
+            let Some(schematic) = planned.get(proto) else {
+                continue;
+            };
+
+            let id = SchematicId::new(*handle_id, schematic.type_info().type_id());
+
+            match &planned.compiled {
+                Some(compiled) if self.hooks.is_apply && context.entity().is_some() => {
+                    self.insert(compiled, schematic, id, context, config)?;
                 }
+                _ => self.process(schematic, id, context, config)?,
+            }
+        }
 
-                on_before_prototype(config, proto, context);
+        Ok(())
+    }
 
-                for (_, schematic) in proto.schematics().iter() {
-                    let id = SchematicId::new(*handle_id, schematic.type_info().type_id());
+    /// Finish processing the prototype with the given handle,
+    /// including its selected variant and any overrides.
+    fn finish(
+        &self,
+        handle_id: &HandleId,
+        context: &mut SchematicContext,
+        config: &mut C,
+    ) -> Result<(), ProtoSpawnError> {
+        let proto = self.prototype(handle_id);
 
-                    on_before_schematic(config, schematic, id.clone(), context);
-                    callback(schematic, id.clone(), context);
-                    on_after_schematic(config, schematic, id.clone(), context);
+        // The selected variant is applied on top of the prototype's own schematics
+        let variant = self.node.variant(handle_id).and_then(|index| {
+            self.plan
+                .variants(handle_id)
+                .or_else(|| proto.variants())?
+                .get(index)
+        });
+
+        for (type_name, schematic) in variant.into_iter().flat_map(Schematics::iter) {
+            if self.node.is_removed(handle_id, type_name) || self.is_overridden(type_name) {
+                continue;
+            }
+
+            let id = SchematicId::new(*handle_id, schematic.type_info().type_id());
+
+            let patched;
+            let schematic = if schematic.is_patch() {
+                match resolve_patch(
+                    self.node,
+                    self.prototypes,
+                    Some(*handle_id),
+                    schematic,
+                    type_name,
+                ) {
+                    Ok(schematic) => {
+                        patched = schematic;
+                        &patched
+                    }
+                    Err(err) => {
+                        error!(
+                            "could not apply patch for schematic {:?} in prototype {:?}: {}",
+                            type_name,
+                            proto.id(),
+                            err
+                        );
+                        continue;
+                    }
                 }
+            } else {
+                schematic
+            };
+
+            self.process(schematic, id, context, config)?;
+        }
+
+        // Overrides are applied as part of the root prototype
+        let root_handle_id = self.node.prototypes().next_back();
+        if let Some(overrides) = self
+            .overrides()
+            .filter(|_| Some(handle_id) == root_handle_id)
+        {
+            for (type_name, schematic) in overrides.iter() {
+                let id = SchematicId::new(*handle_id, schematic.type_info().type_id());
 
-                on_after_prototype(config, proto, context);
+                let patched;
+                let schematic = if schematic.is_patch() {
+                    match resolve_patch(self.node, self.prototypes, None, schematic, type_name) {
+                        Ok(schematic) => {
+                            patched = schematic;
+                            &patched
+                        }
+                        Err(err) => {
+                            error!(
+                                "could not apply override for schematic {:?} in prototype {:?}: {}",
+                                type_name,
+                                proto.id(),
+                                err
+                            );
+                            continue;
+                        }
+                    }
+                } else {
+                    schematic
+                };
+
+                self.process(schematic, id, context, config)?;
             }
+        }
+
+        (self.hooks.after_prototype)(config, proto, context);
+        Ok(())
+    }
+
+    /// Insert a copy of a [compiled] schematic into the current entity, along with its hooks.
+    ///
+    /// [compiled]: crate::schematics::Schematic::compile
+    fn insert(
+        &self,
+        compiled: &CompiledSchematic,
+        schematic: &DynamicSchematic,
+        id: SchematicId,
+        context: &mut SchematicContext,
+        config: &mut C,
+    ) -> Result<(), ProtoSpawnError> {
+        (self.hooks.before_schematic)(config, schematic, id.clone(), context);
+        if let Some(mut entity) = context.entity_mut() {
+            compiled
+                .insert(&mut entity)
+                .map_err(|error| ProtoSpawnError::Schematic {
+                    type_name: schematic.type_info().type_name().to_string(),
+                    error,
+                })?;
+        }
+        (self.hooks.after_schematic)(config, schematic, id, context);
+        Ok(())
+    }
+
+    /// Process a single schematic using the callback, along with its hooks.
+    fn process(
+        &self,
+        schematic: &DynamicSchematic,
+        id: SchematicId,
+        context: &mut SchematicContext,
+        config: &mut C,
+    ) -> Result<(), ProtoSpawnError> {
+        (self.hooks.before_schematic)(config, schematic, id.clone(), context);
+        (self.callback)(schematic, id.clone(), context)
+            .and_then(|_| context.take_error().map_or(Ok(()), Err))
+            .map_err(|error| ProtoSpawnError::Schematic {
+                type_name: schematic.type_info().type_name().to_string(),
+                error,
+            })?;
+        (self.hooks.after_schematic)(config, schematic, id, context);
+        Ok(())
+    }
+}
+
+/// Split the given schematics into runs of consecutive schematics that are either
+/// all [compiled] or all not compiled, preserving their order.
+///
+/// [compiled]: crate::schematics::Schematic::compile
+fn compiled_runs(schematics: &[PlannedSchematic]) -> impl Iterator<Item = &[PlannedSchematic]> {
+    let mut rest = schematics;
+    std::iter::from_fn(move || {
+        let is_compiled = rest.first()?.compiled.is_some();
+        let len = rest
+            .iter()
+            .position(|planned| planned.compiled.is_some() != is_compiled)
+            .unwrap_or(rest.len());
+        let (run, next) = rest.split_at(len);
+        rest = next;
+        Some(run)
+    })
+}
+
+/// Resolve the [patch] for the schematic with the given [type name] in the given prototype.
+///
+/// This applies the patch (and any patches before it) on top of the closest
+/// full version of the schematic that comes before it in the node's application order.
+///
+/// If no prototype is given, the patch is applied after all prototypes in the node.
+///
+/// [patch]: DynamicSchematic::is_patch
+/// [type name]: std::any::type_name
+fn resolve_patch<T: Prototypical>(
+    node: &EntityTreeNode,
+    prototypes: &Assets<T>,
+    handle_id: Option<HandleId>,
+    patch: &DynamicSchematic,
+    type_name: &str,
+) -> Result<DynamicSchematic, SchematicError> {
+    let mut resolved: Option<DynamicSchematic> = None;
+
+    for current in node.prototypes() {
+        let schematic = if Some(*current) == handle_id {
+            Some(patch)
+        } else {
+            prototypes
+                .get(&prototypes.get_handle(*current))
+                .and_then(|proto| proto.schematics().get_by_name(type_name))
+                .filter(|_| !node.is_removed(current, type_name))
+        };
+
+        if let Some(schematic) = schematic {
+            resolved = Some(match resolved {
+                Some(base) if schematic.is_patch() => base.with_patch(schematic)?,
+                _ => schematic.try_clone()?,
+            });
+        }
+
+        if Some(*current) == handle_id {
+            break;
+        }
+    }
+
+    if handle_id.is_none() {
+        resolved = Some(match resolved {
+            Some(base) => base.with_patch(patch)?,
+            None => patch.try_clone()?,
         });
     }
+
+    resolved
+        .ok_or(SchematicError::FromReflectFail)?
+        .try_resolve()
+}
+
+/// Resolve the [parameter] values for each prototype in the given node.
+///
+/// Values given at spawn time take precedence, followed by those passed down by
+/// inheriting prototypes (closer ones first), and finally the declared defaults.
+///
+/// Only prototypes whose resolved values differ from their defaults are returned.
+///
+/// [parameter]: Prototypical::params
+fn resolve_params<T: Prototypical>(
+    node: &EntityTreeNode,
+    prototypes: &Assets<T>,
+    spawn_params: Option<&ProtoParams>,
+) -> HashMap<HandleId, ProtoParams> {
+    let mut resolved = HashMap::new();
+    let mut inherited = ProtoParams::default();
+
+    // Iterate from this prototype to its furthest template
+    for handle_id in node.prototypes().rev() {
+        let Some(proto) = prototypes.get(&prototypes.get_handle(*handle_id)) else {
+            continue;
+        };
+
+        if let Some(defaults) = proto.params() {
+            let mut params = defaults.clone();
+            for (name, _) in defaults.iter() {
+                let value = spawn_params
+                    .and_then(|params| params.get(name))
+                    .or_else(|| inherited.get(name));
+
+                if let Some(value) = value {
+                    params.insert(name.clone(), value.clone());
+                }
+            }
+
+            if &params != defaults {
+                resolved.insert(*handle_id, params);
+            }
+        }
+
+        if let Some(templates) = proto.templates() {
+            for (path, _) in templates.iter() {
+                for (name, value) in proto.template_params(path).into_iter().flatten() {
+                    if !inherited.contains(name) {
+                        inherited.insert(name.clone(), value.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    resolved
 }
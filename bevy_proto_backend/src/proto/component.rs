@@ -1,15 +1,37 @@
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
 use bevy::asset::HandleId;
 use bevy::prelude::Component;
 
+use crate::proto::ProtoParams;
+use crate::schematics::Schematics;
+
 /// A component used to track existing entities spawned via a [prototype].
 ///
+/// This is inserted on the root entity of a spawned prototype as well as
+/// on each of its child entities.
+///
+/// Root entities also keep the [parameters] and [overrides] they were spawned with,
+/// so that they can be spawned the same way again when their prototype is reloaded.
+///
+/// Two instances are considered equal if they share the same prototype, child index, and seed.
+///
 /// [prototype]: crate::proto::Prototypical
-#[derive(Component, Clone, Hash, PartialEq, Eq)]
+/// [parameters]: crate::proto::Prototypical::params
+/// [overrides]: crate::proto::ProtoEntityCommands::insert_with_overrides
+#[derive(Component, Clone)]
 pub struct ProtoInstance {
     /// Used to identify the prototype.
     handle: HandleId,
     /// Used to indicate the child index within the parent.
     child_index: usize,
+    /// Used to select the random children and variants of this entity.
+    seed: u64,
+    /// The parameters given when this entity was spawned (if any).
+    params: Option<ProtoParams>,
+    /// The overrides given when this entity was spawned (if any).
+    overrides: Option<Arc<Schematics>>,
 }
 
 impl ProtoInstance {
@@ -17,6 +39,84 @@ impl ProtoInstance {
         Self {
             handle,
             child_index,
+            seed: 0,
+            params: None,
+            overrides: None,
         }
     }
+
+    pub(crate) fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub(crate) fn with_params(mut self, params: Option<ProtoParams>) -> Self {
+        self.params = params;
+        self
+    }
+
+    pub(crate) fn with_overrides(mut self, overrides: Option<Arc<Schematics>>) -> Self {
+        self.overrides = overrides;
+        self
+    }
+
+    /// The asset handle ID of the prototype this entity was spawned from.
+    pub fn handle(&self) -> HandleId {
+        self.handle
+    }
+
+    /// The index of this entity among its siblings.
+    ///
+    /// This will be `0` for root entities.
+    pub fn child_index(&self) -> usize {
+        self.child_index
+    }
+
+    /// The seed used to select the random [children] and [variants] of this entity.
+    ///
+    /// [children]: crate::children::ChildForm
+    /// [variants]: crate::schematics::Variants
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// The [parameters] this entity was spawned with.
+    ///
+    /// This is always `None` for child entities.
+    ///
+    /// [parameters]: crate::proto::Prototypical::params
+    pub fn params(&self) -> Option<&ProtoParams> {
+        self.params.as_ref()
+    }
+
+    /// The [overrides] this entity was spawned with.
+    ///
+    /// This is always `None` for child entities.
+    ///
+    /// [overrides]: crate::proto::ProtoEntityCommands::insert_with_overrides
+    pub fn overrides(&self) -> Option<&Schematics> {
+        self.overrides.as_deref()
+    }
+
+    pub(crate) fn overrides_arc(&self) -> Option<&Arc<Schematics>> {
+        self.overrides.as_ref()
+    }
+}
+
+impl PartialEq for ProtoInstance {
+    fn eq(&self, other: &Self) -> bool {
+        self.handle == other.handle
+            && self.child_index == other.child_index
+            && self.seed == other.seed
+    }
+}
+
+impl Eq for ProtoInstance {}
+
+impl Hash for ProtoInstance {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.handle.hash(state);
+        self.child_index.hash(state);
+        self.seed.hash(state);
+    }
 }
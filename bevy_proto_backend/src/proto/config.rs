@@ -2,7 +2,7 @@ use bevy::asset::Handle;
 use bevy::prelude::{FromWorld, Resource};
 
 use crate::cycles::{Cycle, CycleResponse};
-use crate::proto::Prototypical;
+use crate::proto::{OverridePolicy, Prototypical};
 use crate::schematics::{DynamicSchematic, SchematicContext, SchematicId};
 
 /// Configuration for a [prototype].
@@ -137,6 +137,41 @@ pub trait Config<T: Prototypical>: Resource + FromWorld {
     ) {
     }
 
+    /// The namespace used to resolve unqualified [prototype] IDs.
+    ///
+    /// When set, lookups such as [`ProtoCommands::spawn`] and [`Prototypes::is_ready`]
+    /// first try an ID without a namespace (e.g. `Sword`) within this namespace (e.g. `base:Sword`)
+    /// before falling back to the ID as-is.
+    ///
+    /// By default, this returns `None`.
+    ///
+    /// [prototype]: Prototypical
+    /// [`ProtoCommands::spawn`]: crate::proto::ProtoCommands::spawn
+    /// [`Prototypes::is_ready`]: crate::proto::Prototypes::is_ready
+    fn namespace(&self) -> Option<&str> {
+        None
+    }
+
+    /// The [`OverridePolicy`] of the given [prototype].
+    ///
+    /// This is used when a prototype is registered with the same ID as another prototype.
+    /// If both prototypes have a policy, the one with the higher priority takes the ID,
+    /// either replacing the other prototype or merging into it.
+    /// The lower priority prototype is kept around, so that it can take the ID back
+    /// once the higher priority prototype is removed.
+    /// Any prototypes depending on the overridden one are reloaded to use the new one.
+    ///
+    /// If either prototype has no policy, the later one fails to register
+    /// with [`ProtoError::AlreadyExists`].
+    ///
+    /// By default, this returns `None`.
+    ///
+    /// [prototype]: Prototypical
+    /// [`ProtoError::AlreadyExists`]: crate::proto::ProtoError::AlreadyExists
+    fn override_policy(&self, prototype: &T) -> Option<OverridePolicy> {
+        None
+    }
+
     /// Controls how [cycles] should be handled.
     ///
     /// When `#[cfg(debug_assertions)]` is enabled, the default behavior will be to panic.
@@ -0,0 +1,196 @@
+use std::path::PathBuf;
+
+use bevy::asset::{AssetServer, Handle, LoadState};
+use bevy::ecs::entity::Entities;
+use bevy::ecs::system::Command;
+use bevy::prelude::{Commands, Entity, EventWriter, Res, ResMut, Resource, World};
+
+use crate::proto::{
+    Config, ProtoInsertCommand, ProtoSpawnError, ProtoSpawnFailed, ProtoStorage, Prototypes,
+    Prototypical,
+};
+use crate::registration::ProtoRegistry;
+
+/// A [command] to insert a [prototype] on an entity once it is ready.
+///
+/// See [`ProtoCommands::spawn_when_ready`] for details.
+///
+/// [command]: Command
+/// [prototype]: Prototypical
+/// [`ProtoCommands::spawn_when_ready`]: crate::proto::ProtoCommands::spawn_when_ready
+pub struct ProtoDeferredInsertCommand<T: Prototypical> {
+    entity: Entity,
+    target: DeferredTarget<T>,
+}
+
+impl<T: Prototypical> ProtoDeferredInsertCommand<T> {
+    /// Create a command that waits for the prototype with the given [ID].
+    ///
+    /// [ID]: Prototypical::id
+    pub fn new(id: T::Id, entity: Entity) -> Self {
+        Self {
+            entity,
+            target: DeferredTarget::Id(id),
+        }
+    }
+
+    /// Create a command that loads the prototype at the given path and waits for it.
+    ///
+    /// The prototype's handle is stored the same way as [`PrototypesMut::load`].
+    ///
+    /// [`PrototypesMut::load`]: crate::proto::PrototypesMut::load
+    pub fn from_path<P: Into<PathBuf>>(path: P, entity: Entity) -> Self {
+        Self {
+            entity,
+            target: DeferredTarget::Path(path.into()),
+        }
+    }
+}
+
+impl<T: Prototypical> Command for ProtoDeferredInsertCommand<T> {
+    fn apply(self, world: &mut World) {
+        let target = match self.target {
+            DeferredTarget::Id(id) => PendingTarget::Id(id),
+            DeferredTarget::Path(path) => {
+                let asset_server = world.resource::<AssetServer>();
+                // Only string paths are parsed for a label (e.g. `orcs.prototypes.ron#Grunt`)
+                let handle: Handle<T> = match path.to_str() {
+                    Some(path) => asset_server.load(path),
+                    None => asset_server.load(path.as_path()),
+                };
+                world
+                    .resource_mut::<ProtoStorage<T>>()
+                    .insert(path, handle.clone());
+                PendingTarget::Handle(handle)
+            }
+        };
+
+        world
+            .resource_mut::<ProtoDeferredInserts<T>>()
+            .pending
+            .push((self.entity, target));
+    }
+}
+
+enum DeferredTarget<T: Prototypical> {
+    Id(T::Id),
+    Path(PathBuf),
+}
+
+enum PendingTarget<T: Prototypical> {
+    Id(T::Id),
+    Handle(Handle<T>),
+}
+
+/// Resource containing the entities waiting on a [prototype] to be ready.
+///
+/// [prototype]: Prototypical
+#[derive(Resource)]
+pub(crate) struct ProtoDeferredInserts<T: Prototypical> {
+    pending: Vec<(Entity, PendingTarget<T>)>,
+}
+
+impl<T: Prototypical> Default for ProtoDeferredInserts<T> {
+    fn default() -> Self {
+        Self {
+            pending: Vec::new(),
+        }
+    }
+}
+
+/// Inserts any deferred prototypes that are now ready.
+///
+/// The prototypes are inserted as [fallible], with a [`ProtoSpawned`] event
+/// only being sent once the insertion succeeds.
+///
+/// [fallible]: crate::proto::ProtoInsertCommand::with_fallible
+/// [`ProtoSpawned`]: crate::proto::ProtoSpawned
+pub(crate) fn insert_deferred_prototypes<T: Prototypical, C: Config<T>>(
+    mut deferred: ResMut<ProtoDeferredInserts<T>>,
+    registry: Res<ProtoRegistry<T, C>>,
+    prototypes: Prototypes<T, C>,
+    entities: &Entities,
+    mut commands: Commands,
+    mut failed: EventWriter<ProtoSpawnFailed<T>>,
+) {
+    if deferred.pending.is_empty() {
+        return;
+    }
+
+    deferred.pending.retain(|(entity, target)| {
+        let entity = *entity;
+
+        let result = match target {
+            PendingTarget::Id(id) => {
+                let namespace = prototypes.config().namespace();
+                if let Some(id) = registry.resolve_id(id, namespace) {
+                    Some(Ok(id.clone()))
+                } else {
+                    let handle = registry.load_queue().read().get_handle(id);
+                    match handle {
+                        Some(handle) => (prototypes.get_load_state(handle) == LoadState::Failed)
+                            .then(|| {
+                                Err((
+                                    Some(id.clone()),
+                                    ProtoSpawnError::LoadFailed(id.to_string()),
+                                ))
+                            }),
+                        // Nothing left to load could ever register this ID
+                        None => (!prototypes.is_loading()).then(|| {
+                            Err((Some(id.clone()), ProtoSpawnError::NotLoaded(id.to_string())))
+                        }),
+                    }
+                }
+            }
+            PendingTarget::Handle(handle) => match prototypes.get_load_state(handle) {
+                LoadState::Loaded => registry.get_id(handle).cloned().map(Ok),
+                LoadState::Failed => {
+                    let id = registry.load_queue().read().get_id(handle.id());
+                    let name = id
+                        .as_ref()
+                        .map(ToString::to_string)
+                        .unwrap_or_else(|| format!("{:?}", handle));
+                    Some(Err((id, ProtoSpawnError::LoadFailed(name))))
+                }
+                _ => None,
+            },
+        };
+
+        let Some(result) = result else {
+            // Still loading
+            return true;
+        };
+
+        if !entities.contains(entity) {
+            let id = match result {
+                Ok(id) => Some(id),
+                Err((id, _)) => id,
+            };
+            failed.send(ProtoSpawnFailed {
+                id,
+                entity: Some(entity),
+                error: ProtoSpawnError::MissingEntity(entity),
+            });
+            return false;
+        }
+
+        match result {
+            Ok(id) => {
+                commands.add(
+                    ProtoInsertCommand::<T, C>::new(id, Some(entity))
+                        .with_fallible(true)
+                        .with_spawned_event(true),
+                );
+            }
+            Err((id, error)) => {
+                failed.send(ProtoSpawnFailed {
+                    id,
+                    entity: Some(entity),
+                    error,
+                });
+            }
+        }
+
+        false
+    });
+}
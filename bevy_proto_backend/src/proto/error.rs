@@ -1,4 +1,7 @@
 use bevy::asset::{AssetPath, HandleUntyped};
+use bevy::prelude::Entity;
+
+use crate::schematics::SchematicError;
 use thiserror::Error;
 
 /// The main error type for [prototype]-related operations.
@@ -31,9 +34,60 @@ pub enum ProtoError {
         path: Box<AssetPath<'static>>,
         existing: Box<AssetPath<'static>>,
     },
+    /// Indicates that a prototype was not registered because a higher priority prototype
+    /// with the same ID [overrides] it.
+    ///
+    /// The prototype is registered once the overriding prototype is removed.
+    ///
+    /// [overrides]: crate::proto::Config::override_policy
+    #[error(
+        "the prototype with ID {id:?} (`{path:?}`) is overridden by a higher priority prototype"
+    )]
+    Overridden {
+        id: String,
+        path: Box<AssetPath<'static>>,
+    },
     /// Indicates that an operation that requires an entity was attempted on a prototype that doesn't require one.
     ///
     /// This includes attempting to register children on an entity-less prototype.
     #[error("expected prototype with ID {id:?} to require an entity")]
     RequiresEntity { id: String },
+    /// Indicates that the [parameters] of a prototype could not be substituted.
+    ///
+    /// [parameters]: crate::proto::ProtoParams
+    #[error("could not substitute parameters for prototype with ID {id:?}: {error}")]
+    InvalidParams { id: String, error: String },
+    /// Indicates that a prototype references another prototype by an ID that isn't registered.
+    ///
+    /// Registration of the referencing prototype is deferred until the ID becomes available.
+    #[error("could not find a registered prototype with ID {0:?}")]
+    MissingReference(String),
+}
+
+/// Errors that can occur when spawning a [prototype].
+///
+/// [prototype]: crate::proto::Prototypical
+#[derive(Debug, Error)]
+pub enum ProtoSpawnError {
+    /// Indicates that the prototype is not loaded.
+    #[error("the prototype {0:?} is not loaded")]
+    NotLoaded(String),
+    /// Indicates that the prototype is still loading.
+    #[error("the prototype {0:?} is still loading (use `Prototypes::is_ready` to check load status or `ProtoCommands::spawn_when_ready` to wait for it)")]
+    StillLoading(String),
+    /// Indicates that the prototype (or one of its dependencies) failed to load or register.
+    #[error("the prototype {0:?} failed to load")]
+    LoadFailed(String),
+    /// Indicates that the entity to insert the prototype onto no longer exists.
+    #[error("the entity {0:?} does not exist")]
+    MissingEntity(Entity),
+    /// Indicates that a prototype-related operation failed.
+    #[error(transparent)]
+    Proto(#[from] ProtoError),
+    /// Indicates that a schematic could not be applied (or removed).
+    #[error("could not apply schematic `{type_name}`: {error}")]
+    Schematic {
+        type_name: String,
+        error: SchematicError,
+    },
 }
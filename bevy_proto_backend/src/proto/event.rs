@@ -0,0 +1,39 @@
+use bevy::prelude::{Entity, Event};
+
+use crate::proto::{ProtoSpawnError, Prototypical};
+
+/// Event sent when a [prototype] that was waiting to finish loading
+/// has been inserted onto its entity.
+///
+/// See [`ProtoCommands::spawn_when_ready`] for details.
+///
+/// [prototype]: Prototypical
+/// [`ProtoCommands::spawn_when_ready`]: crate::proto::ProtoCommands::spawn_when_ready
+#[derive(Debug, PartialEq, Event)]
+pub struct ProtoSpawned<T: Prototypical> {
+    /// The ID of the spawned prototype.
+    pub id: T::Id,
+    /// The entity the prototype was inserted onto.
+    pub entity: Entity,
+}
+
+/// Event sent when a [prototype] could not be spawned.
+///
+/// [prototype]: Prototypical
+#[derive(Debug, Event)]
+pub struct ProtoSpawnFailed<T: Prototypical> {
+    /// The ID of the prototype, if known.
+    ///
+    /// This may be `None` if the prototype was requested by path
+    /// and failed before its ID could be determined.
+    pub id: Option<T::Id>,
+    /// The entity the prototype was to be inserted onto, if any.
+    ///
+    /// This is `None` for commands that don't target a single entity,
+    /// such as [`ProtoCommands::try_spawn_batch`].
+    ///
+    /// [`ProtoCommands::try_spawn_batch`]: crate::proto::ProtoCommands::try_spawn_batch
+    pub entity: Option<Entity>,
+    /// The reason the prototype could not be spawned.
+    pub error: ProtoSpawnError,
+}
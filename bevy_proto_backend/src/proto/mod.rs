@@ -5,9 +5,15 @@ pub use color::*;
 pub use commands::*;
 pub use component::*;
 pub use config::*;
+pub use deferred::*;
 pub use error::*;
+pub use event::*;
+pub use namespace::*;
+pub use overrides::*;
+pub use params::*;
 pub use prototypes::*;
 pub use prototypical::*;
+pub use rng::*;
 pub(crate) use storage::*;
 
 #[cfg(feature = "bevy_render")]
@@ -15,7 +21,13 @@ mod color;
 mod commands;
 mod component;
 mod config;
+mod deferred;
 mod error;
+mod event;
+mod namespace;
+mod overrides;
+mod params;
 mod prototypes;
 mod prototypical;
+mod rng;
 mod storage;
@@ -0,0 +1,32 @@
+/// The character separating a prototype's namespace from its name (e.g. `base:Sword`).
+pub const NAMESPACE_SEPARATOR: char = ':';
+
+/// Splits the given prototype ID into its namespace (if any) and its name.
+///
+/// ```
+/// # use bevy_proto_backend::proto::split_namespace;
+/// assert_eq!((Some("base"), "Sword"), split_namespace("base:Sword"));
+/// assert_eq!((None, "Sword"), split_namespace("Sword"));
+/// ```
+pub fn split_namespace(id: &str) -> (Option<&str>, &str) {
+    match id.split_once(NAMESPACE_SEPARATOR) {
+        Some((namespace, name)) => (Some(namespace), name),
+        None => (None, id),
+    }
+}
+
+/// Qualifies the given prototype ID with the given namespace.
+///
+/// Returns `None` if the ID already has a namespace.
+///
+/// ```
+/// # use bevy_proto_backend::proto::qualify_id;
+/// assert_eq!(Some(String::from("base:Sword")), qualify_id("Sword", "base"));
+/// assert_eq!(None, qualify_id("extra:Sword", "base"));
+/// ```
+pub fn qualify_id(id: &str, namespace: &str) -> Option<String> {
+    match split_namespace(id) {
+        (Some(_), _) => None,
+        (None, name) => Some(format!("{namespace}{NAMESPACE_SEPARATOR}{name}")),
+    }
+}
@@ -0,0 +1,50 @@
+/// Determines how a [prototype] overrides a lower priority prototype with the same ID.
+///
+/// [prototype]: crate::proto::Prototypical
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum OverrideMode {
+    /// The overridden prototype is replaced entirely.
+    Replace,
+    /// The overridden prototype is inherited like a template,
+    /// applying its schematics (and children) before those of the overriding prototype.
+    Merge,
+}
+
+/// The policy used when multiple [prototypes] share the same ID.
+///
+/// See [`Config::override_policy`] for details.
+///
+/// [prototypes]: crate::proto::Prototypical
+/// [`Config::override_policy`]: crate::proto::Config::override_policy
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct OverridePolicy {
+    /// The priority of the prototype.
+    ///
+    /// Prototypes with a higher priority override those with a lower priority.
+    /// When two prototypes have the same priority, the one registered last takes precedence.
+    pub priority: i32,
+    /// How the prototype overrides the one below it.
+    pub mode: OverrideMode,
+}
+
+impl OverridePolicy {
+    /// Create a policy that [replaces] any lower priority prototypes.
+    ///
+    /// [replaces]: OverrideMode::Replace
+    pub fn replace(priority: i32) -> Self {
+        Self {
+            priority,
+            mode: OverrideMode::Replace,
+        }
+    }
+
+    /// Create a policy that [merges] into any lower priority prototypes.
+    ///
+    /// [merges]: OverrideMode::Merge
+    pub fn merge(priority: i32) -> Self {
+        Self {
+            priority,
+            mode: OverrideMode::Merge,
+        }
+    }
+}
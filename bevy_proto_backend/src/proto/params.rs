@@ -0,0 +1,256 @@
+use std::fmt::{Display, Formatter};
+
+use bevy::utils::hashbrown::hash_map::Iter;
+use bevy::utils::HashMap;
+use serde::de::{Error, MapAccess, Visitor};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A single parameter value used to configure a [prototype].
+///
+/// [prototype]: crate::proto::Prototypical
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParamValue {
+    /// A boolean value.
+    Bool(bool),
+    /// An integer value.
+    Int(i64),
+    /// A floating-point value.
+    Float(f64),
+    /// A string value.
+    String(String),
+}
+
+impl Display for ParamValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::Int(value) => write!(f, "{value}"),
+            Self::Float(value) => write!(f, "{value}"),
+            Self::String(value) => write!(f, "{value:?}"),
+        }
+    }
+}
+
+macro_rules! impl_from_param_value {
+    ($variant: ident, $cast: ty: $($ty: ty),*) => {
+        $(
+            impl From<$ty> for ParamValue {
+                fn from(value: $ty) -> Self {
+                    Self::$variant(value as $cast)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_param_value!(Int, i64: i8, i16, i32, i64, u8, u16, u32);
+impl_from_param_value!(Float, f64: f32, f64);
+
+impl From<bool> for ParamValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<String> for ParamValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&str> for ParamValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl Serialize for ParamValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Bool(value) => serializer.serialize_bool(*value),
+            Self::Int(value) => serializer.serialize_i64(*value),
+            Self::Float(value) => serializer.serialize_f64(*value),
+            Self::String(value) => serializer.serialize_str(value),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ParamValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ParamValueVisitor;
+
+        impl<'de> Visitor<'de> for ParamValueVisitor {
+            type Value = ParamValue;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                write!(formatter, "a boolean, number, or string")
+            }
+
+            fn visit_bool<E: Error>(self, value: bool) -> Result<Self::Value, E> {
+                Ok(ParamValue::Bool(value))
+            }
+
+            fn visit_i64<E: Error>(self, value: i64) -> Result<Self::Value, E> {
+                Ok(ParamValue::Int(value))
+            }
+
+            fn visit_u64<E: Error>(self, value: u64) -> Result<Self::Value, E> {
+                i64::try_from(value)
+                    .map(ParamValue::Int)
+                    .map_err(|_| Error::custom(format_args!("integer {value} is too large")))
+            }
+
+            fn visit_f64<E: Error>(self, value: f64) -> Result<Self::Value, E> {
+                Ok(ParamValue::Float(value))
+            }
+
+            fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+                Ok(ParamValue::String(value.to_string()))
+            }
+        }
+
+        deserializer.deserialize_any(ParamValueVisitor)
+    }
+}
+
+/// A collection of named [parameter values] used to configure a [prototype].
+///
+/// Prototypes may declare parameters (along with their default values) which can then
+/// be referenced from within their schematics.
+/// These defaults can then be overridden when the prototype is spawned or inherited.
+///
+/// [parameter values]: ParamValue
+/// [prototype]: crate::proto::Prototypical
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ProtoParams(HashMap<String, ParamValue>);
+
+impl ProtoParams {
+    /// Create an empty set of parameters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the given parameter.
+    pub fn with(mut self, name: impl Into<String>, value: impl Into<ParamValue>) -> Self {
+        self.insert(name, value);
+        self
+    }
+
+    /// Insert the given parameter, returning the previous value (if any).
+    pub fn insert(
+        &mut self,
+        name: impl Into<String>,
+        value: impl Into<ParamValue>,
+    ) -> Option<ParamValue> {
+        self.0.insert(name.into(), value.into())
+    }
+
+    /// Get the value of the parameter with the given name.
+    pub fn get(&self, name: &str) -> Option<&ParamValue> {
+        self.0.get(name)
+    }
+
+    /// Returns true if a parameter with the given name is contained.
+    pub fn contains(&self, name: &str) -> bool {
+        self.0.contains_key(name)
+    }
+
+    /// Returns an iterator over all the parameters.
+    pub fn iter(&self) -> Iter<'_, String, ParamValue> {
+        self.0.iter()
+    }
+
+    /// The number of contained parameters.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if there are no parameters.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<'a> IntoIterator for &'a ProtoParams {
+    type Item = (&'a String, &'a ParamValue);
+    type IntoIter = Iter<'a, String, ParamValue>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K: Into<String>, V: Into<ParamValue>> FromIterator<(K, V)> for ProtoParams {
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|(name, value)| (name.into(), value.into()))
+                .collect(),
+        )
+    }
+}
+
+impl<K: Into<String>, V: Into<ParamValue>, const N: usize> From<[(K, V); N]> for ProtoParams {
+    fn from(value: [(K, V); N]) -> Self {
+        Self::from_iter(value)
+    }
+}
+
+impl Serialize for ProtoParams {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // Sort by name so the output is stable
+        let mut params = self.0.iter().collect::<Vec<_>>();
+        params.sort_by_key(|(name, _)| *name);
+
+        let mut map = serializer.serialize_map(Some(params.len()))?;
+        for (name, value) in params {
+            map.serialize_entry(name, value)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for ProtoParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ProtoParamsVisitor;
+
+        impl<'de> Visitor<'de> for ProtoParamsVisitor {
+            type Value = ProtoParams;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                write!(formatter, "map of parameters")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut params = HashMap::with_capacity(map.size_hint().unwrap_or_default());
+                while let Some((name, value)) = map.next_entry::<String, ParamValue>()? {
+                    if params.insert(name.clone(), value).is_some() {
+                        return Err(Error::custom(format_args!("duplicate parameter: `{name}`")));
+                    }
+                }
+
+                Ok(ProtoParams(params))
+            }
+        }
+
+        // Use `deserialize_any` so that struct-like syntax (such as RON's `(speed: 5.0)`)
+        // can also be used to define parameters
+        deserializer.deserialize_any(ProtoParamsVisitor)
+    }
+}
@@ -7,14 +7,26 @@ use std::hash::Hash;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
 
+use crate::children::ChildForm;
 use crate::proto::{Config, ProtoStorage, Prototypical};
 use crate::registration::ProtoRegistry;
+use crate::schematics::{Schematic, SchematicError};
+use crate::tree::ProtoTree;
 
 #[derive(Debug, Error)]
 pub enum ProtoLoadError {
     /// Indicates that the [`AssetServer`] encountered an error.
     #[error(transparent)]
     AssetServerError(#[from] AssetServerError),
+    /// Indicates that a [bundle] was loaded with a [`Loader`] that doesn't support them.
+    ///
+    /// [bundle]: crate::load::Loader::deserialize_bundle
+    /// [`Loader`]: crate::load::Loader
+    #[error("the bundle {0:?} could not be loaded since the loader does not support bundles")]
+    UnsupportedBundle(PathBuf),
+    /// Indicates that a schematic could not be loaded.
+    #[error(transparent)]
+    SchematicError(#[from] SchematicError),
 }
 
 /// A helper [`SystemParam`] for managing [prototypes].
@@ -52,7 +64,11 @@ impl<'w, T: Prototypical, C: Config<T>> PrototypesMut<'w, T, C> {
     /// To load without automatically storing the handle, try using [`AssetServer::load`].
     pub fn load<P: Into<PathBuf>>(&mut self, path: P) -> Handle<T> {
         let path = path.into();
-        let handle = self.asset_server.load(path.as_path());
+        // Only string paths are parsed for a label (e.g. `orcs.prototypes.ron#Grunt`)
+        let handle = match path.to_str() {
+            Some(path) => self.asset_server.load(path),
+            None => self.asset_server.load(path.as_path()),
+        };
         self.storage.insert(path, handle.clone());
         handle
     }
@@ -136,17 +152,31 @@ macro_rules! impl_prototypes {
                 }
             }
 
+            /// Returns true if any stored prototype is still loading or waiting to be registered.
+            pub fn is_loading(&self) -> bool {
+                !self.registry.load_queue().read().is_empty()
+                    || self
+                        .storage
+                        .handles()
+                        .any(|handle| self.get_load_state(handle) == LoadState::Loading)
+            }
+
             /// Returns true if the prototype with the given [ID] is ready to be spawned.
             ///
             /// This method is preferred over [`AssetServer::get_load_state`] as it better
             /// accounts for prototype dependencies and registration.
             ///
+            /// IDs without a namespace are resolved within the [configured namespace].
+            ///
             /// [ID]: Prototypical::id
-            pub fn is_ready<I: Hash + Eq + ?Sized>(&self, id: &I) -> bool
+            /// [configured namespace]: Config::namespace
+            pub fn is_ready<I: Hash + Eq + ToString + ?Sized>(&self, id: &I) -> bool
             where
                 T::Id: Borrow<I>,
             {
-                self.registry.contains(id)
+                self.registry
+                    .resolve_id(id, self.config.namespace())
+                    .is_some()
             }
 
             /// Returns true if the prototype with the given handle is ready to be spawned.
@@ -169,12 +199,140 @@ macro_rules! impl_prototypes {
                 self.storage.get(path)
             }
 
+            /// Returns an iterator over the [IDs] of all registered prototypes.
+            ///
+            /// [IDs]: Prototypical::id
+            pub fn ids(&self) -> impl Iterator<Item = &T::Id> {
+                self.registry.iter().map(|(id, _)| id)
+            }
+
+            /// Returns an iterator over the [IDs] and handles of all registered prototypes.
+            ///
+            /// Note that the returned handles are weak.
+            ///
+            /// [IDs]: Prototypical::id
+            pub fn iter(&self) -> impl Iterator<Item = (&T::Id, &Handle<T>)> {
+                self.registry.iter()
+            }
+
+            /// Get the handle of the registered prototype with the given [ID].
+            ///
+            /// IDs without a namespace are resolved within the [configured namespace].
+            /// Note that the returned handle is weak.
+            ///
+            /// [ID]: Prototypical::id
+            /// [configured namespace]: Config::namespace
+            pub fn get_by_id<I: Hash + Eq + ToString + ?Sized>(&self, id: &I) -> Option<&Handle<T>>
+            where
+                T::Id: Borrow<I>,
+            {
+                self.registry
+                    .resolve_id(id, self.config.namespace())
+                    .and_then(|id| self.registry.get_handle(id))
+            }
+
+            /// Returns an iterator over the [IDs] of all registered prototypes
+            /// that contain the given [schematic], either directly or through their templates.
+            ///
+            /// Schematics within variants are not included.
+            ///
+            /// [IDs]: Prototypical::id
+            /// [schematic]: Schematic
+            pub fn with_schematic<S: Schematic>(&self) -> impl Iterator<Item = &T::Id> {
+                self.with_schematic_by_name(std::any::type_name::<S>())
+            }
+
+            /// Returns an iterator over the [IDs] of all registered prototypes
+            /// that contain the schematic with the given [type name],
+            /// either directly or through their templates.
+            ///
+            /// Schematics within variants are not included.
+            ///
+            /// [IDs]: Prototypical::id
+            /// [type name]: std::any::type_name
+            pub fn with_schematic_by_name<'a>(
+                &'a self,
+                name: &'a str,
+            ) -> impl Iterator<Item = &'a T::Id> + 'a {
+                self.registry.iter().filter_map(move |(id, handle)| {
+                    self.registry
+                        .get_tree(handle)
+                        .filter(|tree| tree.schematics().contains(name))
+                        .map(|_| id)
+                })
+            }
+
+            /// Returns the [IDs] of all templates inherited by the registered prototype
+            /// with the given [ID], including the templates of those templates.
+            ///
+            /// Templates are listed in the reverse order they are applied in,
+            /// such that templates listed first override the ones listed after them.
+            ///
+            /// IDs without a namespace are resolved within the [configured namespace].
+            ///
+            /// [IDs]: Prototypical::id
+            /// [ID]: Prototypical::id
+            /// [configured namespace]: Config::namespace
+            pub fn get_templates<I: Hash + Eq + ToString + ?Sized>(
+                &self,
+                id: &I,
+            ) -> Option<Vec<&T::Id>>
+            where
+                T::Id: Borrow<I>,
+            {
+                let tree = self.get_tree(id)?;
+                Some(
+                    tree.prototypes()
+                        .iter()
+                        // The first prototype in a tree is always the prototype itself
+                        .skip(1)
+                        .filter_map(|handle| self.registry.get_id(*handle))
+                        .collect(),
+                )
+            }
+
+            /// Returns the [IDs] of the children of the registered prototype with the given [ID],
+            /// including any children inherited from its templates.
+            ///
+            /// Children are returned within their [`ChildForm`],
+            /// so randomly selected children are listed along with the ways they could be selected.
+            ///
+            /// IDs without a namespace are resolved within the [configured namespace].
+            ///
+            /// [IDs]: Prototypical::id
+            /// [ID]: Prototypical::id
+            /// [configured namespace]: Config::namespace
+            pub fn get_children<I: Hash + Eq + ToString + ?Sized>(
+                &self,
+                id: &I,
+            ) -> Option<Vec<ChildForm<T::Id>>>
+            where
+                T::Id: Borrow<I>,
+            {
+                let tree = self.get_tree(id)?;
+                Some(
+                    tree.children()
+                        .iter()
+                        .map(|form| form.map(|child| child.id().clone()))
+                        .collect(),
+                )
+            }
+
             /// Returns a reference to the [`Config`] resource.
             ///
             /// [`Config`]: Config
             pub fn config(&self) -> &C {
                 &self.config
             }
+
+            fn get_tree<I: Hash + Eq + ToString + ?Sized>(&self, id: &I) -> Option<&ProtoTree<T>>
+            where
+                T::Id: Borrow<I>,
+            {
+                self.registry
+                    .resolve_id(id, self.config.namespace())
+                    .and_then(|id| self.registry.get_tree_by_id(id))
+            }
         }
     };
 }
@@ -2,11 +2,13 @@ use std::fmt::Debug;
 use std::hash::Hash;
 
 use bevy::asset::Asset;
+use bevy::reflect::TypeRegistryInternal;
 
 use crate::children::{Children, PrototypicalChild};
 use crate::deps::Dependencies;
 use crate::path::ProtoPath;
-use crate::schematics::Schematics;
+use crate::proto::{ProtoError, ProtoParams};
+use crate::schematics::{Schematics, Variants};
 use crate::templates::Templates;
 
 /// The trait used to define a prototype.
@@ -56,6 +58,64 @@ pub trait Prototypical: Asset + Sized {
     fn schematics(&self) -> &Schematics;
     /// A mutable reference to the collection of [`Schematics`] contained in this prototype.
     fn schematics_mut(&mut self) -> &mut Schematics;
+    /// The [type names] of inherited schematics that this prototype removes.
+    ///
+    /// Schematics of these types will not be applied from any of this prototype's templates.
+    ///
+    /// Defaults to none.
+    ///
+    /// [type names]: std::any::type_name
+    fn removed_schematics(&self) -> &[String] {
+        &[]
+    }
+    /// The [parameters] declared by this prototype, along with their default values.
+    ///
+    /// Defaults to none.
+    ///
+    /// [parameters]: ProtoParams
+    fn params(&self) -> Option<&ProtoParams> {
+        None
+    }
+    /// The [parameters] this prototype passes down to the given template, if any.
+    ///
+    /// Defaults to none.
+    ///
+    /// [parameters]: ProtoParams
+    fn template_params(&self, _template: &ProtoPath) -> Option<&ProtoParams> {
+        None
+    }
+    /// Creates a new collection of [`Schematics`] and [`Variants`] for this prototype
+    /// using the given values for its declared [parameters].
+    ///
+    /// Returns `Ok(None)` if this prototype does not support parameter substitution,
+    /// in which case its regular [schematics] and [variants] are used.
+    ///
+    /// Defaults to `Ok(None)`.
+    ///
+    /// [parameters]: Self::params
+    /// [schematics]: Self::schematics
+    /// [variants]: Self::variants
+    fn schematics_with_params(
+        &self,
+        _params: &ProtoParams,
+        _registry: &TypeRegistryInternal,
+    ) -> Result<Option<(Schematics, Option<Variants>)>, ProtoError> {
+        Ok(None)
+    }
+    /// An immutable reference to the collection of alternative schematic [`Variants`]
+    /// contained in this prototype, if any.
+    ///
+    /// Defaults to none.
+    fn variants(&self) -> Option<&Variants> {
+        None
+    }
+    /// A mutable reference to the collection of alternative schematic [`Variants`]
+    /// contained in this prototype, if any.
+    ///
+    /// Defaults to none.
+    fn variants_mut(&mut self) -> Option<&mut Variants> {
+        None
+    }
     /// An immutable reference to the collection of [`Templates`] inherited by this prototype, if any.
     fn templates(&self) -> Option<&Templates>;
     /// A mutable reference to the collection of [`Templates`] inherited by this prototype, if any.
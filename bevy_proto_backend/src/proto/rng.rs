@@ -0,0 +1,66 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+
+use bevy::prelude::Resource;
+
+/// The random number generator used by [prototypes].
+///
+/// This is used to resolve any randomness when spawning a prototype,
+/// such as selecting which [children] to spawn.
+///
+/// By default, this resource is seeded randomly.
+/// To make spawning deterministic (e.g. for replays or tests),
+/// it can be [reseeded] with a fixed value.
+///
+/// [prototypes]: crate::proto::Prototypical
+/// [children]: crate::children::ChildForm
+/// [reseeded]: ProtoRng::reseed
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct ProtoRng {
+    state: u64,
+}
+
+impl ProtoRng {
+    /// Create a new generator with the given seed.
+    pub fn from_seed(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Reset this generator with the given seed.
+    pub fn reseed(&mut self, seed: u64) {
+        self.state = seed;
+    }
+
+    /// Generate the next random `u64`.
+    pub fn next_u64(&mut self) -> u64 {
+        // SplitMix64: https://prng.di.unimi.it/splitmix64.c
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut value = self.state;
+        value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        value ^ (value >> 31)
+    }
+
+    /// Generate the next random `f32` in the range `[0, 1)`.
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// Generate a random `u32` between `min` and `max` (inclusive).
+    ///
+    /// If `min` is greater than or equal to `max`, `min` is returned.
+    pub fn range(&mut self, min: u32, max: u32) -> u32 {
+        if min >= max {
+            return min;
+        }
+
+        let span = u64::from(max - min) + 1;
+        min + (self.next_u64() % span) as u32
+    }
+}
+
+impl Default for ProtoRng {
+    fn default() -> Self {
+        Self::from_seed(RandomState::new().build_hasher().finish())
+    }
+}
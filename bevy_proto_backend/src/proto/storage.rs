@@ -27,6 +27,11 @@ impl<T: Prototypical> ProtoStorage<T> {
         self.path_to_handle.get(path.as_ref())
     }
 
+    /// Returns an iterator over the strong handles of all stored prototypes.
+    pub fn handles(&self) -> impl Iterator<Item = &Handle<T>> {
+        self.path_to_handle.values()
+    }
+
     /// Insert a prototype handle into this resource for the given path.
     ///
     /// If a handle already existed for the path, the existing one is returned.
@@ -1,8 +1,10 @@
 pub(crate) use manager::*;
 pub(crate) use registry::*;
+pub(crate) use reload::*;
 pub(crate) use systems::*;
 
 mod manager;
 mod params;
 mod registry;
+mod reload;
 mod systems;
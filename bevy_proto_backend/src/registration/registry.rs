@@ -5,13 +5,16 @@ use std::sync::Arc;
 
 use crate::registration::params::RegistryParams;
 use bevy::asset::{Handle, HandleId};
-use bevy::prelude::Resource;
+use bevy::prelude::{error, Resource};
 
 use crate::assets::ProtoAssetEvent;
 use bevy::utils::{HashMap, HashSet};
 use parking_lot::RwLock;
 
-use crate::proto::{Config, ProtoError, Prototypical};
+use crate::path::ProtoPath;
+use crate::proto::{
+    qualify_id, split_namespace, Config, OverrideMode, OverridePolicy, ProtoError, Prototypical,
+};
 use crate::tree::{ProtoTree, ProtoTreeBuilder};
 
 /// Resource used to track load states, store mappings, and generate cached data.
@@ -30,9 +33,44 @@ pub(crate) struct ProtoRegistry<T: Prototypical, C: Config<T>> {
     load_queue: Arc<RwLock<LoadQueue<T>>>,
     /// Set of prototypes that failed to be registered.
     failed: HashSet<HandleId>,
+    /// Maps the handle of an [ID reference] to the ID of the registered prototype.
+    ///
+    /// [ID reference]: ProtoPath::from_id
+    references: HashMap<HandleId, T::Id>,
+    /// Maps the name of an [ID reference] (i.e. without its namespace) to the set of prototypes
+    /// whose registration is deferred until a prototype with that name is registered.
+    ///
+    /// [ID reference]: ProtoPath::from_id
+    waiting: HashMap<String, HashSet<HandleId>>,
+    /// Maps the handle of a prototype that has not yet been loaded to the set of prototypes
+    /// whose registration is deferred until it is registered.
+    ///
+    /// This only occurs for prototypes added directly to the assets,
+    /// since loaded prototypes wait for their dependencies to load.
+    pending: HashMap<HandleId, HashSet<HandleId>>,
+    /// Maps the string form of every registered ID with a namespace to the ID itself.
+    ///
+    /// This allows unqualified IDs to be resolved within a namespace.
+    namespaced: HashMap<String, T::Id>,
+    /// Maps the ID of every prototype that has been [overridden] to the [layers] sharing that ID,
+    /// ordered from lowest to highest priority.
+    ///
+    /// Only the last layer is actually registered.
+    ///
+    /// [overridden]: Config::override_policy
+    /// [layers]: ProtoLayer
+    layers: HashMap<T::Id, Vec<ProtoLayer<T>>>,
+    /// Maps the handle of every overridden prototype to its ID.
+    shadowed: HashMap<HandleId, T::Id>,
     _phantom: PhantomData<C>,
 }
 
+/// A prototype sharing its ID with other prototypes.
+struct ProtoLayer<T: Prototypical> {
+    handle: Handle<T>,
+    policy: OverridePolicy,
+}
+
 impl<T: Prototypical, C: Config<T>> ProtoRegistry<T, C> {
     /// Registers a prototype.
     ///
@@ -53,6 +91,9 @@ impl<T: Prototypical, C: Config<T>> ProtoRegistry<T, C> {
             id: prototype.id().clone(),
         });
 
+        self.register_waiting(prototype.id(), params);
+        self.register_pending(handle.id(), params);
+
         Ok(prototype)
     }
 
@@ -64,6 +105,12 @@ impl<T: Prototypical, C: Config<T>> ProtoRegistry<T, C> {
         handle: &Handle<T>,
         params: &mut RegistryParams<T, C>,
     ) -> Option<T::Id> {
+        if let Some(id) = self.shadowed.get(&handle.id()).cloned() {
+            // Overridden prototypes are not registered themselves
+            self.remove_layer(&id, handle.id(), params);
+            return None;
+        }
+
         let id = self.unregister_internal(handle, params)?;
 
         let strong_handle = params.get_strong_handle(handle);
@@ -76,6 +123,9 @@ impl<T: Prototypical, C: Config<T>> ProtoRegistry<T, C> {
             id: id.clone(),
         });
 
+        // Removed before promoting the next layer so that its registration comes last
+        self.remove_layer(&id, handle.id(), params);
+
         Some(id)
     }
 
@@ -87,6 +137,20 @@ impl<T: Prototypical, C: Config<T>> ProtoRegistry<T, C> {
         handle: &Handle<T>,
         params: &mut RegistryParams<'w, T, C>,
     ) -> Result<&'w T, ProtoError> {
+        if let Some(id) = self.shadowed.get(&handle.id()).cloned() {
+            // Overridden prototypes only affect the registered prototype if it merges into them
+            self.trees.remove(&handle.id());
+            if self.is_visible(&id, handle.id()) {
+                self.restack(&id, None, params)?;
+            }
+
+            let prototype = params.get_prototype(handle)?;
+            return Err(ProtoError::Overridden {
+                id: prototype.id().to_string(),
+                path: Box::new(prototype.path().into()),
+            });
+        }
+
         if self.unregister_internal(handle, params).is_some() {
             let prototype = self.register_internal(handle, params, true)?;
             let strong_handle = params.get_strong_handle(handle);
@@ -97,23 +161,48 @@ impl<T: Prototypical, C: Config<T>> ProtoRegistry<T, C> {
                 handle: handle.clone_weak(),
                 id: prototype.id().clone(),
             });
+            self.register_waiting(prototype.id(), params);
             Ok(prototype)
         } else {
             Err(ProtoError::NotRegistered(handle.clone_weak_untyped()))
         }
     }
 
-    pub fn contains<I: Hash + Eq + ?Sized>(&self, id: &I) -> bool
+    /// Resolves the given ID to the ID of a registered prototype.
+    ///
+    /// If a namespace is given and the ID doesn't have one,
+    /// the ID is first looked up within that namespace before falling back to the ID as-is.
+    pub fn resolve_id<I: Hash + Eq + ToString + ?Sized>(
+        &self,
+        id: &I,
+        namespace: Option<&str>,
+    ) -> Option<&T::Id>
     where
         T::Id: Borrow<I>,
     {
-        self.handles.contains_key(id)
+        namespace
+            .and_then(|namespace| qualify_id(&id.to_string(), namespace))
+            .and_then(|id| self.namespaced.get(&id))
+            .or_else(|| self.handles.get_key_value(id).map(|(id, _)| id))
+    }
+
+    /// Returns an iterator over the IDs and (weak) handles of all registered prototypes.
+    pub fn iter(&self) -> impl Iterator<Item = (&T::Id, &Handle<T>)> {
+        self.handles.iter()
+    }
+
+    pub fn get_handle<I: Borrow<T::Id>>(&self, id: I) -> Option<&Handle<T>> {
+        self.handles.get(id.borrow())
     }
 
     pub fn contains_handle<H: Into<HandleId>>(&self, handle: H) -> bool {
         self.ids.contains_key(&handle.into())
     }
 
+    pub fn get_id<H: Into<HandleId>>(&self, handle: H) -> Option<&T::Id> {
+        self.ids.get(&handle.into())
+    }
+
     pub fn contains_failed_handle<H: Into<HandleId>>(&self, handle: H) -> bool {
         self.failed.contains(&handle.into())
     }
@@ -141,6 +230,37 @@ impl<T: Prototypical, C: Config<T>> ProtoRegistry<T, C> {
             .and_then(|handle| self.get_tree(handle))
     }
 
+    /// Get the handle of the registered prototype that [overrides] the one with the given handle.
+    ///
+    /// [overrides]: Config::override_policy
+    pub fn get_override<H: Into<HandleId>>(&self, handle: H) -> Option<&Handle<T>> {
+        self.shadowed
+            .get(&handle.into())
+            .and_then(|id| self.handles.get(id))
+    }
+
+    /// Get the handle of the prototype that the one with the given handle and ID [merges] into.
+    ///
+    /// [merges]: OverrideMode::Merge
+    pub fn get_merge_base<H: Into<HandleId>>(&self, handle: H, id: &T::Id) -> Option<Handle<T>> {
+        let handle = handle.into();
+        let layers = self.layers.get(id)?;
+        let index = layers
+            .iter()
+            .position(|layer| layer.handle.id() == handle)?;
+        (index > 0 && layers[index].policy.mode == OverrideMode::Merge)
+            .then(|| layers[index - 1].handle.clone_weak())
+    }
+
+    /// Get the handle of the registered prototype an [ID reference] refers to.
+    ///
+    /// [ID reference]: ProtoPath::from_id
+    pub fn resolve_reference(&self, path: &ProtoPath) -> Option<&Handle<T>> {
+        self.references
+            .get(&HandleId::from(path))
+            .and_then(|id| self.handles.get(id))
+    }
+
     pub fn load_queue(&self) -> &Arc<RwLock<LoadQueue<T>>> {
         &self.load_queue
     }
@@ -166,21 +286,41 @@ impl<T: Prototypical, C: Config<T>> ProtoRegistry<T, C> {
 
             // Check if ID already exists
             if let Some(existing_handle) = self.handles.get(prototype.id()) {
-                self.failed.insert(handle.id());
                 if existing_handle.id() != handle.id() {
                     // Not the same asset!
-                    let exiting_prototype = params.get_prototype(&handle)?;
-                    return Err(ProtoError::AlreadyExists {
-                        id: prototype.id().to_string(),
-                        path: Box::new(prototype.path().into()),
-                        existing: Box::new(exiting_prototype.path().into()),
-                    });
+                    let existing_handle = existing_handle.clone_weak();
+                    return self.register_override(&handle, &existing_handle, params);
                 }
             }
         }
 
-        ProtoTreeBuilder::new(self, params.prototypes(), params.config()).build(&handle)?;
+        let result =
+            ProtoTreeBuilder::new(self, params.prototypes(), params.config()).build(&handle);
+        match &result {
+            Err(ProtoError::MissingReference(reference)) => {
+                let (_, name) = split_namespace(reference);
+                self.waiting
+                    .entry(name.to_string())
+                    .or_default()
+                    .insert(handle.id());
+            }
+            Err(ProtoError::DoesNotExist(missing)) if missing.id() != handle.id() => {
+                self.pending
+                    .entry(missing.id())
+                    .or_default()
+                    .insert(handle.id());
+            }
+            _ => {}
+        }
+        result?;
 
+        let id_str = prototype.id().to_string();
+        if split_namespace(&id_str).0.is_some() {
+            self.namespaced
+                .insert(id_str.clone(), prototype.id().clone());
+        }
+        self.references
+            .insert(reference_handle(&id_str), prototype.id().clone());
         self.ids.insert(handle.id(), prototype.id().clone());
         self.handles
             .insert(prototype.id().clone(), handle.clone_weak());
@@ -201,6 +341,8 @@ impl<T: Prototypical, C: Config<T>> ProtoRegistry<T, C> {
 
         let id = self.ids.remove(&handle_id)?;
         self.handles.remove(&id);
+        self.references.remove(&reference_handle(&id));
+        self.namespaced.remove(&id.to_string());
         self.failed.remove(&handle_id);
         self.trees.remove(&handle_id);
         if let Some(dependents) = self.dependents.remove(&handle_id) {
@@ -217,6 +359,293 @@ impl<T: Prototypical, C: Config<T>> ProtoRegistry<T, C> {
 
         Some(id)
     }
+
+    /// Registers a prototype with the same ID as the registered prototype of the given handle.
+    ///
+    /// If both prototypes have an [override policy], the prototype is added as a [layer]
+    /// and the highest priority layer is registered.
+    /// Otherwise, this returns [`ProtoError::AlreadyExists`].
+    ///
+    /// [override policy]: Config::override_policy
+    /// [layer]: ProtoLayer
+    fn register_override<'w>(
+        &mut self,
+        handle: &Handle<T>,
+        existing: &Handle<T>,
+        params: &mut RegistryParams<'w, T, C>,
+    ) -> Result<&'w T, ProtoError> {
+        let prototype = params.get_prototype(handle)?;
+        let existing_prototype = params.get_prototype(existing)?;
+        let id = prototype.id().clone();
+
+        let policy = params.config().override_policy(prototype);
+        let existing_policy = match self.layers.get(&id) {
+            Some(layers) => layers.last().map(|layer| layer.policy),
+            None => params.config().override_policy(existing_prototype),
+        };
+
+        let (Some(policy), Some(existing_policy)) = (policy, existing_policy) else {
+            self.failed.insert(handle.id());
+            return Err(ProtoError::AlreadyExists {
+                id: id.to_string(),
+                path: Box::new(prototype.path().into()),
+                existing: Box::new(existing_prototype.path().into()),
+            });
+        };
+
+        let layers = self.layers.entry(id.clone()).or_insert_with(|| {
+            vec![ProtoLayer {
+                handle: existing.clone_weak(),
+                policy: existing_policy,
+            }]
+        });
+
+        // Prototypes registered later take precedence over those with the same priority
+        let index = layers
+            .iter()
+            .position(|layer| layer.policy.priority > policy.priority)
+            .unwrap_or(layers.len());
+        layers.insert(
+            index,
+            ProtoLayer {
+                handle: handle.clone_weak(),
+                policy,
+            },
+        );
+        self.shadowed.insert(handle.id(), id.clone());
+
+        if self.is_visible(&id, handle.id()) {
+            // The caller is responsible for notifying about this prototype's registration
+            self.restack(&id, Some(handle.id()), params)?;
+        }
+
+        if self.ids.contains_key(&handle.id()) {
+            Ok(prototype)
+        } else {
+            Err(ProtoError::Overridden {
+                id: id.to_string(),
+                path: Box::new(prototype.path().into()),
+            })
+        }
+    }
+
+    /// Registers the highest priority [layer] of the prototypes with the given ID.
+    ///
+    /// If that layer fails to register, it's removed and the next highest layer is used instead.
+    /// Any prototypes depending on one of the layers are then reloaded.
+    ///
+    /// If this changes which layer is registered, the [config] is notified and events are sent
+    /// for both the previously registered layer and the newly registered one,
+    /// unless that layer is the given `registering` handle (whose registration the caller handles).
+    /// If the same layer remains registered, it is treated as reloaded.
+    ///
+    /// [layer]: ProtoLayer
+    /// [config]: Config
+    fn restack(
+        &mut self,
+        id: &T::Id,
+        registering: Option<HandleId>,
+        params: &mut RegistryParams<T, C>,
+    ) -> Result<(), ProtoError> {
+        let previous = self.handles.get(id).map(Handle::clone_weak);
+        let handles = self
+            .layers
+            .get(id)
+            .map(|layers| {
+                layers
+                    .iter()
+                    .map(|layer| layer.handle.id())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        // 1. Unregister all layers
+        let mut dependents = HashSet::new();
+        for handle in &handles {
+            if self.ids.remove(handle).is_some() {
+                self.handles.remove(id);
+            }
+            self.trees.remove(handle);
+            self.shadowed.insert(*handle, id.clone());
+            if let Some(handle_dependents) = self.dependents.remove(handle) {
+                dependents.extend(handle_dependents);
+            }
+        }
+        dependents.retain(|dependent| !handles.contains(dependent));
+
+        // 2. Register the highest priority layer
+        let mut error = None;
+        let mut registered = None;
+        while let Some(top) = self
+            .layers
+            .get(id)
+            .and_then(|layers| layers.last())
+            .map(|layer| layer.handle.clone_weak())
+        {
+            self.shadowed.remove(&top.id());
+            if self.layers.get(id).is_some_and(|layers| layers.len() <= 1) {
+                // A single prototype no longer needs to be tracked
+                self.layers.remove(id);
+            }
+
+            match self.register_internal(&top, params, true) {
+                Ok(prototype) => {
+                    registered = Some((prototype, top));
+                    break;
+                }
+                Err(err) => {
+                    if let Some(layers) = self.layers.get_mut(id) {
+                        layers.pop();
+                    }
+                    self.failed.insert(top.id());
+                    error.get_or_insert(err);
+                }
+            }
+        }
+
+        // 3. Notify about the change to the registered prototype
+        let is_reload = matches!(
+            (&previous, &registered),
+            (Some(previous), Some((_, top))) if previous.id() == top.id()
+        );
+        if let Some(previous) = previous.filter(|_| !is_reload) {
+            let strong_handle = params.get_strong_handle(&previous);
+            params
+                .config_mut()
+                .on_unregister_prototype(id, strong_handle);
+            params.send_event(ProtoAssetEvent::Removed {
+                handle: previous,
+                id: id.clone(),
+            });
+        }
+        if let Some((prototype, top)) = &registered {
+            let strong_handle = params.get_strong_handle(top);
+            if is_reload {
+                params
+                    .config_mut()
+                    .on_reload_prototype(prototype, strong_handle);
+                params.send_event(ProtoAssetEvent::Modified {
+                    handle: top.clone_weak(),
+                    id: id.clone(),
+                });
+            } else if registering != Some(top.id()) {
+                params
+                    .config_mut()
+                    .on_register_prototype(prototype, strong_handle);
+                params.send_event(ProtoAssetEvent::Created {
+                    handle: top.clone_weak(),
+                    id: id.clone(),
+                });
+            }
+        }
+
+        // 4. Reload dependents so they use the newly registered layer
+        if registered.is_some() {
+            self.register_waiting(id, params);
+        }
+        for dependent in dependents {
+            self.reload(&Handle::weak(dependent), params).ok();
+        }
+
+        error.map_or(Ok(()), Err)
+    }
+
+    /// Removes the [layer] with the given handle from the prototypes with the given ID.
+    ///
+    /// If the layer affected the registered prototype, the layers are [restacked].
+    ///
+    /// [layer]: ProtoLayer
+    /// [restacked]: Self::restack
+    fn remove_layer(&mut self, id: &T::Id, handle: HandleId, params: &mut RegistryParams<T, C>) {
+        let is_visible = self.is_visible(id, handle);
+        let Some(layers) = self.layers.get_mut(id) else {
+            return;
+        };
+
+        layers.retain(|layer| layer.handle.id() != handle);
+        self.shadowed.remove(&handle);
+
+        if is_visible {
+            if let Err(err) = self.restack(id, None, params) {
+                error!("could not register overridden prototype: {}", err);
+            }
+        } else if layers.len() <= 1 {
+            self.layers.remove(id);
+        }
+    }
+
+    /// Returns true if the [layer] with the given handle affects the registered prototype.
+    ///
+    /// This is the case for the highest priority layer and any layers it (transitively) merges into.
+    ///
+    /// [layer]: ProtoLayer
+    fn is_visible(&self, id: &T::Id, handle: HandleId) -> bool {
+        let Some(layers) = self.layers.get(id) else {
+            return false;
+        };
+
+        layers
+            .iter()
+            .position(|layer| layer.handle.id() == handle)
+            .is_some_and(|index| {
+                layers[index + 1..]
+                    .iter()
+                    .all(|layer| layer.policy.mode == OverrideMode::Merge)
+            })
+    }
+
+    /// Register any prototypes that were waiting on the given ID to be registered.
+    ///
+    /// Since references may be resolved within a namespace,
+    /// this includes any prototypes waiting on the same name in another namespace.
+    fn register_waiting(&mut self, id: &T::Id, params: &mut RegistryParams<T, C>) {
+        let id = id.to_string();
+        let (_, name) = split_namespace(&id);
+        let Some(waiting) = self.waiting.remove(name) else {
+            return;
+        };
+
+        for handle in waiting {
+            match self.register(&Handle::weak(handle), params) {
+                // Still waiting on another prototype or overridden by another prototype
+                Ok(_)
+                | Err(
+                    ProtoError::MissingReference(_)
+                    | ProtoError::DoesNotExist(_)
+                    | ProtoError::Overridden { .. },
+                ) => {}
+                Err(err) => error!("could not register prototype: {}", err),
+            }
+        }
+    }
+
+    /// Register any prototypes that were waiting on the prototype with the given handle
+    /// to be registered.
+    fn register_pending(&mut self, handle: HandleId, params: &mut RegistryParams<T, C>) {
+        let Some(pending) = self.pending.remove(&handle) else {
+            return;
+        };
+
+        for handle in pending {
+            match self.register(&Handle::weak(handle), params) {
+                // Still waiting on another prototype or overridden by another prototype
+                Ok(_)
+                | Err(
+                    ProtoError::MissingReference(_)
+                    | ProtoError::DoesNotExist(_)
+                    | ProtoError::Overridden { .. },
+                ) => {}
+                Err(err) => error!("could not register prototype: {}", err),
+            }
+        }
+    }
+}
+
+/// The handle used by an [ID reference] to the prototype with the given ID.
+///
+/// [ID reference]: ProtoPath::from_id
+fn reference_handle<I: ToString>(id: &I) -> HandleId {
+    HandleId::from(ProtoPath::from_id(id.to_string()))
 }
 
 impl<T: Prototypical, C: Config<T>> Default for ProtoRegistry<T, C> {
@@ -228,6 +657,12 @@ impl<T: Prototypical, C: Config<T>> Default for ProtoRegistry<T, C> {
             dependents: HashMap::new(),
             load_queue: Default::default(),
             failed: HashSet::new(),
+            references: HashMap::new(),
+            waiting: HashMap::new(),
+            pending: HashMap::new(),
+            namespaced: HashMap::new(),
+            layers: HashMap::new(),
+            shadowed: HashMap::new(),
             _phantom: PhantomData,
         }
     }
@@ -254,13 +689,38 @@ impl<T: Prototypical> LoadQueue<T> {
         }
     }
 
+    pub fn get_handle<I: Borrow<T::Id>>(&self, id: I) -> Option<HandleId> {
+        self.handles.get(id.borrow()).map(Handle::id)
+    }
+
+    pub fn get_id<I: Borrow<HandleId>>(&self, handle: I) -> Option<T::Id> {
+        self.ids.get(handle.borrow()).cloned()
+    }
+
     pub fn is_queued<I: Borrow<T::Id>>(&self, id: I) -> bool {
         self.handles.contains_key(id.borrow())
     }
 
+    /// Returns true if the given ID is queued, either within the given namespace or as-is.
+    ///
+    /// See [`ProtoRegistry::resolve_id`] for details.
+    pub fn is_queued_in(&self, id: &T::Id, namespace: Option<&str>) -> bool {
+        let qualified = namespace.and_then(|namespace| qualify_id(&id.to_string(), namespace));
+        self.is_queued(id)
+            || qualified.is_some_and(|qualified| {
+                self.handles
+                    .keys()
+                    .any(|queued| queued.to_string() == qualified)
+            })
+    }
+
     pub fn is_queued_handle<I: Borrow<HandleId>>(&self, id: I) -> bool {
         self.ids.contains_key(id.borrow())
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.handles.is_empty()
+    }
 }
 
 impl<T: Prototypical> Clone for LoadQueue<T> {
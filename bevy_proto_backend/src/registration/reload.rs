@@ -0,0 +1,410 @@
+use bevy::asset::{Assets, Handle, HandleId};
+use bevy::ecs::event::{Events, ManualEventReader};
+use bevy::ecs::system::Command;
+use bevy::prelude::{error, Entity, Local, Mut, Resource, World};
+use bevy::utils::{HashMap, HashSet};
+
+use crate::assets::ProtoAssetEvent;
+use crate::proto::{Config, ProtoInsertCommand, ProtoInstance, Prototypical};
+use crate::registration::ProtoRegistry;
+use crate::schematics::{DynamicSchematic, SchematicContext, SchematicId, Schematics, Variants};
+use crate::tree::ProtoTree;
+
+/// Resource used to cache the last-applied state of registered [prototypes].
+///
+/// Once a prototype is reloaded, its asset data has already been replaced.
+/// This cache allows the stale [schematics] to still be removed from existing
+/// [instances] before the new ones are applied.
+///
+/// [prototypes]: Prototypical
+/// [schematics]: crate::schematics::Schematic
+/// [instances]: ProtoInstance
+#[derive(Resource)]
+pub(crate) struct ProtoInstanceCache<T: Prototypical> {
+    trees: HashMap<HandleId, ProtoTree<T>>,
+    schematics: HashMap<HandleId, Schematics>,
+    variants: HashMap<HandleId, Variants>,
+}
+
+impl<T: Prototypical> ProtoInstanceCache<T> {
+    /// Cache the current state of the prototype with the given handle.
+    fn store<C: Config<T>>(
+        &mut self,
+        handle: HandleId,
+        registry: &ProtoRegistry<T, C>,
+        prototypes: &Assets<T>,
+    ) {
+        if let Some(tree) = registry.get_tree(handle) {
+            self.trees.insert(handle, tree.clone());
+        }
+
+        let Some(prototype) = prototypes.get(&Handle::weak(handle)) else {
+            return;
+        };
+
+        match prototype.schematics().try_clone() {
+            Ok(schematics) => {
+                self.schematics.insert(handle, schematics);
+            }
+            Err(err) => {
+                error!(
+                    "could not cache schematics for prototype {:?}: {}",
+                    prototype.id(),
+                    err
+                );
+                self.schematics.remove(&handle);
+            }
+        }
+
+        match prototype.variants().map(Variants::try_clone) {
+            Some(Ok(variants)) => {
+                self.variants.insert(handle, variants);
+            }
+            Some(Err(err)) => {
+                error!(
+                    "could not cache variants for prototype {:?}: {}",
+                    prototype.id(),
+                    err
+                );
+                self.variants.remove(&handle);
+            }
+            None => {
+                self.variants.remove(&handle);
+            }
+        }
+    }
+
+    /// Remove the cached state of the prototype with the given handle.
+    fn remove(&mut self, handle: HandleId) {
+        self.trees.remove(&handle);
+        self.schematics.remove(&handle);
+        self.variants.remove(&handle);
+    }
+}
+
+impl<T: Prototypical> Default for ProtoInstanceCache<T> {
+    fn default() -> Self {
+        Self {
+            trees: HashMap::new(),
+            schematics: HashMap::new(),
+            variants: HashMap::new(),
+        }
+    }
+}
+
+/// The subset of [`ProtoAssetEvent`] data needed to refresh instances.
+enum InstanceChange<T: Prototypical> {
+    Created(HandleId),
+    Modified(HandleId, T::Id),
+    Removed(HandleId),
+}
+
+/// Refreshes existing [instances] of a [prototype] whenever it is reloaded.
+///
+/// If the prototype's [tree] kept its structure, only the schematics that were added,
+/// removed, or changed (according to the [`ProtoInstanceCache`]) are processed.
+/// This allows unchanged schematics to keep any runtime state they might have.
+///
+/// Otherwise, the stale schematics of every affected entity (and its children) are removed,
+/// after which the reloaded prototype is re-inserted.
+///
+/// [tree]: ProtoTree
+/// [instances]: ProtoInstance
+/// [prototype]: Prototypical
+pub(crate) fn reload_proto_instances<T: Prototypical, C: Config<T>>(
+    world: &mut World,
+    mut reader: Local<ManualEventReader<ProtoAssetEvent<T>>>,
+) {
+    let changes = reader
+        .iter(world.resource::<Events<ProtoAssetEvent<T>>>())
+        .map(|event| match event {
+            ProtoAssetEvent::Created { handle, .. } => InstanceChange::Created(handle.id()),
+            ProtoAssetEvent::Modified { handle, id } => {
+                InstanceChange::Modified(handle.id(), id.clone())
+            }
+            ProtoAssetEvent::Removed { handle, .. } => InstanceChange::Removed(handle.id()),
+        })
+        .collect::<Vec<InstanceChange<T>>>();
+
+    for change in changes {
+        match change {
+            InstanceChange::Created(handle) => store_instance_cache::<T, C>(handle, world),
+            InstanceChange::Modified(handle, id) => {
+                let entities = world
+                    .query::<(Entity, &ProtoInstance)>()
+                    .iter(world)
+                    .filter(|(_, instance)| instance.handle() == handle)
+                    .map(|(entity, _)| entity)
+                    .collect::<Vec<_>>();
+
+                for entity in entities {
+                    if !apply_schematic_diff::<T, C>(handle, entity, world) {
+                        // Re-insert with the same params and overrides the entity was spawned with
+                        let instance = world.get::<ProtoInstance>(entity).unwrap().clone();
+                        remove_stale_schematics::<T, C>(handle, entity, world);
+                        ProtoInsertCommand::<T, C>::new(id.clone(), Some(entity))
+                            .with_instance(&instance)
+                            .apply(world);
+                    }
+                }
+
+                store_instance_cache::<T, C>(handle, world);
+            }
+            InstanceChange::Removed(handle) => {
+                world.resource_mut::<ProtoInstanceCache<T>>().remove(handle);
+            }
+        }
+    }
+}
+
+fn store_instance_cache<T: Prototypical, C: Config<T>>(handle: HandleId, world: &mut World) {
+    world.resource_scope(|world, mut cache: Mut<ProtoInstanceCache<T>>| {
+        cache.store(
+            handle,
+            world.resource::<ProtoRegistry<T, C>>(),
+            world.resource::<Assets<T>>(),
+        );
+    });
+}
+
+/// Returns the seed used to select the random children of the given entity.
+fn instance_seed(entity: Entity, world: &World) -> u64 {
+    world
+        .get::<ProtoInstance>(entity)
+        .map(ProtoInstance::seed)
+        .unwrap_or_default()
+}
+
+/// Removes the cached schematics of the prototype with the given handle from the entity.
+fn remove_stale_schematics<T: Prototypical, C: Config<T>>(
+    handle: HandleId,
+    entity: Entity,
+    world: &mut World,
+) {
+    world.resource_scope(|world, cache: Mut<ProtoInstanceCache<T>>| {
+        world.resource_scope(|world, mut config: Mut<C>| {
+            let Some(tree) = cache.trees.get(&handle) else {
+                return;
+            };
+
+            let seed = instance_seed(entity, world);
+            let entity_tree = tree.to_entity_tree(Some(entity), seed, world);
+
+            for node in entity_tree.iter() {
+                entity_tree.set_current(node);
+
+                let mut context = SchematicContext::new(world, &entity_tree);
+
+                for handle_id in node.prototypes() {
+                    let variant = node.variant(handle_id).and_then(|index| {
+                        cache
+                            .variants
+                            .get(handle_id)
+                            .and_then(|variants| variants.get(index))
+                    });
+
+                    let schematics = cache.schematics.get(handle_id).into_iter().chain(variant);
+                    for (_, schematic) in schematics.flat_map(Schematics::iter) {
+                        remove_schematic::<T, C>(*handle_id, schematic, &mut *config, &mut context);
+                    }
+                }
+            }
+        });
+    });
+}
+
+/// Applies the changes between the cached and current schematics of the prototype
+/// with the given handle to the entity.
+///
+/// Returns false if the prototype's tree was not cached, changed its structure,
+/// or contains any [variants], [patches], [removed] schematics, or [params].
+/// The same is true if the entity was spawned with params or overrides.
+/// In these cases, nothing is done and the entity should be fully refreshed instead.
+///
+/// [variants]: Variants
+/// [patches]: DynamicSchematic::is_patch
+/// [removed]: Prototypical::removed_schematics
+/// [params]: Prototypical::params
+fn apply_schematic_diff<T: Prototypical, C: Config<T>>(
+    handle: HandleId,
+    entity: Entity,
+    world: &mut World,
+) -> bool {
+    world.resource_scope(|world, registry: Mut<ProtoRegistry<T, C>>| {
+        world.resource_scope(|world, cache: Mut<ProtoInstanceCache<T>>| {
+            world.resource_scope(|world, mut config: Mut<C>| {
+                world.resource_scope(|world, prototypes: Mut<Assets<T>>| {
+                    let (Some(old_tree), Some(new_tree)) =
+                        (cache.trees.get(&handle), registry.get_tree(handle))
+                    else {
+                        return false;
+                    };
+
+                    if !old_tree.matches_structure(new_tree) {
+                        return false;
+                    }
+
+                    // Removals, params, and overrides depend on the rest of the tree
+                    // (or on how the entity was spawned), so they can't be diffed
+                    let has_spawn_inputs =
+                        world.get::<ProtoInstance>(entity).is_some_and(|instance| {
+                            instance.params().is_some() || instance.overrides().is_some()
+                        });
+
+                    if has_spawn_inputs || old_tree.has_removals() || new_tree.has_removals() {
+                        return false;
+                    }
+
+                    let seed = instance_seed(entity, world);
+                    let entity_tree = new_tree.to_entity_tree(Some(entity), seed, world);
+
+                    // Variants are not diffed, so their entities need to be fully refreshed.
+                    // The same goes for patches and params, which need to be resolved first.
+                    let requires_refresh = entity_tree.iter().any(|node| {
+                        node.has_variants()
+                            || node.prototypes().any(|handle_id| {
+                                requires_resolving(&prototypes, &cache, *handle_id)
+                            })
+                    });
+
+                    if requires_refresh {
+                        return false;
+                    }
+
+                    for node in entity_tree.iter() {
+                        entity_tree.set_current(node);
+
+                        let mut context = SchematicContext::new(world, &entity_tree);
+
+                        // 1. Collect the schematics that need to be (re)applied or removed
+                        let mut dirty = HashSet::new();
+                        let mut removed = Vec::new();
+                        for handle_id in node.prototypes() {
+                            let Some(new) = get_schematics(&prototypes, *handle_id) else {
+                                continue;
+                            };
+
+                            match cache.schematics.get(handle_id) {
+                                Some(old) => {
+                                    let diff = old.diff(new);
+                                    dirty.extend(diff.added().iter().cloned());
+                                    dirty.extend(diff.changed().iter().cloned());
+                                    removed.extend(
+                                        diff.removed().iter().map(|key| (*handle_id, key.clone())),
+                                    );
+                                }
+                                None => dirty.extend(new.iter().map(|(key, _)| key.clone())),
+                            }
+                        }
+
+                        // 2. Remove schematics that are no longer defined by any prototype.
+                        //    Otherwise, reapply the remaining ones so they take effect again.
+                        for (handle_id, key) in removed {
+                            let is_defined = node.prototypes().any(|other| {
+                                get_schematics(&prototypes, *other)
+                                    .map(|schematics| schematics.contains_by_name(&key))
+                                    .unwrap_or_default()
+                            });
+
+                            if is_defined {
+                                dirty.insert(key);
+                            } else if let Some(schematic) = cache
+                                .schematics
+                                .get(&handle_id)
+                                .and_then(|schematics| schematics.get_by_name(&key))
+                            {
+                                remove_schematic::<T, C>(
+                                    handle_id,
+                                    schematic,
+                                    &mut *config,
+                                    &mut context,
+                                );
+                            }
+                        }
+
+                        // 3. Apply dirty schematics in order
+                        for handle_id in node.prototypes() {
+                            let Some(schematics) = get_schematics(&prototypes, *handle_id) else {
+                                continue;
+                            };
+
+                            for (key, schematic) in schematics.iter() {
+                                if dirty.contains(key) {
+                                    apply_schematic::<T, C>(
+                                        *handle_id,
+                                        schematic,
+                                        &mut *config,
+                                        &mut context,
+                                    );
+                                }
+                            }
+                        }
+                    }
+
+                    true
+                })
+            })
+        })
+    })
+}
+
+/// Returns true if the prototype with the given handle declares [params]
+/// or contains [patches], either currently or in its cached state.
+///
+/// [params]: Prototypical::params
+/// [patches]: DynamicSchematic::is_patch
+fn requires_resolving<T: Prototypical>(
+    prototypes: &Assets<T>,
+    cache: &ProtoInstanceCache<T>,
+    handle: HandleId,
+) -> bool {
+    let has_patches =
+        |schematics: &Schematics| schematics.iter().any(|(_, schematic)| schematic.is_patch());
+
+    let is_parameterized = prototypes
+        .get(&Handle::weak(handle))
+        .is_some_and(|prototype| prototype.params().is_some());
+
+    is_parameterized
+        || get_schematics(prototypes, handle).is_some_and(has_patches)
+        || cache.schematics.get(&handle).is_some_and(has_patches)
+}
+
+fn get_schematics<T: Prototypical>(
+    prototypes: &Assets<T>,
+    handle: HandleId,
+) -> Option<&Schematics> {
+    prototypes
+        .get(&Handle::weak(handle))
+        .map(Prototypical::schematics)
+}
+
+fn apply_schematic<T: Prototypical, C: Config<T>>(
+    handle: HandleId,
+    schematic: &DynamicSchematic,
+    config: &mut C,
+    context: &mut SchematicContext,
+) {
+    let id = SchematicId::new(handle, schematic.type_info().type_id());
+
+    config.on_before_apply_schematic(schematic, id.clone(), context);
+    if let Err(err) = schematic.apply(id.clone(), context) {
+        error!("could not apply reloaded schematic: {}", err);
+    }
+    config.on_after_apply_schematic(schematic, id, context);
+}
+
+fn remove_schematic<T: Prototypical, C: Config<T>>(
+    handle: HandleId,
+    schematic: &DynamicSchematic,
+    config: &mut C,
+    context: &mut SchematicContext,
+) {
+    let id = SchematicId::new(handle, schematic.type_info().type_id());
+
+    config.on_before_remove_schematic(schematic, id.clone(), context);
+    if let Err(err) = schematic.remove(id.clone(), context) {
+        error!("could not remove stale schematic: {}", err);
+    }
+    config.on_after_remove_schematic(schematic, id, context);
+}
@@ -1,7 +1,7 @@
 use bevy::asset::AssetEvent;
-use bevy::prelude::{error, EventReader};
+use bevy::prelude::{debug, error, EventReader};
 
-use crate::proto::{Config, Prototypical};
+use crate::proto::{Config, ProtoError, Prototypical};
 use crate::registration::ProtoManager;
 
 /// Handles the registration of loaded, modified, and removed prototypes.
@@ -11,16 +11,35 @@ pub(crate) fn on_proto_asset_event<T: Prototypical, C: Config<T>>(
 ) {
     for event in events.iter() {
         match event {
-            AssetEvent::Created { handle } => {
-                if let Err(err) = manager.register(handle) {
-                    error!("could not register prototype: {}", err);
+            AssetEvent::Created { handle } => match manager.register(handle) {
+                Ok(_) => {}
+                Err(ProtoError::MissingReference(id)) => {
+                    debug!(
+                        "deferring registration of prototype until {:?} is registered",
+                        id
+                    );
                 }
-            }
-            AssetEvent::Modified { handle } => {
-                if let Err(err) = manager.reload(handle) {
-                    error!("could not reload modified prototype: {}", err);
+                Err(ProtoError::DoesNotExist(missing)) if missing.id() != handle.id() => {
+                    debug!(
+                        "deferring registration of prototype until {:?} is loaded",
+                        missing
+                    );
                 }
-            }
+                Err(ProtoError::Overridden { id, .. }) => {
+                    debug!("prototype {:?} is overridden by another prototype", id);
+                }
+                Err(err) => error!("could not register prototype: {}", err),
+            },
+            AssetEvent::Modified { handle } => match manager.reload(handle) {
+                Ok(_) => {}
+                Err(ProtoError::MissingReference(id)) => {
+                    debug!("deferring reload of prototype until {:?} is registered", id);
+                }
+                Err(ProtoError::Overridden { id, .. }) => {
+                    debug!("prototype {:?} is overridden by another prototype", id);
+                }
+                Err(err) => error!("could not reload modified prototype: {}", err),
+            },
             AssetEvent::Removed { handle } => {
                 manager.unregister(handle);
             }
@@ -1,10 +1,11 @@
 use std::borrow::Cow;
 use std::fmt::{Debug, Formatter};
 
+use bevy::reflect::Reflect;
 use bevy::utils::hashbrown::hash_map::{IntoIter, Iter, IterMut};
 use bevy::utils::HashMap;
 
-use crate::schematics::{DynamicSchematic, Schematic};
+use crate::schematics::{DynamicSchematic, Schematic, SchematicError, SchematicsDiff};
 
 /// A collection of [schematics] for a [prototype].
 ///
@@ -74,6 +75,13 @@ impl Schematics {
         self.0.insert(key, schematic)
     }
 
+    /// Insert a new schematic [patch].
+    ///
+    /// [patch]: DynamicSchematic::new_patch
+    pub fn insert_patch<T: Schematic>(&mut self, patch: impl Reflect) -> Option<DynamicSchematic> {
+        self.insert_dynamic(DynamicSchematic::new_patch::<T>(patch))
+    }
+
     /// Insert a new schematic dynamically.
     pub fn insert_dynamic(&mut self, schematic: DynamicSchematic) -> Option<DynamicSchematic> {
         let key = Cow::Borrowed(schematic.type_info().type_name());
@@ -111,6 +119,24 @@ impl Schematics {
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+
+    /// Compute the [difference] going from this collection to the given one.
+    ///
+    /// [difference]: SchematicsDiff
+    pub fn diff(&self, other: &Schematics) -> SchematicsDiff {
+        SchematicsDiff::new(self, other)
+    }
+
+    /// Attempts to clone this collection by [cloning] each of its schematics.
+    ///
+    /// [cloning]: DynamicSchematic::try_clone
+    pub fn try_clone(&self) -> Result<Self, SchematicError> {
+        self.0
+            .iter()
+            .map(|(key, schematic)| Ok((key.clone(), schematic.try_clone()?)))
+            .collect::<Result<HashMap<_, _>, _>>()
+            .map(Self)
+    }
 }
 
 impl Debug for Schematics {
@@ -0,0 +1,124 @@
+use std::fmt::{Debug, Formatter};
+
+use bevy::ecs::world::EntityMut;
+use bevy::prelude::{Bundle, Entity, FromReflect, Reflect, World};
+
+use crate::schematics::SchematicError;
+
+/// The final value of a [`Schematic`], converted from its [input] ahead of time.
+///
+/// Since this value does not depend on the entity it's applied to,
+/// it only needs to be created once per prototype.
+/// Spawning then simply inserts a copy of it, without going through [`Schematic::apply`].
+///
+/// This is created by [`Schematic::compile`].
+///
+/// [`Schematic`]: crate::schematics::Schematic
+/// [input]: crate::schematics::Schematic::Input
+/// [`Schematic::apply`]: crate::schematics::Schematic::apply
+/// [`Schematic::compile`]: crate::schematics::Schematic::compile
+pub struct CompiledSchematic {
+    value: Box<dyn Reflect>,
+    insert: fn(&dyn Reflect, &mut EntityMut) -> Result<(), SchematicError>,
+    insert_batch: fn(&dyn Reflect, &[Entity], &mut World) -> Result<(), SchematicError>,
+}
+
+impl CompiledSchematic {
+    /// Create a compiled schematic that inserts a clone of the given component or bundle.
+    pub fn new<B: Bundle + Reflect + Clone>(value: B) -> Self {
+        Self {
+            value: Box::new(value),
+            insert: |value, entity| insert(value, entity, clone_value::<B>),
+            insert_batch: |value, entities, world| {
+                insert_batch(value, entities, world, clone_value::<B>)
+            },
+        }
+    }
+
+    /// Create a compiled schematic that inserts a copy of the given component or bundle,
+    /// created using its [`FromReflect`] implementation.
+    ///
+    /// This allows types that don't implement [`Clone`] to be compiled.
+    /// Otherwise, prefer [`CompiledSchematic::new`] since it doesn't need to convert
+    /// the value on every insert.
+    pub fn from_reflect<B: Bundle + FromReflect>(value: B) -> Self {
+        Self {
+            value: Box::new(value),
+            insert: |value, entity| insert(value, entity, reflect_value::<B>),
+            insert_batch: |value, entities, world| {
+                insert_batch(value, entities, world, reflect_value::<B>)
+            },
+        }
+    }
+
+    /// Get a reference to the compiled value.
+    pub fn value(&self) -> &dyn Reflect {
+        &*self.value
+    }
+
+    /// Insert a copy of the compiled value into the given entity.
+    pub fn insert(&self, entity: &mut EntityMut) -> Result<(), SchematicError> {
+        (self.insert)(&*self.value, entity)
+    }
+
+    /// Insert a copy of the compiled value into each of the given entities at once.
+    ///
+    /// Nothing is inserted if the value could not be copied.
+    /// If any of the entities no longer exist, the value is still inserted
+    /// into the remaining entities before the error is returned.
+    pub fn insert_batch(
+        &self,
+        entities: &[Entity],
+        world: &mut World,
+    ) -> Result<(), SchematicError> {
+        (self.insert_batch)(&*self.value, entities, world)
+    }
+}
+
+impl Debug for CompiledSchematic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CompiledSchematic")
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+fn insert<B: Bundle>(
+    value: &dyn Reflect,
+    entity: &mut EntityMut,
+    copy: fn(&dyn Reflect) -> Result<B, SchematicError>,
+) -> Result<(), SchematicError> {
+    entity.insert(copy(value)?);
+    Ok(())
+}
+
+fn insert_batch<B: Bundle>(
+    value: &dyn Reflect,
+    entities: &[Entity],
+    world: &mut World,
+    copy: fn(&dyn Reflect) -> Result<B, SchematicError>,
+) -> Result<(), SchematicError> {
+    let batch = entities
+        .iter()
+        .map(|entity| Ok((*entity, copy(value)?)))
+        .collect::<Result<Vec<_>, SchematicError>>()?;
+
+    world
+        .insert_or_spawn_batch(batch)
+        .map_err(SchematicError::MissingEntities)
+}
+
+/// Clones the given value, which is always of type `B`.
+fn clone_value<B: Reflect + Clone>(value: &dyn Reflect) -> Result<B, SchematicError> {
+    value
+        .downcast_ref::<B>()
+        .cloned()
+        .ok_or_else(|| SchematicError::TypeMismatch {
+            expected: std::any::type_name::<B>(),
+            found: value.type_name().to_string(),
+        })
+}
+
+fn reflect_value<B: FromReflect>(value: &dyn Reflect) -> Result<B, SchematicError> {
+    B::from_reflect(value).ok_or(SchematicError::FromReflectFail)
+}
@@ -1,3 +1,4 @@
+use crate::schematics::SchematicError;
 use crate::tree::{EntityAccess, EntityTree};
 use bevy::ecs::world::{EntityMut, EntityRef};
 use bevy::prelude::{Entity, World};
@@ -6,11 +7,33 @@ use bevy::prelude::{Entity, World};
 pub struct SchematicContext<'a, 'b> {
     world: &'a mut World,
     tree: &'a EntityTree<'b>,
+    fallible: bool,
+    error: Option<SchematicError>,
 }
 
 impl<'a, 'b> SchematicContext<'a, 'b> {
     pub(crate) fn new(world: &'a mut World, tree: &'a EntityTree<'b>) -> Self {
-        Self { world, tree }
+        Self {
+            world,
+            tree,
+            fallible: false,
+            error: None,
+        }
+    }
+
+    /// Sets whether errors [reported] by schematics should be returned rather than panic.
+    ///
+    /// [reported]: Self::fail
+    pub(crate) fn with_fallible(mut self, fallible: bool) -> Self {
+        self.fallible = fallible;
+        self
+    }
+
+    /// Takes the first error [reported] by a schematic, if any.
+    ///
+    /// [reported]: Self::fail
+    pub(crate) fn take_error(&mut self) -> Option<SchematicError> {
+        self.error.take()
     }
 
     /// Returns a reference to the world.
@@ -40,6 +63,33 @@ impl<'a, 'b> SchematicContext<'a, 'b> {
         self.tree.find_entity(access)
     }
 
+    /// Reports that the current schematic could not be applied (or removed).
+    ///
+    /// When the prototype is spawned with [`ProtoCommands::try_spawn`] (or similar),
+    /// the error is surfaced as a [`ProtoSpawnFailed`] event once the schematic returns,
+    /// and no further schematics are processed.
+    /// Otherwise, this panics.
+    ///
+    /// [`ProtoCommands::try_spawn`]: crate::proto::ProtoCommands::try_spawn
+    /// [`ProtoSpawnFailed`]: crate::proto::ProtoSpawnFailed
+    pub fn fail(&mut self, error: SchematicError) {
+        if !self.fallible {
+            panic!("{}", error);
+        }
+
+        self.error.get_or_insert(error);
+    }
+
+    /// Returns true if the current schematic has [reported] an error.
+    ///
+    /// Schematics should check this before modifying the world with any values
+    /// that were converted using the context, since those may just be placeholders.
+    ///
+    /// [reported]: Self::fail
+    pub fn has_failed(&self) -> bool {
+        self.error.is_some()
+    }
+
     /// Returns a reference to the entity tree.
     pub fn tree(&self) -> &EntityTree {
         self.tree
@@ -0,0 +1,120 @@
+use std::borrow::Cow;
+
+use crate::schematics::Schematics;
+
+/// The difference between two [`Schematics`] collections.
+///
+/// Schematics are matched by their [type name] and compared
+/// using [`DynamicSchematic::reflect_partial_eq`].
+///
+/// This is generated by [`Schematics::diff`].
+///
+/// [type name]: std::any::type_name
+/// [`DynamicSchematic::reflect_partial_eq`]: crate::schematics::DynamicSchematic::reflect_partial_eq
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SchematicsDiff {
+    added: Vec<Cow<'static, str>>,
+    removed: Vec<Cow<'static, str>>,
+    changed: Vec<Cow<'static, str>>,
+}
+
+impl SchematicsDiff {
+    /// Compute the difference going from the `old` schematics to the `new` ones.
+    pub(crate) fn new(old: &Schematics, new: &Schematics) -> Self {
+        let mut diff = Self::default();
+
+        for (key, new_schematic) in new.iter() {
+            match old.get_by_name(key) {
+                None => diff.added.push(key.clone()),
+                Some(old_schematic) => {
+                    // Schematics that cannot be compared are assumed to have changed
+                    if old_schematic.reflect_partial_eq(new_schematic) != Some(true) {
+                        diff.changed.push(key.clone());
+                    }
+                }
+            }
+        }
+
+        for (key, _) in old.iter() {
+            if !new.contains_by_name(key) {
+                diff.removed.push(key.clone());
+            }
+        }
+
+        diff
+    }
+
+    /// The type names of schematics that only exist in the new collection.
+    pub fn added(&self) -> &[Cow<'static, str>] {
+        &self.added
+    }
+
+    /// The type names of schematics that only exist in the old collection.
+    pub fn removed(&self) -> &[Cow<'static, str>] {
+        &self.removed
+    }
+
+    /// The type names of schematics that exist in both collections but whose inputs differ.
+    pub fn changed(&self) -> &[Cow<'static, str>] {
+        &self.changed
+    }
+
+    /// Returns true if the two collections are equivalent.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::{GlobalTransform, Transform};
+
+    use super::*;
+
+    #[test]
+    fn should_be_empty_for_equal_schematics() {
+        let mut old = Schematics::default();
+        old.insert::<Transform>(Transform::from_xyz(1.0, 2.0, 3.0));
+        let mut new = Schematics::default();
+        new.insert::<Transform>(Transform::from_xyz(1.0, 2.0, 3.0));
+
+        assert!(old.diff(&new).is_empty());
+    }
+
+    #[test]
+    fn should_detect_added_and_changed_schematics() {
+        let mut old = Schematics::default();
+        old.insert::<Transform>(Transform::from_xyz(1.0, 2.0, 3.0));
+        let mut new = Schematics::default();
+        new.insert::<Transform>(Transform::from_xyz(3.0, 2.0, 1.0));
+        new.insert::<GlobalTransform>(GlobalTransform::default());
+
+        let diff = old.diff(&new);
+        assert_eq!(
+            &[Cow::Borrowed(std::any::type_name::<GlobalTransform>())],
+            diff.added()
+        );
+        assert_eq!(
+            &[Cow::Borrowed(std::any::type_name::<Transform>())],
+            diff.changed()
+        );
+        assert!(diff.removed().is_empty());
+    }
+
+    #[test]
+    fn should_detect_removed_schematics() {
+        let mut old = Schematics::default();
+        old.insert::<Transform>(Transform::default());
+        old.insert::<GlobalTransform>(GlobalTransform::default());
+        let mut new = Schematics::default();
+        new.insert::<Transform>(Transform::default());
+
+        let diff = old.diff(&new);
+        assert_eq!(
+            &[Cow::Borrowed(std::any::type_name::<GlobalTransform>())],
+            diff.removed()
+        );
+        assert!(diff.added().is_empty());
+        assert!(diff.changed().is_empty());
+    }
+}
@@ -5,7 +5,7 @@ use bevy::reflect::{FromType, GetTypeRegistration, TypeInfo, TypeRegistration, T
 
 use crate::deps::DependenciesBuilder;
 use crate::schematics::schematic::Schematic;
-use crate::schematics::{SchematicContext, SchematicError, SchematicId};
+use crate::schematics::{CompiledSchematic, SchematicContext, SchematicError, SchematicId};
 
 /// A dynamic representation of a [`Schematic`].
 ///
@@ -15,10 +15,19 @@ use crate::schematics::{SchematicContext, SchematicError, SchematicId};
 /// This is generated from [`ReflectSchematic::create_dynamic`] and can be used
 /// to use a type-erased version of the schematic.
 ///
+/// # Patches
+///
+/// A [patch] is a special kind of `DynamicSchematic` whose input only contains
+/// a subset of the actual input data.
+/// Rather than replacing the input of the same schematic defined by a template,
+/// a patch is applied on top of it using [`Reflect::apply`].
+///
 /// [input]: Schematic::Input
+/// [patch]: ReflectSchematic::create_patch
 pub struct DynamicSchematic {
     input: Box<dyn Reflect>,
     reflect_schematic: ReflectSchematic,
+    is_patch: bool,
 }
 
 impl DynamicSchematic {
@@ -26,9 +35,33 @@ impl DynamicSchematic {
         Self {
             input: Box::new(input),
             reflect_schematic: <ReflectSchematic as FromType<T>>::from_type(),
+            is_patch: false,
         }
     }
 
+    /// Create a new [patch] for the given schematic.
+    ///
+    /// The patch should be a partial representation of the schematic's [input],
+    /// such as a [`DynamicStruct`] containing only the fields to change.
+    ///
+    /// [patch]: ReflectSchematic::create_patch
+    /// [input]: Schematic::Input
+    /// [`DynamicStruct`]: bevy::reflect::DynamicStruct
+    pub fn new_patch<T: Schematic>(patch: impl Reflect) -> Self {
+        <ReflectSchematic as FromType<T>>::from_type().create_patch(Box::new(patch))
+    }
+
+    /// Returns true if this schematic is a [patch].
+    ///
+    /// The input of a patch is only partial and must first be [resolved]
+    /// before the schematic can be applied.
+    ///
+    /// [patch]: ReflectSchematic::create_patch
+    /// [resolved]: Self::try_resolve
+    pub fn is_patch(&self) -> bool {
+        self.is_patch
+    }
+
     /// Get a reference to the reflected [schematic input] data.
     ///
     /// [schematic input]: Schematic::Input
@@ -62,14 +95,35 @@ impl DynamicSchematic {
     }
 
     /// Dynamically call the corresponding [`Schematic::preload_dependencies`] method.
+    ///
+    /// This does nothing for [patches] since their input is only partial.
+    ///
+    /// [patches]: Self::is_patch
     pub fn preload_dependencies(
         &mut self,
         id: SchematicId,
         dependencies: &mut DependenciesBuilder,
     ) -> Result<(), SchematicError> {
+        if self.is_patch {
+            return Ok(());
+        }
+
         (self.reflect_schematic.preload_dependencies)(&mut *self.input, id, dependencies)
     }
 
+    /// Dynamically call the corresponding [`Schematic::compile`] method.
+    ///
+    /// This always returns `None` for [patches] since their input is only partial.
+    ///
+    /// [patches]: Self::is_patch
+    pub fn compile(&self, id: SchematicId) -> Result<Option<CompiledSchematic>, SchematicError> {
+        if self.is_patch {
+            return Ok(None);
+        }
+
+        (self.reflect_schematic.compile)(&*self.input, id)
+    }
+
     /// The type info of the corresponding [`Schematic`].
     pub fn type_info(&self) -> &'static TypeInfo {
         self.reflect_schematic.type_info()
@@ -82,13 +136,67 @@ impl DynamicSchematic {
         self.reflect_schematic.input_registration()
     }
 
+    /// Compares this [`DynamicSchematic`] with another using [`Reflect::reflect_partial_eq`].
+    ///
+    /// Returns `Some(false)` if the two are not the same schematic type,
+    /// and `None` if their inputs cannot be compared.
+    pub fn reflect_partial_eq(&self, other: &DynamicSchematic) -> Option<bool> {
+        if self.type_info().type_id() != other.type_info().type_id() {
+            return Some(false);
+        }
+
+        self.input.reflect_partial_eq(other.input())
+    }
+
     /// Attempts to clone this [`DynamicSchematic`].
     pub fn try_clone(&self) -> Result<Self, SchematicError> {
+        let input = if self.is_patch {
+            self.input.clone_value()
+        } else {
+            (self.reflect_schematic.clone_input)(&*self.input)?
+        };
+
         Ok(Self {
-            input: (self.reflect_schematic.clone_input)(&*self.input)?,
+            input,
             reflect_schematic: self.reflect_schematic.clone(),
+            is_patch: self.is_patch,
         })
     }
+
+    /// Returns a copy of this schematic with the given [patch] applied on top of it.
+    ///
+    /// If this schematic is not itself a patch, the returned schematic will not be either.
+    ///
+    /// [patch]: ReflectSchematic::create_patch
+    pub fn with_patch(&self, patch: &DynamicSchematic) -> Result<Self, SchematicError> {
+        if self.type_info().type_id() != patch.type_info().type_id() {
+            return Err(SchematicError::TypeMismatch {
+                expected: self.type_info().type_name(),
+                found: patch.type_info().type_name().to_string(),
+            });
+        }
+
+        let mut schematic = self.try_clone()?;
+        schematic.input.apply(patch.input());
+        Ok(schematic)
+    }
+
+    /// Attempts to convert this schematic into one that can be applied.
+    ///
+    /// For [patches], this requires the partial input to contain enough data to
+    /// create the full input using [`FromReflect`].
+    /// All other schematics are simply [cloned].
+    ///
+    /// [patches]: Self::is_patch
+    /// [cloned]: Self::try_clone
+    pub fn try_resolve(&self) -> Result<Self, SchematicError> {
+        if self.is_patch {
+            self.reflect_schematic
+                .create_dynamic(self.input.clone_value())
+        } else {
+            self.try_clone()
+        }
+    }
 }
 
 impl Debug for DynamicSchematic {
@@ -96,6 +204,7 @@ impl Debug for DynamicSchematic {
         f.debug_struct("DynamicSchematic")
             .field("type_name", &self.reflect_schematic.type_info.type_name())
             .field("input", &self.input)
+            .field("is_patch", &self.is_patch)
             .finish()
     }
 }
@@ -125,6 +234,10 @@ pub struct ReflectSchematic {
         id: SchematicId,
         dependencies: &mut DependenciesBuilder,
     ) -> Result<(), SchematicError>,
+    compile: fn(
+        input: &dyn Reflect,
+        id: SchematicId,
+    ) -> Result<Option<CompiledSchematic>, SchematicError>,
     clone_input: fn(input: &dyn Reflect) -> Result<Box<dyn Reflect>, SchematicError>,
 }
 
@@ -140,6 +253,23 @@ impl ReflectSchematic {
         (self.create_dynamic)(schematic_input, data)
     }
 
+    /// Create a [`DynamicSchematic`] patch using the given partial reflected [schematic input] data.
+    ///
+    /// Unlike [`create_dynamic`], the input does not need to be complete.
+    /// Instead, it will be [applied] on top of the input of the same schematic
+    /// inherited from a template.
+    ///
+    /// [schematic input]: Schematic::Input
+    /// [`create_dynamic`]: Self::create_dynamic
+    /// [applied]: Reflect::apply
+    pub fn create_patch(&self, patch: Box<dyn Reflect>) -> DynamicSchematic {
+        DynamicSchematic {
+            input: patch,
+            reflect_schematic: self.clone(),
+            is_patch: true,
+        }
+    }
+
     /// The type info of the corresponding [`Schematic`].
     pub fn type_info(&self) -> &'static TypeInfo {
         self.type_info
@@ -165,6 +295,7 @@ impl<T: Schematic> FromType<T> for ReflectSchematic {
                 Ok(DynamicSchematic {
                     input: Box::new(input),
                     reflect_schematic: data,
+                    is_patch: false,
                 })
             },
             apply: |reflect_input, id, context| {
@@ -198,6 +329,15 @@ impl<T: Schematic> FromType<T> for ReflectSchematic {
                 <T as Schematic>::preload_dependencies(input, id, dependencies);
                 Ok(())
             },
+            compile: |reflect_input, id| {
+                let input = reflect_input.downcast_ref::<T::Input>().ok_or_else(|| {
+                    SchematicError::TypeMismatch {
+                        expected: std::any::type_name::<T::Input>(),
+                        found: reflect_input.type_name().to_string(),
+                    }
+                })?;
+                Ok(<T as Schematic>::compile(input, id))
+            },
             clone_input: |reflect_input| {
                 <T::Input as FromReflect>::from_reflect(reflect_input)
                     .map(|input| Box::new(input) as Box<dyn Reflect>)
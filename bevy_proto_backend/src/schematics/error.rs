@@ -1,3 +1,6 @@
+use std::path::PathBuf;
+
+use bevy::prelude::Entity;
 use thiserror::Error;
 
 /// [`Schematic`]-related error.
@@ -20,4 +23,15 @@ pub enum SchematicError {
         expected: &'static str,
         found: String,
     },
+    /// No entity could be found at the given [`EntityAccess`] path.
+    ///
+    /// [`EntityAccess`]: crate::tree::EntityAccess
+    #[error("entity should exist at path {0:?}")]
+    MissingEntity(PathBuf),
+    /// A [`CompiledSchematic`] could not be inserted into the given entities
+    /// since they no longer exist.
+    ///
+    /// [`CompiledSchematic`]: crate::schematics::CompiledSchematic
+    #[error("entities should exist: {0:?}")]
+    MissingEntities(Vec<Entity>),
 }
@@ -39,15 +39,21 @@
 
 pub use bevy_proto_derive::Schematic;
 pub use collection::*;
+pub use compiled::*;
 pub use context::*;
+pub use diff::*;
 pub use dynamic::*;
 pub use error::*;
 pub use id::*;
 pub use schematic::*;
+pub use variants::*;
 
 mod collection;
+mod compiled;
 mod context;
+mod diff;
 mod dynamic;
 mod error;
 mod id;
 mod schematic;
+mod variants;
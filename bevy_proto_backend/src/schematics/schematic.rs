@@ -2,7 +2,7 @@ use bevy::prelude::{FromReflect, Reflect};
 use bevy::reflect::{GetTypeRegistration, Typed};
 
 use crate::deps::DependenciesBuilder;
-use crate::schematics::{SchematicContext, SchematicId};
+use crate::schematics::{CompiledSchematic, SchematicContext, SchematicId};
 
 /// Trait used to create a [prototype] schematic for modifying an [entity]
 /// (or the [world] in general).
@@ -61,6 +61,21 @@ pub trait Schematic: Reflect + Typed {
     ) {
         // By default, do nothing.
     }
+
+    /// Converts the given input into its final value ahead of time, if possible.
+    ///
+    /// The [compiled schematic] is created once per prototype and then inserted
+    /// into every spawned entity in place of calling [`Schematic::apply`].
+    /// It should therefore only be returned if applying this schematic does nothing more
+    /// than insert a value that doesn't depend on the [`SchematicContext`].
+    ///
+    /// By default, returns `None`.
+    ///
+    /// [compiled schematic]: CompiledSchematic
+    #[allow(unused_variables)]
+    fn compile(input: &Self::Input, id: SchematicId) -> Option<CompiledSchematic> {
+        None
+    }
 }
 
 /// A custom [`From`]-like trait used to convert the [input] of a [schematic]
@@ -0,0 +1,98 @@
+use std::fmt::{Debug, Formatter};
+use std::slice::{Iter, IterMut};
+
+use crate::schematics::{SchematicError, Schematics};
+
+/// A collection of alternative [`Schematics`] for a [prototype].
+///
+/// Each spawned instance of the prototype applies exactly one of these variants
+/// on top of the prototype's regular schematics.
+/// The variant is selected using the instance's [seed],
+/// so spawning with the same seed always results in the same variant.
+///
+/// # Order
+///
+/// Insertion order matters.
+/// Variants are selected by index, so reordering them will change which variant
+/// a given seed selects.
+///
+/// [prototype]: crate::proto::Prototypical
+/// [seed]: crate::proto::ProtoInstance::seed
+#[derive(Default)]
+pub struct Variants(Vec<Schematics>);
+
+impl Variants {
+    /// Create an empty [`Variants`] with the given capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(Vec::with_capacity(capacity))
+    }
+
+    /// Add a new variant.
+    pub fn push(&mut self, schematics: Schematics) {
+        self.0.push(schematics);
+    }
+
+    /// Get the variant at the given index.
+    pub fn get(&self, index: usize) -> Option<&Schematics> {
+        self.0.get(index)
+    }
+
+    /// Get the variant at the given index mutably.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut Schematics> {
+        self.0.get_mut(index)
+    }
+
+    /// Iterate over the variants in the order they were inserted.
+    pub fn iter(&self) -> Iter<'_, Schematics> {
+        self.0.iter()
+    }
+
+    /// Iterate mutably over the variants in the order they were inserted.
+    pub fn iter_mut(&mut self) -> IterMut<'_, Schematics> {
+        self.0.iter_mut()
+    }
+
+    /// The number of variants.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if there are no variants.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Attempts to clone this collection by [cloning] each of its variants.
+    ///
+    /// [cloning]: Schematics::try_clone
+    pub fn try_clone(&self) -> Result<Self, SchematicError> {
+        self.0
+            .iter()
+            .map(Schematics::try_clone)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self)
+    }
+}
+
+impl Debug for Variants {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Variants(")?;
+        f.debug_list().entries(self.0.iter()).finish()?;
+        write!(f, ")")
+    }
+}
+
+impl FromIterator<Schematics> for Variants {
+    fn from_iter<T: IntoIterator<Item = Schematics>>(iter: T) -> Self {
+        Self(Vec::from_iter(iter))
+    }
+}
+
+impl IntoIterator for Variants {
+    type Item = Schematics;
+    type IntoIter = std::vec::IntoIter<Schematics>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
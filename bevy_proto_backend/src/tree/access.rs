@@ -8,7 +8,7 @@ use bevy::prelude::Entity;
 use bevy::reflect::{std_traits::ReflectDefault, Reflect, ReflectDeserialize};
 use serde::Deserialize;
 
-use crate::schematics::{FromSchematicInput, SchematicContext, SchematicId};
+use crate::schematics::{FromSchematicInput, SchematicContext, SchematicError, SchematicId};
 
 /// A deserializable prototype entity reference.
 ///
@@ -300,32 +300,28 @@ impl<T: AsRef<Path>> From<T> for EntityAccess {
                     let path = path.to_string_lossy();
 
                     if let Some(index) = path.strip_prefix('@') {
-                        if let Some((index, id)) = index.split_once(':') {
-                            // "foo/bar/@3:baz"
-                            let occurrence = NonZeroIsize::from_str(index.trim()).unwrap();
-                            access.ops.push(AccessOp::Child(ChildAccess::Id(
-                                id.trim().to_string(),
-                                occurrence,
-                            )));
-                        } else {
+                        if let Ok(index) = isize::from_str(index.trim()) {
                             // "foo/bar/@3"
-                            let index = isize::from_str(index.trim()).unwrap();
                             access.ops.push(AccessOp::Child(ChildAccess::At(index)));
+                        } else {
+                            // "foo/bar/@3:baz"
+                            let (id, occurrence) = parse_occurrence(index);
+                            access
+                                .ops
+                                .push(AccessOp::Child(ChildAccess::Id(id, occurrence)));
                         }
                     } else if let Some(index) = path.strip_prefix('~') {
-                        if let Some((index, id)) = index.split_once(':') {
-                            // "foo/bar/~3:baz"
-                            let occurrence = NonZeroIsize::from_str(index.trim()).unwrap();
-                            access.ops.push(AccessOp::Sibling(SiblingAccess::Id(
-                                id.trim().to_string(),
-                                occurrence,
-                            )));
-                        } else {
+                        if let Ok(offset) = NonZeroIsize::from_str(index.trim()) {
                             // "foo/bar/~3"
-                            let offset = NonZeroIsize::from_str(index.trim()).unwrap();
                             access
                                 .ops
                                 .push(AccessOp::Sibling(SiblingAccess::At(offset)));
+                        } else {
+                            // "foo/bar/~3:baz" or "foo/bar/~baz"
+                            let (id, occurrence) = parse_occurrence(index);
+                            access
+                                .ops
+                                .push(AccessOp::Sibling(SiblingAccess::Id(id, occurrence)));
                         }
                     } else {
                         // "foo/bar/baz"
@@ -342,20 +338,36 @@ impl<T: AsRef<Path>> From<T> for EntityAccess {
     }
 }
 
+/// Splits an optional occurrence prefix (i.e. `3:`) from the given ID.
+///
+/// Since IDs may themselves contain a `:` (such as namespaced IDs like `base:Sword`),
+/// the prefix is only treated as an occurrence if it is a valid non-zero integer.
+fn parse_occurrence(value: &str) -> (String, NonZeroIsize) {
+    match value.split_once(':') {
+        Some((index, id)) => match NonZeroIsize::from_str(index.trim()) {
+            Ok(occurrence) => (id.trim().to_string(), occurrence),
+            Err(_) => (value.trim().to_string(), get_one()),
+        },
+        None => (value.trim().to_string(), get_one()),
+    }
+}
+
 impl FromSchematicInput<EntityAccess> for Entity {
     fn from_input(input: EntityAccess, _id: SchematicId, context: &mut SchematicContext) -> Self {
-        context
-            .find_entity(&input)
-            .unwrap_or_else(|| panic!("entity should exist at path {:?}", input.to_path()))
+        context.find_entity(&input).unwrap_or_else(|| {
+            context.fail(SchematicError::MissingEntity(input.to_path()));
+            Entity::PLACEHOLDER
+        })
     }
 }
 
 impl FromSchematicInput<ProtoEntity> for Entity {
     fn from_input(input: ProtoEntity, _id: SchematicId, context: &mut SchematicContext) -> Self {
         let access: EntityAccess = input.into();
-        context
-            .find_entity(&access)
-            .unwrap_or_else(|| panic!("entity should exist at path {:?}", access.to_path()))
+        context.find_entity(&access).unwrap_or_else(|| {
+            context.fail(SchematicError::MissingEntity(access.to_path()));
+            Entity::PLACEHOLDER
+        })
     }
 }
 
@@ -403,9 +415,10 @@ impl FromSchematicInput<ProtoEntityList> for Vec<Entity> {
             .into_iter()
             .map(|entity| {
                 let access: EntityAccess = entity.into();
-                context
-                    .find_entity(&access)
-                    .unwrap_or_else(|| panic!("entity should exist at path {:?}", access.to_path()))
+                context.find_entity(&access).unwrap_or_else(|| {
+                    context.fail(SchematicError::MissingEntity(access.to_path()));
+                    Entity::PLACEHOLDER
+                })
             })
             .collect()
     }
@@ -416,3 +429,71 @@ impl Debug for EntityAccess {
         write!(f, "{:?}", self.to_path())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nz(value: isize) -> NonZeroIsize {
+        NonZeroIsize::new(value).unwrap()
+    }
+
+    #[test]
+    fn should_parse_occurrence() {
+        assert_eq!((String::from("Sword"), nz(1)), parse_occurrence("Sword"));
+        assert_eq!((String::from("Sword"), nz(3)), parse_occurrence("3:Sword"));
+        assert_eq!(
+            (String::from("Sword"), nz(-2)),
+            parse_occurrence("-2:Sword")
+        );
+        // A namespace is not an occurrence
+        assert_eq!(
+            (String::from("base:Sword"), nz(1)),
+            parse_occurrence("base:Sword")
+        );
+        assert_eq!(
+            (String::from("base:Sword"), nz(3)),
+            parse_occurrence("3:base:Sword")
+        );
+    }
+
+    #[test]
+    fn should_parse_namespaced_child() {
+        assert_eq!(
+            vec![AccessOp::Child(ChildAccess::Id(
+                String::from("base:Sword"),
+                nz(1)
+            ))],
+            EntityAccess::from("@base:Sword").ops
+        );
+        assert_eq!(
+            vec![AccessOp::Child(ChildAccess::Id(
+                String::from("base:Sword"),
+                nz(3)
+            ))],
+            EntityAccess::from("@3:base:Sword").ops
+        );
+    }
+
+    #[test]
+    fn should_parse_namespaced_sibling() {
+        assert_eq!(
+            vec![AccessOp::Sibling(SiblingAccess::Id(
+                String::from("base:Sword"),
+                nz(1)
+            ))],
+            EntityAccess::from("~base:Sword").ops
+        );
+        assert_eq!(
+            vec![AccessOp::Sibling(SiblingAccess::Id(
+                String::from("Sword"),
+                nz(-1)
+            ))],
+            EntityAccess::from("~-1:Sword").ops
+        );
+        assert_eq!(
+            vec![AccessOp::Sibling(SiblingAccess::At(nz(2)))],
+            EntityAccess::from("~2").ops
+        );
+    }
+}
@@ -4,7 +4,8 @@ use bevy::asset::{Assets, Handle};
 
 use crate::children::{Children, MergeKey, PrototypicalChild};
 use crate::cycles::{Cycle, CycleChecker, CycleNode, CycleResponse};
-use crate::proto::{Config, ProtoError, Prototypical};
+use crate::path::ProtoPath;
+use crate::proto::{qualify_id, split_namespace, Config, ProtoError, Prototypical};
 use crate::registration::ProtoRegistry;
 use crate::templates::Templates;
 use crate::tree::ProtoTree;
@@ -66,6 +67,9 @@ impl<'a, T: Prototypical, C: Config<T>> ProtoTreeBuilder<'a, T, C> {
         if let Some(templates) = prototype.templates() {
             self.recurse_templates(templates, &mut tree, checker)?;
         }
+        if let Some(base) = self.registry.get_merge_base(handle_id, prototype.id()) {
+            self.recurse_merge_base(base, &mut tree, checker)?;
+        }
 
         if !tree.requires_entity() && !tree.children().is_empty() {
             return Err(ProtoError::RequiresEntity {
@@ -83,8 +87,8 @@ impl<'a, T: Prototypical, C: Config<T>> ProtoTreeBuilder<'a, T, C> {
         tree: &mut ProtoTree<T>,
         checker: &mut CycleChecker<'a, T>,
     ) -> Result<(), ProtoError> {
-        for (_, template_handle) in templates.iter() {
-            let template_handle = template_handle.typed_weak();
+        for (path, template_handle) in templates.iter() {
+            let template_handle = self.resolve(Some(path), template_handle.typed_weak(), tree)?;
             let template_prototype = self.get_prototype(&template_handle)?;
 
             self.registry
@@ -114,43 +118,120 @@ impl<'a, T: Prototypical, C: Config<T>> ProtoTreeBuilder<'a, T, C> {
         Ok(())
     }
 
+    /// Inherit the overridden prototype that the given tree [merges] into.
+    ///
+    /// This is inherited after any templates so that it's applied first.
+    ///
+    /// [merges]: crate::proto::OverrideMode::Merge
+    fn recurse_merge_base(
+        &mut self,
+        base: Handle<T>,
+        tree: &mut ProtoTree<T>,
+        checker: &mut CycleChecker<'a, T>,
+    ) -> Result<(), ProtoError> {
+        let base_prototype = self.get_prototype(&base)?;
+
+        self.registry.add_dependent(base.id(), tree.handle());
+
+        if let Some(base_tree) = self.recursive_build(base_prototype, base, None, checker)? {
+            tree.inherit(base_tree);
+        }
+
+        Ok(())
+    }
+
     fn recurse_children(
         &mut self,
         children: &'a Children<T>,
         tree: &mut ProtoTree<T>,
         checker: &mut CycleChecker<'a, T>,
     ) -> Result<(), ProtoError> {
-        for child in children.iter() {
-            let child_handle = child.handle();
-            let child_prototype = self.get_prototype(child_handle)?;
+        for form in children.forms() {
+            let mut canceled = false;
+            let form = form.try_filter_map(|child| {
+                if canceled {
+                    return Ok(None);
+                }
 
-            self.registry
-                .add_dependent(child_handle.id(), tree.handle());
+                let child_tree = self.build_child(*child, tree, checker)?;
+                canceled = child_tree.is_none();
+                Ok(child_tree.flatten())
+            })?;
 
-            let node = CycleNode::Child {
-                id: Cow::Borrowed(child_prototype.id()),
-            };
-            if let Err(cycle) = checker.try_push(node) {
-                if self.handle_cycle(cycle)? {
-                    checker.pop();
-                    continue;
-                } else {
-                    checker.pop();
-                    return Ok(());
-                }
+            if let Some(form) = form {
+                tree.append_form(form);
             }
 
-            let child_handle = child_handle.clone_weak();
-            let merge_key = child.merge_key().cloned();
-            if let Some(child_tree) =
-                self.recursive_build(child_prototype, child_handle, merge_key, checker)?
-            {
-                tree.append_child(child_tree.clone());
+            if canceled {
+                return Ok(());
             }
+        }
+        Ok(())
+    }
+
+    /// Build the [tree] for a single child.
+    ///
+    /// Returns `Ok(None)` if the recursion should be canceled
+    /// and `Ok(Some(None))` if this child should be skipped.
+    ///
+    /// [tree]: ProtoTree
+    fn build_child(
+        &mut self,
+        child: &'a T::Child,
+        tree: &ProtoTree<T>,
+        checker: &mut CycleChecker<'a, T>,
+    ) -> Result<Option<Option<ProtoTree<T>>>, ProtoError> {
+        let child_handle = self.resolve(child.path(), child.handle().clone_weak(), tree)?;
+        let child_prototype = self.get_prototype(&child_handle)?;
+
+        self.registry
+            .add_dependent(child_handle.id(), tree.handle());
 
+        let node = CycleNode::Child {
+            id: Cow::Borrowed(child_prototype.id()),
+        };
+        if let Err(cycle) = checker.try_push(node) {
+            let skip = self.handle_cycle(cycle)?;
             checker.pop();
+            return Ok(skip.then_some(None));
         }
-        Ok(())
+
+        let merge_key = child.merge_key().cloned();
+        let child_tree = self.recursive_build(child_prototype, child_handle, merge_key, checker)?;
+
+        checker.pop();
+        Ok(Some(child_tree))
+    }
+
+    /// Resolve the handle of a template or child that may [refer to an ID].
+    ///
+    /// IDs without a namespace are first resolved within the namespace
+    /// of the given tree's prototype.
+    /// Paths to an [overridden] prototype resolve to the prototype overriding it.
+    ///
+    /// [refer to an ID]: ProtoPath::from_id
+    /// [overridden]: Config::override_policy
+    fn resolve(
+        &self,
+        path: Option<&ProtoPath>,
+        handle: Handle<T>,
+        tree: &ProtoTree<T>,
+    ) -> Result<Handle<T>, ProtoError> {
+        let Some((path, id)) = path.and_then(|path| Some((path, path.id()?))) else {
+            return Ok(self
+                .registry
+                .get_override(&handle)
+                .map(Handle::clone_weak)
+                .unwrap_or(handle));
+        };
+
+        let (namespace, _) = split_namespace(tree.id_str());
+        namespace
+            .and_then(|namespace| qualify_id(id, namespace))
+            .and_then(|id| self.registry.resolve_reference(&ProtoPath::from_id(id)))
+            .or_else(|| self.registry.resolve_reference(path))
+            .map(Handle::clone_weak)
+            .ok_or_else(|| ProtoError::MissingReference(id.to_string()))
     }
 
     fn get_prototype(&self, handle: &Handle<T>) -> Result<&'a T, ProtoError> {
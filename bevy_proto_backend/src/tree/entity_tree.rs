@@ -4,16 +4,17 @@ use std::collections::VecDeque;
 use std::fmt::{Debug, Formatter};
 use std::iter::Rev;
 use std::num::NonZeroUsize;
+use std::sync::OnceLock;
 
 use bevy::asset::HandleId;
 use bevy::ecs::system::Command;
 use bevy::prelude::{AddChild, Entity, World};
-use bevy::utils::HashMap;
+use bevy::utils::{HashMap, HashSet};
 use indexmap::set::Iter;
 use indexmap::IndexSet;
 
-use crate::proto::{ProtoInstance, Prototypical};
-use crate::tree::{AccessOp, ChildAccess, EntityAccess, ProtoTree, SiblingAccess};
+use crate::proto::{qualify_id, split_namespace, ProtoInstance, ProtoRng, Prototypical};
+use crate::tree::{AccessOp, ChildAccess, EntityAccess, ProtoTree, SiblingAccess, SpawnPlan};
 
 /// A tree structure containing all the entities to be mutated by a [prototype].
 ///
@@ -21,7 +22,14 @@ use crate::tree::{AccessOp, ChildAccess, EntityAccess, ProtoTree, SiblingAccess}
 /// However, it is generated with all the necessary entities,
 /// allowing those entities to be safely retrieved within a [`Schematic`].
 ///
+/// Any random [child forms] and schematic [variants] are resolved when the tree is generated.
+/// Each node selects its variants and children using its own seed,
+/// which is stored on its entity's [`ProtoInstance`] so that the same
+/// children are selected when the tree is regenerated.
+///
 /// [prototype]: Prototypical
+/// [child forms]: crate::children::ChildForm
+/// [variants]: crate::schematics::Variants
 /// [`ProtoCommands`]: crate::proto::ProtoCommands
 /// [`Schematic`]: crate::schematics::Schematic
 pub struct EntityTree<'a> {
@@ -41,6 +49,7 @@ impl<'a> EntityTree<'a> {
     pub(crate) fn new<T: Prototypical>(
         tree: &'a ProtoTree<T>,
         root: Option<Entity>,
+        seed: u64,
         world: &mut World,
     ) -> Self {
         let mut nodes = vec![EntityTreeNode {
@@ -48,25 +57,38 @@ impl<'a> EntityTree<'a> {
             index: 0,
             entity: root,
             prototypes: tree.prototypes(),
+            removed: tree.removed(),
+            plan: tree.plan(),
+            variants: HashMap::new(),
         }];
         let mut queue = VecDeque::new();
-        queue.push_back((0, root, tree));
+        queue.push_back((0, root, seed, tree));
 
         let mut parents = HashMap::<usize, usize>::new();
         let mut children = HashMap::<usize, EntityChildren>::new();
+        let mut selected = Vec::new();
 
-        while let Some((parent_index, parent_entity, tree)) = queue.pop_front() {
+        while let Some((parent_index, parent_entity, parent_seed, tree)) = queue.pop_front() {
             let mut entity_children = EntityChildren::default();
+            let mut rng = ProtoRng::from_seed(parent_seed);
+
+            nodes[parent_index].variants = Self::select_variants(tree, &mut rng);
+
+            selected.clear();
+            for form in tree.children() {
+                form.select(&mut rng, &mut selected);
+            }
 
-            for child in tree.children() {
+            for child in selected.drain(..) {
                 let index = nodes.len();
+                let seed = rng.next_u64();
 
                 let child_index = entity_children.insert(index, child.id_str());
                 parents.insert(index, parent_index);
 
                 let entity = if child.requires_entity() {
                     Some(Self::init_entity(
-                        ProtoInstance::new(child.handle(), child_index),
+                        ProtoInstance::new(child.handle(), child_index).with_seed(seed),
                         parent_entity,
                         world,
                     ))
@@ -79,9 +101,12 @@ impl<'a> EntityTree<'a> {
                     index,
                     entity,
                     prototypes: child.prototypes(),
+                    removed: child.removed(),
+                    plan: child.plan(),
+                    variants: HashMap::new(),
                 });
 
-                queue.push_back((index, entity, child));
+                queue.push_back((index, entity, seed, child));
             }
 
             children.insert(parent_index, entity_children);
@@ -107,6 +132,9 @@ impl<'a> EntityTree<'a> {
 
     pub(crate) fn get(&self, access: &EntityAccess) -> Option<&EntityTreeNode<'a>> {
         let mut current = self.current.get();
+        // Unqualified IDs are resolved within the namespace of the current prototype
+        let (namespace, _) = split_namespace(self.nodes.get(current)?.id);
+
         for op in access.ops() {
             match op {
                 AccessOp::Root => {
@@ -132,12 +160,10 @@ impl<'a> EntityTree<'a> {
                         (0, last_index)
                     };
 
-                    current = self.children.get(&current)?.get(
-                        id,
-                        start,
-                        end,
-                        occurrence.unsigned_abs(),
-                    )?;
+                    let children = self.children.get(&current)?;
+                    current = find_namespaced(id, namespace, |id| {
+                        children.get(id, start, end, occurrence.unsigned_abs())
+                    })?;
                 }
                 AccessOp::Sibling(SiblingAccess::At(offset)) => {
                     let parent = self.parents.get(&current)?;
@@ -167,7 +193,9 @@ impl<'a> EntityTree<'a> {
                         )
                     };
 
-                    current = siblings.get(id, start, end, occurrence.unsigned_abs())?;
+                    current = find_namespaced(id, namespace, |id| {
+                        siblings.get(id, start, end, occurrence.unsigned_abs())
+                    })?;
                 }
             }
         }
@@ -187,6 +215,29 @@ impl<'a> EntityTree<'a> {
         EntityTreeIter::from_index(0, self)
     }
 
+    /// Selects the index of the variant to apply for each prototype in the given tree.
+    ///
+    /// Prototypes are processed in their application order to ensure the selection
+    /// is deterministic.
+    fn select_variants<T: Prototypical>(
+        tree: &ProtoTree<T>,
+        rng: &mut ProtoRng,
+    ) -> HashMap<HandleId, usize> {
+        let variants = tree.variants();
+        if variants.is_empty() {
+            return HashMap::new();
+        }
+
+        tree.prototypes()
+            .iter()
+            .rev()
+            .filter_map(|handle_id| {
+                let count = u32::try_from(*variants.get(handle_id)?).unwrap_or(u32::MAX);
+                Some((*handle_id, rng.range(0, count - 1) as usize))
+            })
+            .collect()
+    }
+
     /// Spawns an [`Entity`] with the proper parent-child relationship,
     /// along with any additional components.
     ///
@@ -286,6 +337,21 @@ impl<'a> Debug for EntityTree<'a> {
     }
 }
 
+/// Finds a node using the given ID, which may be missing its namespace.
+///
+/// IDs without a namespace are first looked up within the given namespace
+/// before falling back to the ID as-is.
+fn find_namespaced<F: Fn(&str) -> Option<usize>>(
+    id: &str,
+    namespace: Option<&str>,
+    find: F,
+) -> Option<usize> {
+    namespace
+        .and_then(|namespace| qualify_id(id, namespace))
+        .and_then(|id| find(&id))
+        .or_else(|| find(id))
+}
+
 /// Node item stored in a [`EntityTree`].
 ///
 /// This essentially represents a single entity
@@ -295,6 +361,10 @@ pub(crate) struct EntityTreeNode<'a> {
     index: usize,
     entity: Option<Entity>,
     prototypes: &'a IndexSet<HandleId>,
+    removed: &'a HashMap<HandleId, HashSet<String>>,
+    plan: &'a OnceLock<SpawnPlan>,
+    /// The index of the selected variant for each prototype that has variants.
+    variants: HashMap<HandleId, usize>,
 }
 
 impl<'a> EntityTreeNode<'a> {
@@ -308,11 +378,46 @@ impl<'a> EntityTreeNode<'a> {
         self.entity
     }
 
+    /// Returns true if this is the root node of the tree.
+    pub fn is_root(&self) -> bool {
+        self.index == 0
+    }
+
+    /// The cached [`SpawnPlan`] of the [`ProtoTree`] this node was generated from.
+    pub(crate) fn plan(&self) -> &'a OnceLock<SpawnPlan> {
+        self.plan
+    }
+
     /// An iterator over this node's prototype and templates,
     /// in the order that they should be applied.
     pub fn prototypes(&self) -> Rev<Iter<'_, HandleId>> {
         self.prototypes.iter().rev()
     }
+
+    /// Returns true if the schematic with the given [type name] should not be applied
+    /// for the given prototype since it was removed by an inheriting prototype.
+    ///
+    /// [type name]: std::any::type_name
+    pub fn is_removed(&self, handle_id: &HandleId, type_name: &str) -> bool {
+        self.removed
+            .get(handle_id)
+            .map(|removed| removed.contains(type_name))
+            .unwrap_or_default()
+    }
+
+    /// The index of the [variant] selected for the given prototype, if any.
+    ///
+    /// [variant]: crate::schematics::Variants
+    pub fn variant(&self, handle_id: &HandleId) -> Option<usize> {
+        self.variants.get(handle_id).copied()
+    }
+
+    /// Returns true if a [variant] was selected for any of this node's prototypes.
+    ///
+    /// [variant]: crate::schematics::Variants
+    pub fn has_variants(&self) -> bool {
+        !self.variants.is_empty()
+    }
 }
 
 /// Metadata about a node's children.
@@ -6,8 +6,10 @@ pub use access::*;
 pub(crate) use builder::*;
 pub use entity_tree::*;
 pub(crate) use proto_tree::*;
+pub(crate) use spawn_plan::*;
 
 mod access;
 mod builder;
 mod entity_tree;
 mod proto_tree;
+mod spawn_plan;
@@ -1,13 +1,14 @@
 use std::fmt::{Debug, Formatter};
+use std::sync::OnceLock;
 
 use bevy::asset::{Handle, HandleId};
 use bevy::prelude::{Entity, World};
-use bevy::utils::HashMap;
+use bevy::utils::{HashMap, HashSet};
 use indexmap::IndexSet;
 
-use crate::children::MergeKey;
+use crate::children::{ChildForm, MergeKey};
 use crate::proto::Prototypical;
-use crate::tree::EntityTree;
+use crate::tree::{EntityTree, SpawnPlan};
 
 /// A cached tree structure that represents a single [prototype].
 ///
@@ -38,15 +39,51 @@ pub(crate) struct ProtoTree<T: Prototypical> {
     /// [merge key]: MergeKey
     merge_key: Option<MergeKey<T>>,
     /// The immediate children of this tree.
-    children: Vec<ProtoTree<T>>,
+    children: Vec<ChildForm<ProtoTree<T>>>,
     /// A mapping of [merge keys] to children.
     ///
+    /// Only [single] children are merged.
+    ///
     /// [merge keys]: MergeKey
+    /// [single]: ChildForm::Single
     merge_keys: HashMap<MergeKey<T>, usize>,
+    /// The type names of all schematics removed by the prototypes in this tree.
+    removals: HashSet<String>,
+    /// A mapping of template prototypes to the type names of their schematics
+    /// that have been removed by a prototype closer to this one.
+    removed: HashMap<HandleId, HashSet<String>>,
+    /// A mapping of prototypes to their number of [variants] (if they have any).
+    ///
+    /// [variants]: crate::schematics::Variants
+    variants: HashMap<HandleId, usize>,
+    /// The type names of all schematics applied by the prototypes in this tree,
+    /// excluding removed schematics and those within [variants].
+    ///
+    /// [variants]: crate::schematics::Variants
+    schematics: HashSet<String>,
+    /// The type names of all [patches] in this tree without a full schematic to patch.
+    ///
+    /// These patches can't be applied, so they're kept out of `schematics`
+    /// until an inherited prototype provides the full schematic.
+    ///
+    /// [patches]: crate::schematics::DynamicSchematic::is_patch
+    patches: HashSet<String>,
+    /// The compiled [`SpawnPlan`] for this tree's root node.
+    ///
+    /// This is compiled the first time the tree is spawned.
+    /// Since trees are rebuilt whenever one of their prototypes is reloaded,
+    /// the plan is never used for an outdated tree.
+    plan: OnceLock<SpawnPlan>,
 }
 
 impl<T: Prototypical> ProtoTree<T> {
     pub fn new(handle: Handle<T>, merge_key: Option<MergeKey<T>>, prototype: &T) -> Self {
+        let variants = prototype
+            .variants()
+            .filter(|variants| !variants.is_empty())
+            .map(|variants| [(handle.id(), variants.len())].into_iter().collect())
+            .unwrap_or_default();
+
         Self {
             id: prototype.id().clone(),
             id_str: prototype.id().to_string(),
@@ -56,9 +93,19 @@ impl<T: Prototypical> ProtoTree<T> {
             merge_key,
             children: Vec::new(),
             merge_keys: HashMap::new(),
+            removals: prototype.removed_schematics().iter().cloned().collect(),
+            removed: HashMap::new(),
+            variants,
+            schematics: schematic_names(prototype, false),
+            patches: schematic_names(prototype, true),
+            plan: OnceLock::new(),
         }
     }
 
+    pub fn id(&self) -> &T::Id {
+        &self.id
+    }
+
     pub fn id_str(&self) -> &str {
         &self.id_str
     }
@@ -75,46 +122,148 @@ impl<T: Prototypical> ProtoTree<T> {
     pub fn append_child(&mut self, tree: Self) {
         if let Some(merge_key) = tree.merge_key.as_ref() {
             if let Some(index) = self.merge_keys.get(merge_key) {
-                self.children[*index].inherit(tree);
+                if let ChildForm::Single(child) = &mut self.children[*index] {
+                    child.inherit(tree);
+                }
             } else {
                 self.merge_keys
                     .insert(merge_key.clone(), self.children.len());
-                self.children.push(tree);
+                self.children.push(ChildForm::Single(tree));
             }
         } else {
-            self.children.push(tree);
+            self.children.push(ChildForm::Single(tree));
+        }
+    }
+
+    /// Append the given [`ChildForm`] as new children of this tree.
+    ///
+    /// Single children are appended with [`append_child`](Self::append_child).
+    pub fn append_form(&mut self, form: ChildForm<Self>) {
+        match form {
+            ChildForm::Single(tree) => self.append_child(tree),
+            form => self.children.push(form),
         }
     }
 
     /// Merge the given tree into this one by inheriting it.
     pub fn inherit(&mut self, tree: Self) {
+        // Any existing plan no longer matches this tree
+        self.plan.take();
+
         // 1. Inherit all prototypes
         for prototype in tree.prototypes {
-            self.prototypes.insert(prototype);
+            if !self.prototypes.insert(prototype) {
+                continue;
+            }
+
+            if let Some(count) = tree.variants.get(&prototype) {
+                self.variants.insert(prototype, *count);
+            }
+
+            // Schematics removed by this tree take precedence over the inherited prototypes
+            let mut removed = tree.removed.get(&prototype).cloned().unwrap_or_default();
+            removed.extend(self.removals.iter().cloned());
+            if !removed.is_empty() {
+                self.removed.insert(prototype, removed);
+            }
         }
+        // Patches only count once an inherited prototype provides the full schematic
+        for name in tree.schematics {
+            if !self.removals.contains(&name) {
+                self.patches.remove(&name);
+                self.schematics.insert(name);
+            }
+        }
+        for name in tree.patches {
+            if !self.removals.contains(&name) && !self.schematics.contains(&name) {
+                self.patches.insert(name);
+            }
+        }
+        self.removals.extend(tree.removals);
 
         // 2. Update entity requirement
         self.requires_entity |= tree.requires_entity;
 
         // 3. Merge children
-        for child in tree.children {
-            self.append_child(child);
+        for form in tree.children {
+            self.append_form(form);
         }
     }
 
     /// The set of prototypes for this tree (in reverse-application order).
+    ///
+    /// The first entry is always the prototype of this tree itself,
+    /// followed by its templates in the order they were inherited.
     pub fn prototypes(&self) -> &IndexSet<HandleId> {
         &self.prototypes
     }
 
+    /// A mapping of prototypes to the type names of their schematics that should not be applied.
+    pub fn removed(&self) -> &HashMap<HandleId, HashSet<String>> {
+        &self.removed
+    }
+
+    /// Returns true if any prototype in this tree (or its children) removes an inherited schematic.
+    pub fn has_removals(&self) -> bool {
+        !self.removed.is_empty()
+            || self
+                .children
+                .iter()
+                .any(|form| form.any(Self::has_removals))
+    }
+
+    /// The type names of all schematics applied by this tree.
+    ///
+    /// This excludes any schematics within variants,
+    /// as well as any patches without a full schematic to patch.
+    pub fn schematics(&self) -> &HashSet<String> {
+        &self.schematics
+    }
+
+    pub fn plan(&self) -> &OnceLock<SpawnPlan> {
+        &self.plan
+    }
+
+    /// A mapping of prototypes to their number of variants.
+    ///
+    /// Prototypes without any variants are not included.
+    pub fn variants(&self) -> &HashMap<HandleId, usize> {
+        &self.variants
+    }
+
     /// The immediate children of this tree.
-    pub fn children(&self) -> &[ProtoTree<T>] {
+    pub fn children(&self) -> &[ChildForm<ProtoTree<T>>] {
         &self.children
     }
 
+    /// Returns true if the given tree has the same shape as this one.
+    ///
+    /// Two trees share a shape if they apply the same prototypes (in the same order)
+    /// and contain children that share the same shape.
+    /// Such trees will generate the same set of entities when given the same seed.
+    pub fn matches_structure(&self, other: &Self) -> bool {
+        self.handle == other.handle
+            && self.requires_entity == other.requires_entity
+            && self.prototypes.iter().eq(other.prototypes.iter())
+            && self.variants == other.variants
+            && self.children.len() == other.children.len()
+            && self
+                .children
+                .iter()
+                .zip(other.children.iter())
+                .all(|(a, b)| a.matches(b, Self::matches_structure))
+    }
+
     /// Converts this tree to a corresponding [`EntityTree`], using the given root [`Entity`].
-    pub fn to_entity_tree(&self, root: Option<Entity>, world: &mut World) -> EntityTree<'_> {
-        EntityTree::new(self, root, world)
+    ///
+    /// The given seed is used to select any random children.
+    pub fn to_entity_tree(
+        &self,
+        root: Option<Entity>,
+        seed: u64,
+        world: &mut World,
+    ) -> EntityTree<'_> {
+        EntityTree::new(self, root, seed, world)
     }
 }
 
@@ -129,6 +278,13 @@ impl<T: Prototypical> Clone for ProtoTree<T> {
             merge_key: self.merge_key.clone(),
             children: self.children.clone(),
             merge_keys: self.merge_keys.clone(),
+            removals: self.removals.clone(),
+            removed: self.removed.clone(),
+            variants: self.variants.clone(),
+            schematics: self.schematics.clone(),
+            patches: self.patches.clone(),
+            // Clones may be modified, so they must compile their own plan
+            plan: OnceLock::new(),
         }
     }
 }
@@ -143,6 +299,24 @@ impl<T: Prototypical> Debug for ProtoTree<T> {
             .field("merge_key", &self.merge_key)
             .field("children", &self.children)
             .field("merge_keys", &self.merge_keys)
+            .field("removals", &self.removals)
+            .field("removed", &self.removed)
+            .field("variants", &self.variants)
+            .field("schematics", &self.schematics)
+            .field("patches", &self.patches)
             .finish()
     }
 }
+
+/// Returns the type names of the schematics of the given prototype
+/// that either are or aren't [patches].
+///
+/// [patches]: crate::schematics::DynamicSchematic::is_patch
+fn schematic_names<T: Prototypical>(prototype: &T, is_patch: bool) -> HashSet<String> {
+    prototype
+        .schematics()
+        .iter()
+        .filter(|(_, schematic)| schematic.is_patch() == is_patch)
+        .map(|(name, _)| name.to_string())
+        .collect()
+}
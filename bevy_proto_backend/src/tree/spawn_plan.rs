@@ -0,0 +1,83 @@
+use std::borrow::Cow;
+
+use bevy::asset::HandleId;
+use bevy::utils::HashMap;
+
+use crate::proto::Prototypical;
+use crate::schematics::{CompiledSchematic, DynamicSchematic, Variants};
+
+/// A precompiled set of schematics for a single node of a [`ProtoTree`].
+///
+/// This contains the schematics of each prototype in the node (in application order)
+/// with their [parameters] substituted, their [patches] resolved,
+/// and any [removed] schematics filtered out.
+///
+/// Since none of this depends on the entity being spawned,
+/// it is only compiled once per tree and reused for every spawn.
+/// Schematics that support it are also [compiled] into their final value,
+/// so that spawning them only requires inserting a copy of that value.
+///
+/// Schematics that did not need to be resolved are not copied into the plan
+/// and should instead be read directly from their prototype.
+/// The same goes for [variants], of which only those with substituted parameters are stored,
+/// since the selected variant may differ between instances.
+///
+/// [`ProtoTree`]: crate::tree::ProtoTree
+/// [parameters]: crate::proto::Prototypical::params
+/// [patches]: DynamicSchematic::is_patch
+/// [removed]: crate::proto::Prototypical::removed_schematics
+/// [compiled]: crate::schematics::Schematic::compile
+/// [variants]: crate::proto::Prototypical::variants
+#[derive(Default)]
+pub(crate) struct SpawnPlan {
+    steps: Vec<(HandleId, Vec<PlannedSchematic>)>,
+    variants: HashMap<HandleId, Variants>,
+}
+
+/// A schematic in a [`SpawnPlan`].
+pub(crate) struct PlannedSchematic {
+    /// The [type name] of the schematic.
+    ///
+    /// [type name]: std::any::type_name
+    pub type_name: Cow<'static, str>,
+    /// The resolved schematic.
+    ///
+    /// If `None`, it should be read from its prototype.
+    pub schematic: Option<DynamicSchematic>,
+    /// The [compiled] value of the schematic, if it supports being compiled.
+    ///
+    /// [compiled]: crate::schematics::Schematic::compile
+    pub compiled: Option<CompiledSchematic>,
+}
+
+impl SpawnPlan {
+    pub fn new(
+        steps: Vec<(HandleId, Vec<PlannedSchematic>)>,
+        variants: HashMap<HandleId, Variants>,
+    ) -> Self {
+        Self { steps, variants }
+    }
+
+    /// Iterate over the planned schematics for each prototype, in application order.
+    pub fn iter(&self) -> impl Iterator<Item = (&HandleId, &[PlannedSchematic])> {
+        self.steps
+            .iter()
+            .map(|(handle_id, schematics)| (handle_id, schematics.as_slice()))
+    }
+
+    /// Returns the variants of the given prototype with their parameters substituted, if any.
+    ///
+    /// If `None`, the variants should be read from the prototype.
+    pub fn variants(&self, handle_id: &HandleId) -> Option<&Variants> {
+        self.variants.get(handle_id)
+    }
+}
+
+impl PlannedSchematic {
+    /// Returns the schematic to apply, reading it from the given prototype if necessary.
+    pub fn get<'a, T: Prototypical>(&'a self, prototype: &'a T) -> Option<&'a DynamicSchematic> {
+        self.schematic
+            .as_ref()
+            .or_else(|| prototype.schematics().get_by_name(&self.type_name))
+    }
+}
@@ -5,7 +5,9 @@ use crate::common::input::{
     OutputType, SchematicIo,
 };
 use crate::utils::constants::{CONTEXT_IDENT, DEPENDENCIES_IDENT, ID_IDENT, INPUT_IDENT};
-use crate::utils::exports::{DependenciesBuilder, Schematic, SchematicContext, SchematicId};
+use crate::utils::exports::{
+    CompiledSchematic, DependenciesBuilder, Schematic, SchematicContext, SchematicId,
+};
 use proc_macro2::{Ident, TokenStream};
 use quote::{quote, ToTokens};
 use syn::parse::{Parse, ParseStream};
@@ -57,11 +59,23 @@ impl DeriveSchematic {
             }
         };
 
+        // A failed conversion may leave placeholders (e.g. `Entity::PLACEHOLDER`) in the value,
+        // so it should not be inserted
+        let check_failed = conversion.is_some().then(|| {
+            quote! {
+                if #CONTEXT_IDENT.has_failed() {
+                    return;
+                }
+            }
+        });
+
         quote! {
             #from_reflect
 
             #conversion
 
+            #check_failed
+
             #insert
 
         }
@@ -83,6 +97,31 @@ impl DeriveSchematic {
         }
     }
 
+    /// Generates the `Schematic::compile` method, if supported.
+    ///
+    /// Only schematics that insert their input as-is can be compiled,
+    /// since any conversion may depend on the `SchematicContext`.
+    fn compile_def(&self) -> Option<TokenStream> {
+        let is_reflexive = matches!(
+            (self.input_ty(), self.output_ty()),
+            (InputType::Reflexive, OutputType::Reflexive)
+        );
+
+        if !is_reflexive || matches!(self.attrs.kind(), SchematicKind::Resource) {
+            return None;
+        }
+
+        let from_reflect = generate_from_reflect_conversion();
+
+        Some(quote! {
+            fn compile(#INPUT_IDENT: &Self::Input, #ID_IDENT: #SchematicId) -> Option<#CompiledSchematic> {
+                #from_reflect
+
+                Some(#CompiledSchematic::from_reflect(#INPUT_IDENT))
+            }
+        })
+    }
+
     /// Generates the logic for `Schematic::preload`.
     fn preload_def(&self) -> Result<TokenStream, Error> {
         Ok(match &self.data {
@@ -123,6 +162,7 @@ impl DeriveSchematic {
         let apply_def = self.apply_def();
         let remove_def = self.remove_def();
         let preload_def = self.preload_def()?;
+        let compile_def = self.compile_def();
 
         let input_vis = self.io.input_vis();
         let input_ty = match self.input_ty() {
@@ -149,6 +189,8 @@ impl DeriveSchematic {
                 fn preload_dependencies(#INPUT_IDENT: &mut Self::Input, #ID_IDENT: #SchematicId, #DEPENDENCIES_IDENT: &mut #DependenciesBuilder)  {
                     #preload_def
                 }
+
+                #compile_def
             }
         };
 
@@ -30,6 +30,7 @@ create_export!(bevy_proto::assets::[InlinableProtoAsset]);
 create_export!(bevy_proto::assets::__private::[PreloadProtoAssetInput]);
 create_export!(bevy_proto::deps::[DependenciesBuilder]);
 create_export!(bevy_proto::schematics::[Schematic]);
+create_export!(bevy_proto::schematics::[CompiledSchematic]);
 create_export!(bevy_proto::schematics::[FromSchematicInput]);
 create_export!(bevy_proto::schematics::[FromSchematicPreloadInput]);
 create_export!(bevy_proto::schematics::[SchematicId]);
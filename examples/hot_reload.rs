@@ -3,8 +3,12 @@
 //! It's pretty easy to enable hot-reloading in Bevy— just enable [`AssetPlugin::watch_for_changes`].
 //! This allows changes to any prototype to be automatically picked up.
 //!
-//! This example also uses the [`ProtoAssetEvent`] event to reload an existing entity if
-//! its prototype changes, which can allow for faster development.
+//! This example also enables instance reloading on the [`ProtoPlugin`], which automatically
+//! updates any existing entities whenever their prototype changes.
+//! This can allow for faster development.
+//!
+//! Only entities spawned or inserted through [`ProtoCommands`] are tracked for reloading,
+//! and removing a prototype from an entity stops it from being reloaded.
 //!
 //! Please note that hot-reloading is far from perfect.
 //! Changing the IDs of a prototype or its hierarchical structure may cause the
@@ -27,7 +31,8 @@ fn main() {
                 watch_for_changes: ChangeWatcher::with_delay(Duration::from_millis(200)),
                 ..default()
             }),
-            ProtoPlugin::new(),
+            // Enable automatic reloading of spawned prototypes:
+            ProtoPlugin::new().with_instance_reloading(true),
         ))
         .add_systems(Startup, (setup, load))
         .add_systems(
@@ -44,27 +49,13 @@ fn load(mut prototypes: PrototypesMut) {
 fn spawn(
     mut commands: ProtoCommands,
     keyboard_input: Res<Input<KeyCode>>,
-    mut previous: Local<Option<Entity>>,
-    mut proto_asset_events: EventReader<ProtoAssetEvent>,
+    mut spawned: Local<bool>,
 ) {
-    if previous.is_none() || keyboard_input.just_pressed(KeyCode::Space) {
-        *previous = Some(commands.spawn("ReloadableSprite").id());
-    }
-
-    // Listen for changes:
-    for proto_asset_event in proto_asset_events.iter() {
-        match proto_asset_event {
-            // Only trigger a re-insert of the prototype when modified and if IDs match
-            ProtoAssetEvent::Modified { id, .. } if id == "ReloadableSprite" => {
-                commands
-                    .entity(previous.unwrap())
-                    .insert("ReloadableSprite");
-            }
-            _ => {}
-        }
-
-        // Note: We could also have checked using the helper method:
-        // if proto_asset_event.is_modified("ReloadableSprite") { ... }
+    // No need to track the spawned entities:
+    // they're automatically updated when the prototype is modified.
+    if !*spawned || keyboard_input.just_pressed(KeyCode::Space) {
+        commands.spawn("ReloadableSprite");
+        *spawned = true;
     }
 }
 
@@ -17,6 +17,18 @@
 //!
 //! > Again, these overwrites only occur on schematics of the same type.
 //!
+//! Prototypes may also _patch_ a schematic instead of overwriting it.
+//! A patch only contains the fields that should change and is applied
+//! on top of the same schematic from the closest template.
+//! Our `Player` uses this to swap the texture of the `SpriteBundle` from `Small`.
+//!
+//! Lastly, inherited schematics can be dropped entirely by listing their
+//! type names under `remove_schematics`:
+//!
+//! ```text
+//! remove_schematics: ["templates::Colored"],
+//! ```
+//!
 //! In the end, since `Small` and `Big` share the same schematics, and
 //! `Green` and `Red` also share schematics (that differ from the other two),
 //! we should expect to see the schematics from `Small` and `Green` applied.
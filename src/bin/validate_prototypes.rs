@@ -0,0 +1,39 @@
+//! Validates a folder of prototypes from the command line.
+//!
+//! ```text
+//! validate_prototypes [ASSET_FOLDER] [PROTOTYPES_FOLDER]
+//! ```
+//!
+//! The asset folder defaults to `assets` and the prototypes folder
+//! (relative to the asset folder) defaults to `prototypes`.
+//!
+//! Only the schematics provided by Bevy and `bevy_proto` are registered.
+//! Projects with their own schematics should use [`ProtoValidator`] from a test instead.
+
+use std::process::ExitCode;
+
+use bevy_proto::validate::ProtoValidator;
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let asset_folder = args.next().unwrap_or_else(|| String::from("assets"));
+    let prototypes_folder = args.next().unwrap_or_else(|| String::from("prototypes"));
+
+    // Resolve the asset folder from the current directory rather than the executable's
+    let asset_folder = match std::env::current_dir() {
+        Ok(dir) => dir.join(asset_folder).to_string_lossy().into_owned(),
+        Err(err) => {
+            eprintln!("could not read current directory: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let report = ProtoValidator::new(asset_folder, prototypes_folder).validate();
+    print!("{report}");
+
+    if report.is_valid() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
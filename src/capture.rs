@@ -0,0 +1,203 @@
+//! Items for capturing existing entities as [prototypes].
+//!
+//! This is useful for building prototypes in-game (such as from within an editor)
+//! rather than writing them by hand.
+//!
+//! [prototypes]: Prototype
+
+use bevy::asset::{Assets, Handle};
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::prelude::{debug, AppTypeRegistry, Children, Entity, World};
+use bevy::reflect::TypeRegistryInternal;
+
+use bevy_proto_backend::children::Children as ProtoChildren;
+use bevy_proto_backend::deps::Dependencies;
+use bevy_proto_backend::path::ProtoPath;
+use bevy_proto_backend::schematics::{ReflectSchematic, Schematics};
+
+use crate::proto::{ProtoChild, Prototype, PrototypeError};
+
+/// An in-memory [`Prototype`] captured from an existing entity.
+///
+/// Unlike loaded prototypes, a captured prototype owns its children directly
+/// rather than storing them in `Assets<Prototype>`.
+/// This allows it to be inspected, [saved], or [registered] as needed.
+///
+/// [saved]: save_prototype
+/// [registered]: Self::register
+pub struct CapturedPrototype {
+    prototype: Prototype,
+    children: Vec<CapturedPrototype>,
+}
+
+impl CapturedPrototype {
+    /// Set the [path] used to identify the prototype in errors.
+    ///
+    /// Defaults to `"{id}.prototype"`.
+    ///
+    /// [path]: bevy_proto_backend::proto::Prototypical::path
+    pub fn with_path(mut self, path: impl Into<ProtoPath>) -> Self {
+        self.prototype.path = path.into();
+        self
+    }
+
+    /// The captured prototype, excluding its children.
+    pub fn prototype(&self) -> &Prototype {
+        &self.prototype
+    }
+
+    /// The captured children, in the order they appear in the entity's [`Children`].
+    pub fn children(&self) -> &[CapturedPrototype] {
+        &self.children
+    }
+
+    /// Add the prototype to the given `Assets<Prototype>`, along with all of its children.
+    ///
+    /// The returned handle is _strong_.
+    /// The prototype will remain registered for as long as this handle is kept alive.
+    pub fn register(self, prototypes: &mut Assets<Prototype>) -> Handle<Prototype> {
+        let Self {
+            mut prototype,
+            children,
+        } = self;
+
+        if !children.is_empty() {
+            let mut proto_children = ProtoChildren::default();
+            for child in children {
+                proto_children.insert(ProtoChild {
+                    merge_key: None,
+                    handle: child.register(prototypes),
+                    path: None,
+                });
+            }
+            prototype.children = Some(proto_children);
+        }
+
+        prototypes.add(prototype)
+    }
+}
+
+/// Capture the given entity, along with its descendants, as a new [`CapturedPrototype`].
+///
+/// Every component on the entity whose type is registered in the [`AppTypeRegistry`]
+/// with both [`ReflectComponent`] and [`ReflectSchematic`] is captured as a schematic.
+/// Components whose [schematic input] cannot be created from the component itself
+/// are skipped.
+///
+/// Each child entity is captured as an inline child prototype, named `"{id}/{index}"`.
+/// Nothing is added to `Assets<Prototype>`: use [`CapturedPrototype::register`]
+/// to make the prototype available for spawning.
+///
+/// # Errors
+///
+/// Returns an error if the entity does not exist.
+///
+/// [schematic input]: bevy_proto_backend::schematics::Schematic::Input
+pub fn capture_prototype(
+    world: &World,
+    entity: Entity,
+    id: impl Into<String>,
+) -> Result<CapturedPrototype, PrototypeError> {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    capture_entity(world, &type_registry, entity, id.into())
+}
+
+/// Write the given [`CapturedPrototype`] to a `.prototype.ron` file at the given path.
+#[cfg(feature = "ron")]
+pub fn save_prototype(
+    world: &World,
+    prototype: &CapturedPrototype,
+    path: impl AsRef<std::path::Path>,
+) -> Result<(), PrototypeError> {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let output = crate::ser::PrototypeSerializer::captured(
+        &prototype.prototype,
+        &prototype.children,
+        &type_registry,
+    )
+    .to_ron()?;
+
+    std::fs::write(path, output)?;
+    Ok(())
+}
+
+fn capture_entity(
+    world: &World,
+    type_registry: &TypeRegistryInternal,
+    entity: Entity,
+    id: String,
+) -> Result<CapturedPrototype, PrototypeError> {
+    let entity_ref = world.get_entity(entity).ok_or_else(|| {
+        PrototypeError::custom(format!("could not capture missing entity {entity:?}"))
+    })?;
+
+    let mut schematics = Schematics::default();
+    for component_id in entity_ref.archetype().components() {
+        let Some(type_id) = world
+            .components()
+            .get_info(component_id)
+            .and_then(|info| info.type_id())
+        else {
+            continue;
+        };
+
+        let Some(registration) = type_registry.get(type_id) else {
+            continue;
+        };
+
+        let (Some(reflect_component), Some(reflect_schematic)) = (
+            registration.data::<ReflectComponent>(),
+            registration.data::<ReflectSchematic>(),
+        ) else {
+            continue;
+        };
+
+        let Some(component) = reflect_component.reflect(entity_ref) else {
+            continue;
+        };
+
+        match reflect_schematic.create_dynamic(component.clone_value()) {
+            Ok(schematic) => {
+                schematics.insert_dynamic(schematic);
+            }
+            Err(err) => debug!(
+                "skipping component {:?} while capturing {:?}: {}",
+                registration.type_name(),
+                id,
+                err
+            ),
+        }
+    }
+
+    let children = world
+        .get::<Children>(entity)
+        .map(|children| {
+            children
+                .iter()
+                .enumerate()
+                .map(|(index, child)| {
+                    capture_entity(world, type_registry, *child, format!("{id}/{index}"))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()?
+        .unwrap_or_default();
+
+    Ok(CapturedPrototype {
+        prototype: Prototype {
+            path: ProtoPath::from(format!("{id}.prototype")),
+            id,
+            requires_entity: true,
+            schematics,
+            removed_schematics: Vec::new(),
+            variants: None,
+            templates: None,
+            params: None,
+            template_params: Default::default(),
+            source: None,
+            dependencies: Dependencies::default(),
+            children: None,
+        },
+        children,
+    })
+}
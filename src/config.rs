@@ -2,11 +2,13 @@
 //!
 //! [prototypes]: Prototype
 
+use std::path::{Path, PathBuf};
+
 use bevy::asset::Handle;
 use bevy::prelude::Resource;
 
 use bevy_proto_backend::cycles::{Cycle, CycleResponse};
-use bevy_proto_backend::proto::Config;
+use bevy_proto_backend::proto::{Config, OverridePolicy, Prototypical};
 use bevy_proto_backend::schematics::{DynamicSchematic, SchematicContext, SchematicId};
 
 use crate::hooks::{
@@ -32,6 +34,8 @@ pub struct ProtoConfig {
     on_before_remove_schematic: Option<OnBeforeRemoveSchematic>,
     on_after_remove_schematic: Option<OnAfterRemoveSchematic>,
     on_cycle: Option<OnCycle>,
+    namespace: Option<String>,
+    override_policies: Vec<(PathBuf, OverridePolicy)>,
 }
 
 impl ProtoConfig {
@@ -106,6 +110,46 @@ impl ProtoConfig {
         self.on_cycle = Some(callback);
         self
     }
+
+    /// Set the namespace returned by [`Config::namespace`].
+    pub fn with_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Set the [`OverridePolicy`] of all prototypes loaded from within the given folder.
+    ///
+    /// This allows prototypes with the same ID to override each other,
+    /// such as a mod redefining `base:Sword` in its own folder.
+    /// If a prototype is within multiple of the given folders, the most specific one is used.
+    ///
+    /// See [`Config::override_policy`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_proto::prelude::*;
+    /// # use bevy_proto::backend::proto::OverridePolicy;
+    /// let config = ProtoConfig::default()
+    ///   .with_override_policy("base", OverridePolicy::replace(0))
+    ///   .with_override_policy("mods/balance", OverridePolicy::merge(10));
+    /// ```
+    pub fn with_override_policy(
+        mut self,
+        folder: impl Into<PathBuf>,
+        policy: OverridePolicy,
+    ) -> Self {
+        self.override_policies.push((folder.into(), policy));
+        self
+    }
+
+    /// Set or clear the namespace returned by [`Config::namespace`].
+    ///
+    /// This can be used to switch namespaces at runtime,
+    /// such as when changing between game modes.
+    pub fn set_namespace(&mut self, namespace: Option<String>) {
+        self.namespace = namespace;
+    }
 }
 
 impl Config<Prototype> for ProtoConfig {
@@ -208,4 +252,23 @@ impl Config<Prototype> for ProtoConfig {
             CycleResponse::Cancel
         }
     }
+
+    fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+
+    fn override_policy(&self, prototype: &Prototype) -> Option<OverridePolicy> {
+        find_by_folder(&self.override_policies, prototype.path().path()).copied()
+    }
+}
+
+/// Returns the value of the most specific folder containing the given path, if any.
+///
+/// A folder is more specific than another if it has more path components.
+pub(crate) fn find_by_folder<'a, V>(folders: &'a [(PathBuf, V)], path: &Path) -> Option<&'a V> {
+    folders
+        .iter()
+        .filter(|(folder, _)| path.starts_with(folder))
+        .max_by_key(|(folder, _)| folder.components().count())
+        .map(|(_, value)| value)
 }
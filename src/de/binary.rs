@@ -0,0 +1,426 @@
+use std::fmt::Formatter;
+
+use bevy::asset::Handle;
+use bevy::reflect::serde::{TypeRegistrationDeserializer, TypedReflectDeserializer};
+use bevy::reflect::TypeRegistryInternal;
+use serde::de::{DeserializeSeed, EnumAccess, Error, SeqAccess, VariantAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+
+use bevy_proto_backend::children::{Children, ProtoChildBuilder};
+use bevy_proto_backend::load::{Loader, ProtoLoadContext};
+use bevy_proto_backend::path::{
+    ProtoPathContext, ProtoPathDeserializer, ProtoPathListDeserializer,
+};
+use bevy_proto_backend::schematics::{DynamicSchematic, Schematics};
+use bevy_proto_backend::templates::Templates;
+
+use crate::proto::{ProtoChild, Prototype};
+use crate::schematics::get_reflect_schematic;
+use crate::ser::binary::{
+    BINARY_CHILD_LEN, BINARY_CHILD_VALUE, BINARY_CHILD_VALUE_INLINE, BINARY_CHILD_VALUE_PATH,
+    BINARY_PROTOTYPE_LEN, BINARY_SCHEMATIC_LEN,
+};
+
+#[derive(Deserialize, Debug)]
+#[serde(variant_identifier)]
+enum BinaryChildValueVariant {
+    Path,
+    Inline,
+}
+
+/// Deserializer for a [`Prototype`] written in the compact binary layout.
+///
+/// This is the counterpart to [`PrototypeSerializer::to_binary`].
+///
+/// [`PrototypeSerializer::to_binary`]: crate::ser::PrototypeSerializer::to_binary
+pub struct BinaryPrototypeDeserializer<'a, 'ctx, 'load_ctx, L: Loader<Prototype>> {
+    context: &'a mut ProtoLoadContext<'ctx, 'load_ctx, Prototype, L>,
+}
+
+impl<'a, 'ctx, 'load_ctx, L: Loader<Prototype>>
+    BinaryPrototypeDeserializer<'a, 'ctx, 'load_ctx, L>
+{
+    pub fn new(context: &'a mut ProtoLoadContext<'ctx, 'load_ctx, Prototype, L>) -> Self {
+        Self { context }
+    }
+}
+
+impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> DeserializeSeed<'de>
+    for BinaryPrototypeDeserializer<'a, 'ctx, 'load_ctx, L>
+{
+    type Value = Prototype;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct BinaryPrototypeVisitor<'a, 'ctx, 'load_ctx, L: Loader<Prototype>> {
+            context: &'a mut ProtoLoadContext<'ctx, 'load_ctx, Prototype, L>,
+        }
+
+        impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> Visitor<'de>
+            for BinaryPrototypeVisitor<'a, 'ctx, 'load_ctx, L>
+        {
+            type Value = Prototype;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                write!(formatter, "a binary `Prototype`")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let id = seq
+                    .next_element::<String>()?
+                    .ok_or_else(|| Error::invalid_length(0, &self))?;
+
+                let requires_entity = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| Error::invalid_length(1, &self))?;
+
+                let paths = seq
+                    .next_element_seed(ProtoPathListDeserializer::new(self.context))?
+                    .ok_or_else(|| Error::invalid_length(2, &self))?;
+                let templates = if paths.is_empty() {
+                    None
+                } else {
+                    let mut templates = Templates::default();
+                    for path in paths {
+                        let handle: Handle<Prototype> = self.context.get_handle(&path);
+                        templates.insert(path, handle);
+                    }
+                    Some(templates)
+                };
+
+                let schematics = seq
+                    .next_element_seed(BinarySchematicsDeserializer {
+                        registry: self.context.registry(),
+                    })?
+                    .ok_or_else(|| Error::invalid_length(3, &self))?;
+
+                let removed_schematics = seq
+                    .next_element::<Vec<String>>()?
+                    .ok_or_else(|| Error::invalid_length(4, &self))?;
+
+                let mut children = Children::default();
+                self.context
+                    .with_children::<A::Error, _>(|builder| {
+                        let child_list = seq
+                            .next_element_seed(BinaryChildrenDeserializer { builder })?
+                            .ok_or_else(|| Error::invalid_length(5, &"a binary `Prototype`"))?;
+
+                        for child in child_list {
+                            children.insert(child);
+                        }
+
+                        Ok(())
+                    })
+                    .map_err(Error::custom)?;
+
+                Ok(Prototype {
+                    id,
+                    path: self.context.base_path().into(),
+                    requires_entity,
+                    templates,
+                    params: None,
+                    template_params: Default::default(),
+                    source: None,
+                    schematics,
+                    removed_schematics,
+                    variants: None,
+                    children: (!children.is_empty()).then_some(children),
+                    dependencies: Default::default(),
+                })
+            }
+        }
+
+        deserializer.deserialize_tuple(
+            BINARY_PROTOTYPE_LEN,
+            BinaryPrototypeVisitor {
+                context: self.context,
+            },
+        )
+    }
+}
+
+struct BinarySchematicsDeserializer<'a> {
+    registry: &'a TypeRegistryInternal,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for BinarySchematicsDeserializer<'a> {
+    type Value = Schematics;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct BinarySchematicsVisitor<'a> {
+            registry: &'a TypeRegistryInternal,
+        }
+
+        impl<'a, 'de> Visitor<'de> for BinarySchematicsVisitor<'a> {
+            type Value = Schematics;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                write!(formatter, "list of schematics")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let size_hint = seq.size_hint().unwrap_or_default();
+                let mut schematics = Schematics::with_capacity(size_hint);
+
+                while let Some(schematic) = seq.next_element_seed(BinarySchematicDeserializer {
+                    registry: self.registry,
+                })? {
+                    if schematics.contains_by_name(schematic.type_info().type_name()) {
+                        return Err(Error::custom(format_args!(
+                            "duplicate schematic: `{}`",
+                            schematic.type_info().type_name()
+                        )));
+                    }
+
+                    schematics.insert_dynamic(schematic);
+                }
+
+                Ok(schematics)
+            }
+        }
+
+        deserializer.deserialize_seq(BinarySchematicsVisitor {
+            registry: self.registry,
+        })
+    }
+}
+
+struct BinarySchematicDeserializer<'a> {
+    registry: &'a TypeRegistryInternal,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for BinarySchematicDeserializer<'a> {
+    type Value = DynamicSchematic;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct BinarySchematicVisitor<'a> {
+            registry: &'a TypeRegistryInternal,
+        }
+
+        impl<'a, 'de> Visitor<'de> for BinarySchematicVisitor<'a> {
+            type Value = DynamicSchematic;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                write!(formatter, "a `(type name, input)` schematic pair")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let registration = seq
+                    .next_element_seed(TypeRegistrationDeserializer::new(self.registry))?
+                    .ok_or_else(|| Error::invalid_length(0, &self))?;
+
+                let reflect_schematic = get_reflect_schematic(registration)?;
+                let input_registration = reflect_schematic.input_registration();
+
+                let input = seq
+                    .next_element_seed(TypedReflectDeserializer::new(
+                        &input_registration,
+                        self.registry,
+                    ))?
+                    .ok_or_else(|| Error::invalid_length(1, &self))?;
+
+                reflect_schematic
+                    .create_dynamic(input)
+                    .map_err(Error::custom)
+            }
+        }
+
+        deserializer.deserialize_tuple(
+            BINARY_SCHEMATIC_LEN,
+            BinarySchematicVisitor {
+                registry: self.registry,
+            },
+        )
+    }
+}
+
+struct BinaryChildrenDeserializer<'a, 'ctx, 'load_ctx, L: Loader<Prototype>> {
+    builder: &'a mut ProtoChildBuilder<'ctx, 'load_ctx, Prototype, L>,
+}
+
+impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> DeserializeSeed<'de>
+    for BinaryChildrenDeserializer<'a, 'ctx, 'load_ctx, L>
+{
+    type Value = Vec<ProtoChild>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct BinaryChildrenVisitor<'a, 'ctx, 'load_ctx, L: Loader<Prototype>> {
+            builder: &'a mut ProtoChildBuilder<'ctx, 'load_ctx, Prototype, L>,
+        }
+
+        impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> Visitor<'de>
+            for BinaryChildrenVisitor<'a, 'ctx, 'load_ctx, L>
+        {
+            type Value = Vec<ProtoChild>;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                write!(formatter, "list of binary `ProtoChild` values")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut children = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+
+                while let Some(child) = seq.next_element_seed(BinaryChildDeserializer {
+                    builder: self.builder,
+                })? {
+                    children.push(child);
+                }
+
+                Ok(children)
+            }
+        }
+
+        deserializer.deserialize_seq(BinaryChildrenVisitor {
+            builder: self.builder,
+        })
+    }
+}
+
+struct BinaryChildDeserializer<'a, 'ctx, 'load_ctx, L: Loader<Prototype>> {
+    builder: &'a mut ProtoChildBuilder<'ctx, 'load_ctx, Prototype, L>,
+}
+
+impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> DeserializeSeed<'de>
+    for BinaryChildDeserializer<'a, 'ctx, 'load_ctx, L>
+{
+    type Value = ProtoChild;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct BinaryChildVisitor<'a, 'ctx, 'load_ctx, L: Loader<Prototype>> {
+            builder: &'a mut ProtoChildBuilder<'ctx, 'load_ctx, Prototype, L>,
+        }
+
+        impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> Visitor<'de>
+            for BinaryChildVisitor<'a, 'ctx, 'load_ctx, L>
+        {
+            type Value = ProtoChild;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                write!(formatter, "a binary `ProtoChild`")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let merge_key = seq
+                    .next_element::<Option<String>>()?
+                    .ok_or_else(|| Error::invalid_length(0, &self))?;
+
+                let mut child = seq
+                    .next_element_seed(BinaryChildValueDeserializer {
+                        builder: self.builder,
+                    })?
+                    .ok_or_else(|| Error::invalid_length(1, &"a binary `ProtoChild`"))?;
+
+                child.merge_key = merge_key;
+                Ok(child)
+            }
+        }
+
+        deserializer.deserialize_tuple(
+            BINARY_CHILD_LEN,
+            BinaryChildVisitor {
+                builder: self.builder,
+            },
+        )
+    }
+}
+
+struct BinaryChildValueDeserializer<'a, 'ctx, 'load_ctx, L: Loader<Prototype>> {
+    builder: &'a mut ProtoChildBuilder<'ctx, 'load_ctx, Prototype, L>,
+}
+
+impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> DeserializeSeed<'de>
+    for BinaryChildValueDeserializer<'a, 'ctx, 'load_ctx, L>
+{
+    type Value = ProtoChild;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct BinaryChildValueVisitor<'a, 'ctx, 'load_ctx, L: Loader<Prototype>> {
+            builder: &'a mut ProtoChildBuilder<'ctx, 'load_ctx, Prototype, L>,
+        }
+
+        impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> Visitor<'de>
+            for BinaryChildValueVisitor<'a, 'ctx, 'load_ctx, L>
+        {
+            type Value = ProtoChild;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                write!(formatter, "{BINARY_CHILD_VALUE} variant")
+            }
+
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, value) = data.variant::<BinaryChildValueVariant>()?;
+
+                match variant {
+                    BinaryChildValueVariant::Path => {
+                        let path = value.newtype_variant_seed(ProtoPathDeserializer::new(
+                            self.builder.context(),
+                        ))?;
+                        let handle = self
+                            .builder
+                            .add_child_path(path.clone())
+                            .map_err(Error::custom)?;
+                        Ok(ProtoChild {
+                            merge_key: None,
+                            handle,
+                            path: Some(path),
+                        })
+                    }
+                    BinaryChildValueVariant::Inline => {
+                        let prototype = value.newtype_variant_seed(
+                            BinaryPrototypeDeserializer::new(self.builder.context_mut()),
+                        )?;
+                        let handle = self.builder.add_child(prototype).map_err(Error::custom)?;
+                        Ok(ProtoChild {
+                            merge_key: None,
+                            handle,
+                            path: None,
+                        })
+                    }
+                }
+            }
+        }
+
+        deserializer.deserialize_enum(
+            BINARY_CHILD_VALUE,
+            &[BINARY_CHILD_VALUE_PATH, BINARY_CHILD_VALUE_INLINE],
+            BinaryChildValueVisitor {
+                builder: self.builder,
+            },
+        )
+    }
+}
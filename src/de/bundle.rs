@@ -0,0 +1,201 @@
+use std::fmt::Formatter;
+
+use bevy::utils::HashSet;
+use serde::de::{DeserializeSeed, Error, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::Deserializer;
+
+use bevy_proto_backend::load::{Loader, ProtoLoadContext};
+use bevy_proto_backend::proto::Prototypical;
+
+use crate::de::PrototypeDeserializer;
+use crate::prelude::Prototype;
+
+/// Deserializer for a bundle of [`Prototype`] definitions.
+///
+/// A bundle may either be a map of labels to prototypes,
+/// or a list of prototypes which are then labeled by their name.
+pub(crate) struct PrototypeBundleDeserializer<'a, 'ctx, 'load_ctx, L: Loader<Prototype>> {
+    context: &'a mut ProtoLoadContext<'ctx, 'load_ctx, Prototype, L>,
+}
+
+impl<'a, 'ctx, 'load_ctx, L: Loader<Prototype>>
+    PrototypeBundleDeserializer<'a, 'ctx, 'load_ctx, L>
+{
+    pub fn new(context: &'a mut ProtoLoadContext<'ctx, 'load_ctx, Prototype, L>) -> Self {
+        Self { context }
+    }
+}
+
+impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> DeserializeSeed<'de>
+    for PrototypeBundleDeserializer<'a, 'ctx, 'load_ctx, L>
+{
+    type Value = Vec<(String, Prototype)>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct PrototypeBundleVisitor<'a, 'ctx, 'load_ctx, L: Loader<Prototype>> {
+            context: &'a mut ProtoLoadContext<'ctx, 'load_ctx, Prototype, L>,
+        }
+
+        impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> Visitor<'de>
+            for PrototypeBundleVisitor<'a, 'ctx, 'load_ctx, L>
+        {
+            type Value = Vec<(String, Prototype)>;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                write!(formatter, "a map or list of `Prototype` structs")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut labels = HashSet::new();
+                let mut entries = Vec::with_capacity(map.size_hint().unwrap_or_default());
+
+                while let Some(label) = map.next_key::<String>()? {
+                    if !labels.insert(label.clone()) {
+                        return Err(Error::custom(format_args!(
+                            "duplicate prototype label {:?}",
+                            label
+                        )));
+                    }
+
+                    self.context.set_label(Some(label.clone()));
+                    let prototype =
+                        map.next_value_seed(PrototypeDeserializer::new(self.context))?;
+                    entries.push((label, prototype));
+                }
+
+                Ok(entries)
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut labels = HashSet::new();
+                let mut entries = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+
+                loop {
+                    // The name isn't known until the prototype is deserialized,
+                    // so its children are labeled by index instead
+                    self.context.set_label(Some(entries.len().to_string()));
+
+                    let Some(prototype) =
+                        seq.next_element_seed(PrototypeDeserializer::new(self.context))?
+                    else {
+                        break;
+                    };
+
+                    let label = prototype.id().clone();
+                    if !labels.insert(label.clone()) {
+                        return Err(Error::custom(format_args!(
+                            "duplicate prototype label {:?}",
+                            label
+                        )));
+                    }
+
+                    entries.push((label, prototype));
+                }
+
+                Ok(entries)
+            }
+        }
+
+        deserializer.deserialize_any(PrototypeBundleVisitor {
+            context: self.context,
+        })
+    }
+}
+
+/// Deserializer that forwards a single entry of a prototype bundle to the given seed.
+///
+/// Entries are selected by their index within the bundle, regardless of whether
+/// the bundle is a map or a list.
+pub(crate) struct BundleEntryDeserializer<S> {
+    index: usize,
+    seed: S,
+}
+
+impl<S> BundleEntryDeserializer<S> {
+    pub fn new(index: usize, seed: S) -> Self {
+        Self { index, seed }
+    }
+}
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for BundleEntryDeserializer<S> {
+    type Value = S::Value;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct BundleEntryVisitor<S> {
+            index: usize,
+            seed: S,
+        }
+
+        impl<'de, S: DeserializeSeed<'de>> Visitor<'de> for BundleEntryVisitor<S> {
+            type Value = S::Value;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                write!(formatter, "a map or list of `Prototype` structs")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                for _ in 0..self.index {
+                    if map.next_entry::<IgnoredAny, IgnoredAny>()?.is_none() {
+                        return Err(Error::custom(format_args!(
+                            "missing bundle entry {}",
+                            self.index
+                        )));
+                    }
+                }
+
+                if map.next_key::<IgnoredAny>()?.is_none() {
+                    return Err(Error::custom(format_args!(
+                        "missing bundle entry {}",
+                        self.index
+                    )));
+                }
+
+                let value = map.next_value_seed(self.seed)?;
+                while map.next_entry::<IgnoredAny, IgnoredAny>()?.is_some() {}
+
+                Ok(value)
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                for _ in 0..self.index {
+                    if seq.next_element::<IgnoredAny>()?.is_none() {
+                        return Err(Error::custom(format_args!(
+                            "missing bundle entry {}",
+                            self.index
+                        )));
+                    }
+                }
+
+                let value = seq.next_element_seed(self.seed)?.ok_or_else(|| {
+                    Error::custom(format_args!("missing bundle entry {}", self.index))
+                })?;
+                while seq.next_element::<IgnoredAny>()?.is_some() {}
+
+                Ok(value)
+            }
+        }
+
+        deserializer.deserialize_any(BundleEntryVisitor {
+            index: self.index,
+            seed: self.seed,
+        })
+    }
+}
@@ -19,7 +19,7 @@ const PROTO_CHILD_VALUE: &str = "value";
 
 #[derive(Deserialize, Debug)]
 #[serde(field_identifier, rename_all = "snake_case")]
-enum ProtoChildField {
+pub(super) enum ProtoChildField {
     MergeKey,
     Value,
 }
@@ -59,52 +59,14 @@ impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> DeserializeSeed<'de>
             where
                 E: Error,
             {
-                let path = ProtoPath::new(value, self.builder).map_err(Error::custom)?;
-                let handle = self.builder.add_child_path(path).map_err(Error::custom)?;
-                Ok(ProtoChild {
-                    handle,
-                    merge_key: None,
-                })
+                visit_child_path(self.builder, value)
             }
 
-            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
             where
                 A: MapAccess<'de>,
             {
-                let mut merge_key: Option<String> = None;
-                let mut handle: Option<Handle<Prototype>> = None;
-
-                while let Some(key) = map.next_key::<ProtoChildField>()? {
-                    match key {
-                        ProtoChildField::MergeKey => {
-                            if merge_key.is_some() {
-                                return Err(Error::duplicate_field(PROTO_CHILD_MERGE_KEY));
-                            }
-                            merge_key = map.next_value::<Option<String>>()?;
-                        }
-                        ProtoChildField::Value => {
-                            if handle.is_some() {
-                                return Err(Error::duplicate_field(PROTO_CHILD_VALUE));
-                            }
-
-                            let value = map
-                                .next_value_seed(ProtoChildValueDeserializer::new(self.builder))?;
-                            handle = match value {
-                                ProtoChildValue::Path(path) => {
-                                    Some(self.builder.add_child_path(path).map_err(Error::custom)?)
-                                }
-                                ProtoChildValue::Inline(prototype) => {
-                                    Some(self.builder.add_child(prototype).map_err(Error::custom)?)
-                                }
-                            };
-                        }
-                    }
-                }
-
-                Ok(ProtoChild {
-                    merge_key,
-                    handle: handle.ok_or_else(|| Error::missing_field(PROTO_CHILD_VALUE))?,
-                })
+                visit_child_map(self.builder, None, map)
             }
         }
 
@@ -113,3 +75,92 @@ impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> DeserializeSeed<'de>
         })
     }
 }
+
+/// Creates a [`ProtoChild`] from the given path string.
+pub(super) fn visit_child_path<E: Error, L: Loader<Prototype>>(
+    builder: &mut ProtoChildBuilder<'_, '_, Prototype, L>,
+    value: &str,
+) -> Result<ProtoChild, E> {
+    let path = ProtoPath::new(value, builder).map_err(Error::custom)?;
+    let handle = builder
+        .add_child_path(path.clone())
+        .map_err(Error::custom)?;
+    Ok(ProtoChild {
+        handle,
+        merge_key: None,
+        path: Some(path),
+    })
+}
+
+/// Creates a [`ProtoChild`] referencing the registered prototype with the given ID.
+pub(super) fn visit_child_id<E: Error, L: Loader<Prototype>>(
+    builder: &mut ProtoChildBuilder<'_, '_, Prototype, L>,
+    id: String,
+) -> Result<ProtoChild, E> {
+    let path = ProtoPath::from_id(id);
+    let handle = builder
+        .add_child_path(path.clone())
+        .map_err(Error::custom)?;
+    Ok(ProtoChild {
+        handle,
+        merge_key: None,
+        path: Some(path),
+    })
+}
+
+/// Creates a [`ProtoChild`] from the fields of the given map.
+///
+/// If the first key of the map has already been read, it should be passed in as `key`.
+pub(super) fn visit_child_map<'de, A: MapAccess<'de>, L: Loader<Prototype>>(
+    builder: &mut ProtoChildBuilder<'_, '_, Prototype, L>,
+    mut key: Option<ProtoChildField>,
+    mut map: A,
+) -> Result<ProtoChild, A::Error> {
+    let mut merge_key: Option<String> = None;
+    let mut handle: Option<Handle<Prototype>> = None;
+    let mut path: Option<ProtoPath> = None;
+
+    if key.is_none() {
+        key = map.next_key::<ProtoChildField>()?;
+    }
+
+    while let Some(field) = key {
+        match field {
+            ProtoChildField::MergeKey => {
+                if merge_key.is_some() {
+                    return Err(Error::duplicate_field(PROTO_CHILD_MERGE_KEY));
+                }
+                merge_key = map.next_value::<Option<String>>()?;
+            }
+            ProtoChildField::Value => {
+                if handle.is_some() {
+                    return Err(Error::duplicate_field(PROTO_CHILD_VALUE));
+                }
+
+                let value = map.next_value_seed(ProtoChildValueDeserializer::new(builder))?;
+                handle = match value {
+                    ProtoChildValue::Path(child_path) => {
+                        path = Some(child_path.clone());
+                        Some(builder.add_child_path(child_path).map_err(Error::custom)?)
+                    }
+                    ProtoChildValue::Id(id) => {
+                        let child_path = ProtoPath::from_id(id);
+                        path = Some(child_path.clone());
+                        Some(builder.add_child_path(child_path).map_err(Error::custom)?)
+                    }
+                    ProtoChildValue::Inline(prototype) => {
+                        Some(builder.add_child(*prototype).map_err(Error::custom)?)
+                    }
+                };
+            }
+        }
+
+        key = map.next_key::<ProtoChildField>()?;
+    }
+
+    Ok(ProtoChild {
+        merge_key,
+        handle: handle.ok_or_else(|| Error::missing_field(PROTO_CHILD_VALUE))?,
+        path,
+    })
+}
@@ -0,0 +1,344 @@
+use std::fmt::Formatter;
+
+use serde::de::{DeserializeSeed, Error, MapAccess, SeqAccess, Unexpected, Visitor};
+use serde::{Deserialize, Deserializer};
+
+use crate::de::child::{
+    visit_child_id, visit_child_map, visit_child_path, ProtoChildField, PROTO_CHILD,
+};
+use crate::loader::ProtoLoader;
+use bevy_proto_backend::children::{ChildForm, ProtoChildBuilder};
+use bevy_proto_backend::load::Loader;
+
+use crate::prelude::Prototype;
+use crate::proto::ProtoChild;
+
+const ONE_OF: &str = "OneOf";
+const REPEAT: &str = "Repeat";
+const RANGE: &str = "Range";
+const CHANCE: &str = "Chance";
+const ID: &str = "Id";
+
+#[derive(Deserialize, Debug, Copy, Clone)]
+#[serde(field_identifier)]
+enum ProtoChildFormField {
+    #[serde(rename = "merge_key")]
+    MergeKey,
+    #[serde(rename = "value")]
+    Value,
+    OneOf,
+    Repeat,
+    Range,
+    Chance,
+    Id,
+}
+
+/// Deserializer for a [`ChildForm`] of [`ProtoChild`].
+///
+/// A regular child (either a path or a `ProtoChild` struct) is deserialized as
+/// a [`ChildForm::Single`].
+/// The remaining forms are written as a single-entry map (or struct):
+///
+/// * `OneOf: [(weight, form), ...]`
+/// * `Repeat: (count, form)`
+/// * `Range: (min, max, form)`
+/// * `Chance: (probability, form)`
+///
+/// A child may also be referenced by the ID of a registered prototype using `Id: "name"`.
+pub struct ProtoChildFormDeserializer<'a, 'ctx, 'load_ctx, L: Loader<Prototype> = ProtoLoader> {
+    builder: &'a mut ProtoChildBuilder<'ctx, 'load_ctx, Prototype, L>,
+}
+
+impl<'a, 'ctx, 'load_ctx, L: Loader<Prototype>> ProtoChildFormDeserializer<'a, 'ctx, 'load_ctx, L> {
+    pub fn new(builder: &'a mut ProtoChildBuilder<'ctx, 'load_ctx, Prototype, L>) -> Self {
+        Self { builder }
+    }
+}
+
+impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> DeserializeSeed<'de>
+    for ProtoChildFormDeserializer<'a, 'ctx, 'load_ctx, L>
+{
+    type Value = ChildForm<ProtoChild>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ProtoChildFormVisitor<'a, 'ctx, 'load_ctx, L: Loader<Prototype>> {
+            builder: &'a mut ProtoChildBuilder<'ctx, 'load_ctx, Prototype, L>,
+        }
+        impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> Visitor<'de>
+            for ProtoChildFormVisitor<'a, 'ctx, 'load_ctx, L>
+        {
+            type Value = ChildForm<ProtoChild>;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                write!(
+                    formatter,
+                    "a `{PROTO_CHILD}` or one of `{ONE_OF}`, `{REPEAT}`, `{RANGE}`, `{CHANCE}`, or `{ID}`"
+                )
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                visit_child_path(self.builder, value).map(ChildForm::Single)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let Some(field) = map.next_key::<ProtoChildFormField>()? else {
+                    return Err(Error::invalid_length(0, &self));
+                };
+
+                let form = match field {
+                    ProtoChildFormField::MergeKey => {
+                        return visit_child_map(self.builder, Some(ProtoChildField::MergeKey), map)
+                            .map(ChildForm::Single);
+                    }
+                    ProtoChildFormField::Value => {
+                        return visit_child_map(self.builder, Some(ProtoChildField::Value), map)
+                            .map(ChildForm::Single);
+                    }
+                    ProtoChildFormField::Id => {
+                        let id = map.next_value::<String>()?;
+                        ChildForm::Single(visit_child_id(self.builder, id)?)
+                    }
+                    ProtoChildFormField::OneOf => {
+                        ChildForm::OneOf(map.next_value_seed(ProtoChildOneOfDeserializer {
+                            builder: self.builder,
+                        })?)
+                    }
+                    field => map.next_value_seed(ProtoChildFormArgsDeserializer {
+                        builder: self.builder,
+                        field,
+                    })?,
+                };
+
+                if map.next_key::<ProtoChildFormField>()?.is_some() {
+                    return Err(Error::custom(
+                        "expected a single entry for child form (found multiple)",
+                    ));
+                }
+
+                Ok(form)
+            }
+        }
+
+        deserializer.deserialize_any(ProtoChildFormVisitor {
+            builder: self.builder,
+        })
+    }
+}
+
+/// Deserializer for the list of weighted forms of a [`ChildForm::OneOf`].
+struct ProtoChildOneOfDeserializer<'a, 'ctx, 'load_ctx, L: Loader<Prototype>> {
+    builder: &'a mut ProtoChildBuilder<'ctx, 'load_ctx, Prototype, L>,
+}
+
+impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> DeserializeSeed<'de>
+    for ProtoChildOneOfDeserializer<'a, 'ctx, 'load_ctx, L>
+{
+    type Value = Vec<(f32, ChildForm<ProtoChild>)>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ProtoChildOneOfVisitor<'a, 'ctx, 'load_ctx, L: Loader<Prototype>> {
+            builder: &'a mut ProtoChildBuilder<'ctx, 'load_ctx, Prototype, L>,
+        }
+        impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> Visitor<'de>
+            for ProtoChildOneOfVisitor<'a, 'ctx, 'load_ctx, L>
+        {
+            type Value = Vec<(f32, ChildForm<ProtoChild>)>;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                write!(formatter, "a list of `(weight, form)` pairs")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut forms = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+
+                while let Some(form) = seq.next_element_seed(ProtoChildWeightedDeserializer {
+                    builder: self.builder,
+                })? {
+                    forms.push(form);
+                }
+
+                Ok(forms)
+            }
+        }
+
+        deserializer.deserialize_seq(ProtoChildOneOfVisitor {
+            builder: self.builder,
+        })
+    }
+}
+
+/// Deserializer for a single `(weight, form)` pair of a [`ChildForm::OneOf`].
+struct ProtoChildWeightedDeserializer<'a, 'ctx, 'load_ctx, L: Loader<Prototype>> {
+    builder: &'a mut ProtoChildBuilder<'ctx, 'load_ctx, Prototype, L>,
+}
+
+impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> DeserializeSeed<'de>
+    for ProtoChildWeightedDeserializer<'a, 'ctx, 'load_ctx, L>
+{
+    type Value = (f32, ChildForm<ProtoChild>);
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ProtoChildWeightedVisitor<'a, 'ctx, 'load_ctx, L: Loader<Prototype>> {
+            builder: &'a mut ProtoChildBuilder<'ctx, 'load_ctx, Prototype, L>,
+        }
+        impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> Visitor<'de>
+            for ProtoChildWeightedVisitor<'a, 'ctx, 'load_ctx, L>
+        {
+            type Value = (f32, ChildForm<ProtoChild>);
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                write!(formatter, "a `(weight, form)` pair")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let weight = next_element::<f32, A>(&mut seq, 0, &self)?;
+                if weight < 0.0 {
+                    return Err(Error::invalid_value(
+                        Unexpected::Float(weight.into()),
+                        &"a non-negative weight",
+                    ));
+                }
+
+                let form = next_form(&mut seq, 1, self.builder, ONE_OF)?;
+                Ok((weight, form))
+            }
+        }
+
+        deserializer.deserialize_tuple(
+            2,
+            ProtoChildWeightedVisitor {
+                builder: self.builder,
+            },
+        )
+    }
+}
+
+/// Deserializer for the tuple arguments of a [`ChildForm::Repeat`],
+/// [`ChildForm::Range`], or [`ChildForm::Chance`].
+struct ProtoChildFormArgsDeserializer<'a, 'ctx, 'load_ctx, L: Loader<Prototype>> {
+    builder: &'a mut ProtoChildBuilder<'ctx, 'load_ctx, Prototype, L>,
+    field: ProtoChildFormField,
+}
+
+impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> DeserializeSeed<'de>
+    for ProtoChildFormArgsDeserializer<'a, 'ctx, 'load_ctx, L>
+{
+    type Value = ChildForm<ProtoChild>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ProtoChildFormArgsVisitor<'a, 'ctx, 'load_ctx, L: Loader<Prototype>> {
+            builder: &'a mut ProtoChildBuilder<'ctx, 'load_ctx, Prototype, L>,
+            field: ProtoChildFormField,
+        }
+        impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> Visitor<'de>
+            for ProtoChildFormArgsVisitor<'a, 'ctx, 'load_ctx, L>
+        {
+            type Value = ChildForm<ProtoChild>;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                match self.field {
+                    ProtoChildFormField::Repeat => write!(formatter, "a `(count, form)` pair"),
+                    ProtoChildFormField::Range => write!(formatter, "a `(min, max, form)` tuple"),
+                    _ => write!(formatter, "a `(probability, form)` pair"),
+                }
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                match self.field {
+                    ProtoChildFormField::Repeat => {
+                        let count = next_element::<u32, A>(&mut seq, 0, &self)?;
+                        let form = next_form(&mut seq, 1, self.builder, REPEAT)?;
+                        Ok(ChildForm::Repeat(count, Box::new(form)))
+                    }
+                    ProtoChildFormField::Range => {
+                        let min = next_element::<u32, A>(&mut seq, 0, &self)?;
+                        let max = next_element::<u32, A>(&mut seq, 1, &self)?;
+                        if min > max {
+                            return Err(Error::custom(format_args!(
+                                "invalid child range: min ({min}) is greater than max ({max})"
+                            )));
+                        }
+
+                        let form = next_form(&mut seq, 2, self.builder, RANGE)?;
+                        Ok(ChildForm::Range(min, max, Box::new(form)))
+                    }
+                    _ => {
+                        let probability = next_element::<f32, A>(&mut seq, 0, &self)?;
+                        if !(0.0..=1.0).contains(&probability) {
+                            return Err(Error::invalid_value(
+                                Unexpected::Float(probability.into()),
+                                &"a probability between 0.0 and 1.0",
+                            ));
+                        }
+
+                        let form = next_form(&mut seq, 1, self.builder, CHANCE)?;
+                        Ok(ChildForm::Chance(probability, Box::new(form)))
+                    }
+                }
+            }
+        }
+
+        let len = match self.field {
+            ProtoChildFormField::Range => 3,
+            _ => 2,
+        };
+
+        deserializer.deserialize_tuple(
+            len,
+            ProtoChildFormArgsVisitor {
+                builder: self.builder,
+                field: self.field,
+            },
+        )
+    }
+}
+
+fn next_element<'de, T: Deserialize<'de>, A: SeqAccess<'de>>(
+    seq: &mut A,
+    index: usize,
+    expected: &dyn serde::de::Expected,
+) -> Result<T, A::Error> {
+    seq.next_element::<T>()?
+        .ok_or_else(|| Error::invalid_length(index, expected))
+}
+
+fn next_form<'de, A: SeqAccess<'de>, L: Loader<Prototype>>(
+    seq: &mut A,
+    index: usize,
+    builder: &mut ProtoChildBuilder<'_, '_, Prototype, L>,
+    form_name: &str,
+) -> Result<ChildForm<ProtoChild>, A::Error> {
+    seq.next_element_seed(ProtoChildFormDeserializer::new(builder))?
+        .ok_or_else(|| {
+            Error::custom(format_args!(
+                "missing child form at index {index} of `{form_name}` arguments"
+            ))
+        })
+}
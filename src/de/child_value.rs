@@ -15,12 +15,14 @@ use crate::proto::ProtoChildValue;
 const PROTO_CHILD_VALUE: &str = "ProtoChildValue";
 const PROTO_CHILD_VALUE_PATH: &str = "Path";
 const PROTO_CHILD_VALUE_INLINE: &str = "Inline";
+const PROTO_CHILD_VALUE_ID: &str = "Id";
 
 #[derive(Deserialize, Debug)]
 #[serde(variant_identifier)]
 enum ProtoChildValueVariant {
     Path,
     Inline,
+    Id,
 }
 
 pub struct ProtoChildValueDeserializer<'a, 'ctx, 'load_ctx, L: Loader<Prototype> = ProtoLoader> {
@@ -69,11 +71,12 @@ impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> DeserializeSeed<'de>
                         ))?;
                         Ok(ProtoChildValue::Path(path))
                     }
+                    ProtoChildValueVariant::Id => Ok(ProtoChildValue::Id(value.newtype_variant()?)),
                     ProtoChildValueVariant::Inline => {
                         let prototype = value.newtype_variant_seed(PrototypeDeserializer::new(
                             self.builder.context_mut(),
                         ))?;
-                        Ok(ProtoChildValue::Inline(prototype))
+                        Ok(ProtoChildValue::Inline(Box::new(prototype)))
                     }
                 }
             }
@@ -81,7 +84,11 @@ impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> DeserializeSeed<'de>
 
         deserializer.deserialize_enum(
             PROTO_CHILD_VALUE,
-            &[PROTO_CHILD_VALUE_PATH, PROTO_CHILD_VALUE_INLINE],
+            &[
+                PROTO_CHILD_VALUE_PATH,
+                PROTO_CHILD_VALUE_INLINE,
+                PROTO_CHILD_VALUE_ID,
+            ],
             ProtoChildValueVisitor {
                 builder: self.builder,
             },
@@ -1,13 +1,13 @@
 use std::fmt::Formatter;
 
 use crate::loader::ProtoLoader;
-use bevy_proto_backend::children::ProtoChildBuilder;
+use bevy_proto_backend::children::{ChildForm, ProtoChildBuilder};
 use bevy_proto_backend::load::Loader;
 use serde::de::{DeserializeSeed, SeqAccess, Visitor};
 use serde::Deserializer;
 
 use crate::de::child::PROTO_CHILD;
-use crate::de::ProtoChildDeserializer;
+use crate::de::ProtoChildFormDeserializer;
 use crate::proto::{ProtoChild, Prototype};
 
 pub struct ProtoChildrenDeserializer<'a, 'ctx, 'load_ctx, L: Loader<Prototype> = ProtoLoader> {
@@ -23,7 +23,7 @@ impl<'a, 'ctx, 'load_ctx, L: Loader<Prototype>> ProtoChildrenDeserializer<'a, 'c
 impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> DeserializeSeed<'de>
     for ProtoChildrenDeserializer<'a, 'ctx, 'load_ctx, L>
 {
-    type Value = Vec<ProtoChild>;
+    type Value = Vec<ChildForm<ProtoChild>>;
 
     fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
     where
@@ -35,7 +35,7 @@ impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> DeserializeSeed<'de>
         impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> Visitor<'de>
             for ProtoChildrenVisitor<'a, 'ctx, 'load_ctx, L>
         {
-            type Value = Vec<ProtoChild>;
+            type Value = Vec<ChildForm<ProtoChild>>;
 
             fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
                 write!(formatter, "a `{}` list", PROTO_CHILD)
@@ -48,7 +48,7 @@ impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> DeserializeSeed<'de>
                 let mut children = Vec::with_capacity(seq.size_hint().unwrap_or_default());
 
                 while let Some(child) =
-                    seq.next_element_seed(ProtoChildDeserializer::new(self.builder))?
+                    seq.next_element_seed(ProtoChildFormDeserializer::new(self.builder))?
                 {
                     children.push(child);
                 }
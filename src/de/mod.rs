@@ -1,9 +1,21 @@
+#[cfg(feature = "bincode")]
+pub use binary::*;
+pub(crate) use bundle::*;
 pub use child::*;
+pub use child_form::*;
 pub use child_value::*;
 pub use children::*;
+pub(crate) use params::*;
 pub use proto::*;
+pub(crate) use templates::*;
 
+#[cfg(feature = "bincode")]
+mod binary;
+mod bundle;
 mod child;
+mod child_form;
 mod child_value;
 mod children;
+mod params;
 mod proto;
+mod templates;
@@ -0,0 +1,531 @@
+use std::fmt::Formatter;
+
+use serde::de::{DeserializeSeed, EnumAccess, Error, MapAccess, SeqAccess, VariantAccess, Visitor};
+use serde::{forward_to_deserialize_any, Deserializer};
+
+use bevy_proto_backend::proto::{ParamValue, ProtoParams};
+
+/// The prefix used to reference a [parameter] by name.
+///
+/// [parameter]: ProtoParams
+pub(crate) const PARAM_PREFIX: char = '$';
+
+/// A [`DeserializeSeed`] wrapper that substitutes references to [parameters]
+/// (strings of the form `"$name"`) with their values.
+///
+/// Only names contained in the given [`ProtoParams`] are substituted—
+/// all other strings are passed through unchanged.
+///
+/// Since parameters may stand in for non-string values, this relies on
+/// [`Deserializer::deserialize_any`] for booleans and numbers
+/// and should therefore only be used with self-describing formats.
+///
+/// [parameters]: ProtoParams
+pub(crate) struct ParamSeed<'p, S> {
+    seed: S,
+    params: &'p ProtoParams,
+}
+
+impl<'p, S> ParamSeed<'p, S> {
+    pub fn new(seed: S, params: &'p ProtoParams) -> Self {
+        Self { seed, params }
+    }
+}
+
+impl<'de, 'p, S: DeserializeSeed<'de>> DeserializeSeed<'de> for ParamSeed<'p, S> {
+    type Value = S::Value;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.seed.deserialize(ParamDeserializer {
+            inner: deserializer,
+            params: self.params,
+        })
+    }
+}
+
+struct ParamDeserializer<'p, D> {
+    inner: D,
+    params: &'p ProtoParams,
+}
+
+impl<'de, 'p, D: Deserializer<'de>> Deserializer<'de> for ParamDeserializer<'p, D> {
+    type Error = D::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner
+            .deserialize_any(ParamVisitor::new(visitor, self.params))
+    }
+
+    // A parameter reference is always written as a string,
+    // so these need to accept any value in order to be substituted
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64
+    }
+
+    fn deserialize_char<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner
+            .deserialize_char(ParamVisitor::new(visitor, self.params))
+    }
+
+    fn deserialize_str<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner
+            .deserialize_str(ParamVisitor::new(visitor, self.params))
+    }
+
+    fn deserialize_string<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner
+            .deserialize_string(ParamVisitor::new(visitor, self.params))
+    }
+
+    fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner
+            .deserialize_bytes(ParamVisitor::new(visitor, self.params))
+    }
+
+    fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner
+            .deserialize_byte_buf(ParamVisitor::new(visitor, self.params))
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner
+            .deserialize_option(ParamVisitor::new(visitor, self.params))
+    }
+
+    fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner
+            .deserialize_unit(ParamVisitor::new(visitor, self.params))
+    }
+
+    fn deserialize_unit_struct<V>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner
+            .deserialize_unit_struct(name, ParamVisitor::new(visitor, self.params))
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner
+            .deserialize_newtype_struct(name, ParamVisitor::new(visitor, self.params))
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner
+            .deserialize_seq(ParamVisitor::new(visitor, self.params))
+    }
+
+    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner
+            .deserialize_tuple(len, ParamVisitor::new(visitor, self.params))
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner
+            .deserialize_tuple_struct(name, len, ParamVisitor::new(visitor, self.params))
+    }
+
+    fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner
+            .deserialize_map(ParamVisitor::new(visitor, self.params))
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner
+            .deserialize_struct(name, fields, ParamVisitor::new(visitor, self.params))
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner
+            .deserialize_enum(name, variants, ParamVisitor::new(visitor, self.params))
+    }
+
+    fn deserialize_identifier<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        // Identifiers (such as field and variant names) are never substituted
+        self.inner.deserialize_identifier(visitor)
+    }
+
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner.deserialize_ignored_any(visitor)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+struct ParamVisitor<'p, V> {
+    visitor: V,
+    params: &'p ProtoParams,
+}
+
+impl<'p, V> ParamVisitor<'p, V> {
+    fn new(visitor: V, params: &'p ProtoParams) -> Self {
+        Self { visitor, params }
+    }
+
+    /// Returns the value of the parameter referenced by the given string, if any.
+    fn get_param(&self, value: &str) -> Option<&'p ParamValue> {
+        value
+            .strip_prefix(PARAM_PREFIX)
+            .and_then(|name| self.params.get(name))
+    }
+}
+
+impl<'de, 'p, V: Visitor<'de>> ParamVisitor<'p, V> {
+    fn visit_param<E: Error>(self, value: &ParamValue) -> Result<V::Value, E> {
+        match value {
+            ParamValue::Bool(value) => self.visitor.visit_bool(*value),
+            ParamValue::Int(value) => self.visitor.visit_i64(*value),
+            ParamValue::Float(value) => self.visitor.visit_f64(*value),
+            ParamValue::String(value) => self.visitor.visit_str(value),
+        }
+    }
+}
+
+impl<'de, 'p, V: Visitor<'de>> Visitor<'de> for ParamVisitor<'p, V> {
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        self.visitor.expecting(formatter)
+    }
+
+    fn visit_bool<E: Error>(self, v: bool) -> Result<Self::Value, E> {
+        self.visitor.visit_bool(v)
+    }
+
+    fn visit_i8<E: Error>(self, v: i8) -> Result<Self::Value, E> {
+        self.visitor.visit_i8(v)
+    }
+
+    fn visit_i16<E: Error>(self, v: i16) -> Result<Self::Value, E> {
+        self.visitor.visit_i16(v)
+    }
+
+    fn visit_i32<E: Error>(self, v: i32) -> Result<Self::Value, E> {
+        self.visitor.visit_i32(v)
+    }
+
+    fn visit_i64<E: Error>(self, v: i64) -> Result<Self::Value, E> {
+        self.visitor.visit_i64(v)
+    }
+
+    fn visit_i128<E: Error>(self, v: i128) -> Result<Self::Value, E> {
+        self.visitor.visit_i128(v)
+    }
+
+    fn visit_u8<E: Error>(self, v: u8) -> Result<Self::Value, E> {
+        self.visitor.visit_u8(v)
+    }
+
+    fn visit_u16<E: Error>(self, v: u16) -> Result<Self::Value, E> {
+        self.visitor.visit_u16(v)
+    }
+
+    fn visit_u32<E: Error>(self, v: u32) -> Result<Self::Value, E> {
+        self.visitor.visit_u32(v)
+    }
+
+    fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+        self.visitor.visit_u64(v)
+    }
+
+    fn visit_u128<E: Error>(self, v: u128) -> Result<Self::Value, E> {
+        self.visitor.visit_u128(v)
+    }
+
+    fn visit_f32<E: Error>(self, v: f32) -> Result<Self::Value, E> {
+        self.visitor.visit_f32(v)
+    }
+
+    fn visit_f64<E: Error>(self, v: f64) -> Result<Self::Value, E> {
+        self.visitor.visit_f64(v)
+    }
+
+    fn visit_char<E: Error>(self, v: char) -> Result<Self::Value, E> {
+        self.visitor.visit_char(v)
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        match self.get_param(v) {
+            Some(param) => self.visit_param(param),
+            None => self.visitor.visit_str(v),
+        }
+    }
+
+    fn visit_borrowed_str<E: Error>(self, v: &'de str) -> Result<Self::Value, E> {
+        match self.get_param(v) {
+            Some(param) => self.visit_param(param),
+            None => self.visitor.visit_borrowed_str(v),
+        }
+    }
+
+    fn visit_string<E: Error>(self, v: String) -> Result<Self::Value, E> {
+        match self.get_param(&v) {
+            Some(param) => self.visit_param(param),
+            None => self.visitor.visit_string(v),
+        }
+    }
+
+    fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        self.visitor.visit_bytes(v)
+    }
+
+    fn visit_borrowed_bytes<E: Error>(self, v: &'de [u8]) -> Result<Self::Value, E> {
+        self.visitor.visit_borrowed_bytes(v)
+    }
+
+    fn visit_byte_buf<E: Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        self.visitor.visit_byte_buf(v)
+    }
+
+    fn visit_none<E: Error>(self) -> Result<Self::Value, E> {
+        self.visitor.visit_none()
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.visitor.visit_some(ParamDeserializer {
+            inner: deserializer,
+            params: self.params,
+        })
+    }
+
+    fn visit_unit<E: Error>(self) -> Result<Self::Value, E> {
+        self.visitor.visit_unit()
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.visitor.visit_newtype_struct(ParamDeserializer {
+            inner: deserializer,
+            params: self.params,
+        })
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        self.visitor.visit_seq(ParamSeqAccess {
+            inner: seq,
+            params: self.params,
+        })
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        self.visitor.visit_map(ParamMapAccess {
+            inner: map,
+            params: self.params,
+        })
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        self.visitor.visit_enum(ParamEnumAccess {
+            inner: data,
+            params: self.params,
+        })
+    }
+}
+
+struct ParamSeqAccess<'p, A> {
+    inner: A,
+    params: &'p ProtoParams,
+}
+
+impl<'de, 'p, A: SeqAccess<'de>> SeqAccess<'de> for ParamSeqAccess<'p, A> {
+    type Error = A::Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        self.inner
+            .next_element_seed(ParamSeed::new(seed, self.params))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+struct ParamMapAccess<'p, A> {
+    inner: A,
+    params: &'p ProtoParams,
+}
+
+impl<'de, 'p, A: MapAccess<'de>> MapAccess<'de> for ParamMapAccess<'p, A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        // Keys are never substituted
+        self.inner.next_key_seed(seed)
+    }
+
+    fn next_value_seed<T>(&mut self, seed: T) -> Result<T::Value, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        self.inner
+            .next_value_seed(ParamSeed::new(seed, self.params))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+struct ParamEnumAccess<'p, A> {
+    inner: A,
+    params: &'p ProtoParams,
+}
+
+impl<'de, 'p, A: EnumAccess<'de>> EnumAccess<'de> for ParamEnumAccess<'p, A> {
+    type Error = A::Error;
+    type Variant = ParamVariantAccess<'p, A::Variant>;
+
+    fn variant_seed<T>(self, seed: T) -> Result<(T::Value, Self::Variant), Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        let (value, variant) = self.inner.variant_seed(seed)?;
+        Ok((
+            value,
+            ParamVariantAccess {
+                inner: variant,
+                params: self.params,
+            },
+        ))
+    }
+}
+
+struct ParamVariantAccess<'p, A> {
+    inner: A,
+    params: &'p ProtoParams,
+}
+
+impl<'de, 'p, A: VariantAccess<'de>> VariantAccess<'de> for ParamVariantAccess<'p, A> {
+    type Error = A::Error;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        self.inner.unit_variant()
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        self.inner
+            .newtype_variant_seed(ParamSeed::new(seed, self.params))
+    }
+
+    fn tuple_variant<V>(self, len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner
+            .tuple_variant(len, ParamVisitor::new(visitor, self.params))
+    }
+
+    fn struct_variant<V>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner
+            .struct_variant(fields, ParamVisitor::new(visitor, self.params))
+    }
+}
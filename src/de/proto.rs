@@ -1,31 +1,53 @@
 use std::fmt::Formatter;
 
 use bevy::asset::Handle;
-use serde::de::{DeserializeSeed, Error, MapAccess, Visitor};
+use bevy::reflect::TypeRegistryInternal;
+use bevy::utils::HashMap;
+use serde::de::{DeserializeSeed, Error, IgnoredAny, MapAccess, Visitor};
 use serde::{Deserialize, Deserializer};
 
 use bevy_proto_backend::children::Children;
 use bevy_proto_backend::load::{Loader, ProtoLoadContext};
-use bevy_proto_backend::path::{ProtoPathContext, ProtoPathListDeserializer};
-use bevy_proto_backend::schematics::Schematics;
+use bevy_proto_backend::path::{ProtoPath, ProtoPathContext};
+use bevy_proto_backend::proto::ProtoParams;
+use bevy_proto_backend::schematics::{Schematics, Variants};
 use bevy_proto_backend::templates::Templates;
 
-use crate::de::ProtoChildrenDeserializer;
+use crate::de::{ProtoChildrenDeserializer, ProtoTemplatesDeserializer};
 use crate::prelude::Prototype;
-use crate::schematics::SchematicsDeserializer;
+use crate::schematics::{get_reflect_schematic, SchematicsDeserializer, VariantsDeserializer};
 
 const NAME: &str = "name";
+const PARAMS: &str = "params";
 const TEMPLATES: &str = "templates";
 const SCHEMATICS: &str = "schematics";
+const PATCHES: &str = "patches";
+const REMOVE_SCHEMATICS: &str = "remove_schematics";
+const VARIANTS: &str = "variants";
 const CHILDREN: &str = "children";
 const ENTITY: &str = "entity";
+const FIELDS: &[&str] = &[
+    NAME,
+    PARAMS,
+    TEMPLATES,
+    SCHEMATICS,
+    PATCHES,
+    REMOVE_SCHEMATICS,
+    VARIANTS,
+    CHILDREN,
+    ENTITY,
+];
 
 #[derive(Deserialize, Debug)]
 #[serde(field_identifier, rename_all = "snake_case")]
 enum PrototypeField {
     Name,
+    Params,
     Templates,
     Schematics,
+    Patches,
+    RemoveSchematics,
+    Variants,
     Children,
     Entity,
 }
@@ -67,8 +89,13 @@ impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> DeserializeSeed<'de>
                 A: MapAccess<'de>,
             {
                 let mut id: Option<String> = None;
+                let mut params: Option<ProtoParams> = None;
+                let mut template_params = HashMap::<ProtoPath, ProtoParams>::new();
                 let mut templates: Option<Templates> = None;
                 let mut schematics: Option<Schematics> = None;
+                let mut patches: Option<Schematics> = None;
+                let mut removed_schematics: Option<Vec<String>> = None;
+                let mut variants: Option<Variants> = None;
                 let mut children: Option<Children<Prototype>> = None;
                 let mut requires_entity: Option<bool> = None;
 
@@ -80,17 +107,40 @@ impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> DeserializeSeed<'de>
                             }
                             id = Some(map.next_value::<String>()?)
                         }
+                        PrototypeField::Params => {
+                            if params.is_some() {
+                                return Err(Error::duplicate_field(PARAMS));
+                            }
+
+                            if self.context.depth() > 0 {
+                                return Err(Error::custom(
+                                    "inline child prototypes cannot declare `params`",
+                                ));
+                            }
+
+                            if schematics.is_some() || patches.is_some() || variants.is_some() {
+                                return Err(Error::custom(format_args!(
+                                    "`{}` must be defined before `{}`, `{}`, and `{}`",
+                                    PARAMS, SCHEMATICS, PATCHES, VARIANTS
+                                )));
+                            }
+
+                            params = Some(map.next_value::<ProtoParams>()?);
+                        }
                         PrototypeField::Templates => {
                             if templates.is_some() {
                                 return Err(Error::duplicate_field(TEMPLATES));
                             }
 
                             let templates = templates.insert(Templates::default());
-                            let paths =
-                                map.next_value_seed(ProtoPathListDeserializer::new(self.context))?;
+                            let entries = map
+                                .next_value_seed(ProtoTemplatesDeserializer::new(&*self.context))?;
 
-                            for path in paths {
+                            for (path, params) in entries {
                                 let handle: Handle<Prototype> = self.context.get_handle(&path);
+                                if let Some(params) = params {
+                                    template_params.insert(path.clone(), params);
+                                }
                                 templates.insert(path, handle);
                             }
                         }
@@ -99,9 +149,61 @@ impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> DeserializeSeed<'de>
                                 return Err(Error::duplicate_field(SCHEMATICS));
                             }
 
-                            schematics = Some(map.next_value_seed(SchematicsDeserializer::new(
-                                self.context.registry(),
-                            ))?);
+                            let mut deserializer =
+                                SchematicsDeserializer::new(self.context.registry());
+                            if let Some(params) = &params {
+                                deserializer = deserializer.with_params(params);
+                            }
+
+                            schematics = Some(map.next_value_seed(deserializer)?);
+                        }
+                        PrototypeField::Patches => {
+                            if patches.is_some() {
+                                return Err(Error::duplicate_field(PATCHES));
+                            }
+
+                            let mut deserializer =
+                                SchematicsDeserializer::patches(self.context.registry());
+                            if let Some(params) = &params {
+                                deserializer = deserializer.with_params(params);
+                            }
+
+                            patches = Some(map.next_value_seed(deserializer)?);
+                        }
+                        PrototypeField::RemoveSchematics => {
+                            if removed_schematics.is_some() {
+                                return Err(Error::duplicate_field(REMOVE_SCHEMATICS));
+                            }
+
+                            let type_names = map.next_value::<Vec<String>>()?;
+                            for type_name in &type_names {
+                                let registration = self
+                                    .context
+                                    .registry()
+                                    .get_with_name(type_name)
+                                    .ok_or_else(|| {
+                                        Error::custom(format_args!(
+                                            "no registration found for removed schematic: `{}`",
+                                            type_name
+                                        ))
+                                    })?;
+                                get_reflect_schematic::<A::Error>(registration)?;
+                            }
+
+                            removed_schematics = Some(type_names);
+                        }
+                        PrototypeField::Variants => {
+                            if variants.is_some() {
+                                return Err(Error::duplicate_field(VARIANTS));
+                            }
+
+                            let mut deserializer =
+                                VariantsDeserializer::new(self.context.registry());
+                            if let Some(params) = &params {
+                                deserializer = deserializer.with_params(params);
+                            }
+
+                            variants = Some(map.next_value_seed(deserializer)?);
                         }
                         PrototypeField::Children => {
                             if children.is_some() {
@@ -114,8 +216,8 @@ impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> DeserializeSeed<'de>
                                     let child_list = map
                                         .next_value_seed(ProtoChildrenDeserializer::new(builder))?;
 
-                                    for child in child_list {
-                                        children.insert(child);
+                                    for form in child_list {
+                                        children.insert_form(form);
                                     }
 
                                     Ok(())
@@ -131,12 +233,19 @@ impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> DeserializeSeed<'de>
                     }
                 }
 
+                let schematics = merge_patches(schematics, patches)?;
+
                 Ok(Prototype {
                     id: id.ok_or_else(|| Error::missing_field(NAME))?,
                     path: self.context.base_path().into(),
                     requires_entity: requires_entity.unwrap_or(true),
                     templates,
-                    schematics: schematics.unwrap_or_default(),
+                    params,
+                    template_params,
+                    source: None,
+                    schematics,
+                    removed_schematics: removed_schematics.unwrap_or_default(),
+                    variants,
                     children,
                     dependencies: Default::default(),
                 })
@@ -145,10 +254,115 @@ impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> DeserializeSeed<'de>
 
         deserializer.deserialize_struct(
             std::any::type_name::<Prototype>(),
-            &[NAME, TEMPLATES, SCHEMATICS, CHILDREN, ENTITY],
+            FIELDS,
             PrototypeVisitor {
                 context: self.context,
             },
         )
     }
 }
+
+/// Deserializer for only the schematics (and patches) and variants of a [`Prototype`] file,
+/// substituting any references to the given [parameters].
+///
+/// All other fields are ignored.
+///
+/// [parameters]: ProtoParams
+pub(crate) struct ParamSchematicsDeserializer<'a> {
+    params: &'a ProtoParams,
+    registry: &'a TypeRegistryInternal,
+}
+
+impl<'a> ParamSchematicsDeserializer<'a> {
+    pub fn new(params: &'a ProtoParams, registry: &'a TypeRegistryInternal) -> Self {
+        Self { params, registry }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ParamSchematicsDeserializer<'a> {
+    type Value = (Schematics, Option<Variants>);
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ParamSchematicsVisitor<'a> {
+            params: &'a ProtoParams,
+            registry: &'a TypeRegistryInternal,
+        }
+
+        impl<'a, 'de> Visitor<'de> for ParamSchematicsVisitor<'a> {
+            type Value = (Schematics, Option<Variants>);
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                write!(formatter, "a `Prototype` struct")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut schematics: Option<Schematics> = None;
+                let mut patches: Option<Schematics> = None;
+                let mut variants: Option<Variants> = None;
+
+                while let Some(key) = map.next_key::<PrototypeField>()? {
+                    match key {
+                        PrototypeField::Schematics => {
+                            schematics = Some(map.next_value_seed(
+                                SchematicsDeserializer::new(self.registry).with_params(self.params),
+                            )?);
+                        }
+                        PrototypeField::Patches => {
+                            patches = Some(
+                                map.next_value_seed(
+                                    SchematicsDeserializer::patches(self.registry)
+                                        .with_params(self.params),
+                                )?,
+                            );
+                        }
+                        PrototypeField::Variants => {
+                            variants = Some(map.next_value_seed(
+                                VariantsDeserializer::new(self.registry).with_params(self.params),
+                            )?);
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+
+                Ok((merge_patches(schematics, patches)?, variants))
+            }
+        }
+
+        deserializer.deserialize_struct(
+            std::any::type_name::<Prototype>(),
+            FIELDS,
+            ParamSchematicsVisitor {
+                params: self.params,
+                registry: self.registry,
+            },
+        )
+    }
+}
+
+/// Combine the given schematics and patches into a single collection.
+fn merge_patches<E: Error>(
+    schematics: Option<Schematics>,
+    patches: Option<Schematics>,
+) -> Result<Schematics, E> {
+    let mut schematics = schematics.unwrap_or_default();
+    for (type_name, patch) in patches.unwrap_or_default() {
+        if schematics.contains_by_name(&type_name) {
+            return Err(Error::custom(format_args!(
+                "schematic `{}` cannot be both defined and patched",
+                type_name
+            )));
+        }
+
+        schematics.insert_dynamic(patch);
+    }
+
+    Ok(schematics)
+}
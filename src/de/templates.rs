@@ -0,0 +1,159 @@
+use std::fmt::Formatter;
+
+use serde::de::{DeserializeSeed, Error, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+
+use bevy_proto_backend::path::{ProtoPath, ProtoPathContext, ProtoPathDeserializer};
+use bevy_proto_backend::proto::ProtoParams;
+
+const PROTO_TEMPLATE: &str = "ProtoTemplate";
+const PROTO_TEMPLATE_PATH: &str = "path";
+const PROTO_TEMPLATE_PARAMS: &str = "params";
+const PROTO_TEMPLATE_ID: &str = "Id";
+
+#[derive(Deserialize, Debug)]
+#[serde(field_identifier, rename_all = "snake_case")]
+enum ProtoTemplateField {
+    Path,
+    Params,
+    #[serde(rename = "Id")]
+    Id,
+}
+
+/// Deserializer for a list of templates.
+///
+/// Each template may either be a path or a `(path: "...", params: {...})` struct
+/// containing the [parameters] to pass down to the template.
+///
+/// In place of `path`, a template may be referenced by the ID of a registered prototype
+/// using `Id: "..."`, which resolves to a [`ProtoPath::from_id`] path.
+///
+/// [parameters]: ProtoParams
+pub(crate) struct ProtoTemplatesDeserializer<'a> {
+    context: &'a dyn ProtoPathContext,
+}
+
+impl<'a> ProtoTemplatesDeserializer<'a> {
+    pub fn new(context: &'a dyn ProtoPathContext) -> Self {
+        Self { context }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ProtoTemplatesDeserializer<'a> {
+    type Value = Vec<(ProtoPath, Option<ProtoParams>)>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ProtoTemplatesVisitor<'a> {
+            context: &'a dyn ProtoPathContext,
+        }
+
+        impl<'a, 'de> Visitor<'de> for ProtoTemplatesVisitor<'a> {
+            type Value = Vec<(ProtoPath, Option<ProtoParams>)>;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                write!(formatter, "list of templates")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let size_hint = seq.size_hint().unwrap_or_default();
+                let mut templates = Vec::with_capacity(size_hint);
+
+                while let Some(template) = seq.next_element_seed(ProtoTemplateDeserializer {
+                    context: self.context,
+                })? {
+                    templates.push(template);
+                }
+
+                Ok(templates)
+            }
+        }
+
+        deserializer.deserialize_seq(ProtoTemplatesVisitor {
+            context: self.context,
+        })
+    }
+}
+
+struct ProtoTemplateDeserializer<'a> {
+    context: &'a dyn ProtoPathContext,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ProtoTemplateDeserializer<'a> {
+    type Value = (ProtoPath, Option<ProtoParams>);
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ProtoTemplateVisitor<'a> {
+            context: &'a dyn ProtoPathContext,
+        }
+
+        impl<'a, 'de> Visitor<'de> for ProtoTemplateVisitor<'a> {
+            type Value = (ProtoPath, Option<ProtoParams>);
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                write!(formatter, "a path or a `{}` struct", PROTO_TEMPLATE)
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                let path = ProtoPath::new(value, self.context).map_err(Error::custom)?;
+                Ok((path, None))
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut path: Option<ProtoPath> = None;
+                let mut params: Option<ProtoParams> = None;
+
+                while let Some(key) = map.next_key::<ProtoTemplateField>()? {
+                    match key {
+                        ProtoTemplateField::Path => {
+                            if path.is_some() {
+                                return Err(Error::duplicate_field(PROTO_TEMPLATE_PATH));
+                            }
+                            path = Some(
+                                map.next_value_seed(ProtoPathDeserializer::new(self.context))?,
+                            );
+                        }
+                        ProtoTemplateField::Id => {
+                            if path.is_some() {
+                                return Err(Error::custom(format_args!(
+                                    "expected either `{}` or `{}` (found both)",
+                                    PROTO_TEMPLATE_PATH, PROTO_TEMPLATE_ID
+                                )));
+                            }
+                            path = Some(ProtoPath::from_id(map.next_value::<String>()?));
+                        }
+                        ProtoTemplateField::Params => {
+                            if params.is_some() {
+                                return Err(Error::duplicate_field(PROTO_TEMPLATE_PARAMS));
+                            }
+                            params = Some(map.next_value::<ProtoParams>()?);
+                        }
+                    }
+                }
+
+                Ok((
+                    path.ok_or_else(|| Error::missing_field(PROTO_TEMPLATE_PATH))?,
+                    params,
+                ))
+            }
+        }
+
+        deserializer.deserialize_any(ProtoTemplateVisitor {
+            context: self.context,
+        })
+    }
+}
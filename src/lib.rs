@@ -4,8 +4,13 @@
 //!
 //! * Define entities in configuration files called _[prototypes]_
 //! * Inherit from other prototype files
+//! * Configure prototypes with parameters
 //! * Establish entity hierarchies
 //! * Load or preload assets
+//! * Write prototypes back out to configuration files
+//! * Capture existing entities as new prototypes
+//! * Build prototypes at runtime
+//! * Validate prototype files without running the game
 //!
 //! This is all built on a backend crate called [`bevy_proto_backend`].
 //! If you want to define your own prototype schema,
@@ -49,6 +54,9 @@
 //! | custom_schematics | ✅      | Enables some [custom schematics] defined by this crate         |
 //! | ron               | ✅      | Enables RON deserialization                                    |
 //! | yaml              | ❌      | Enables YAML deserialization                                   |
+//! | json              | ❌      | Enables JSON deserialization and [schema] generation           |
+//! | toml              | ❌      | Enables TOML deserialization                                   |
+//! | bincode           | ❌      | Enables the compact binary format                              |
 //! | bevy_animation    | ✅      | Registers types under Bevy's `bevy_animation` feature          |
 //! | bevy_audio        | ✅      | Registers types under Bevy's `bevy_audio` feature              |
 //! | bevy_gltf         | ✅      | Registers types under Bevy's `bevy_gltf` feature               |
@@ -60,8 +68,15 @@
 //!
 //! [prototypes]: proto::Prototype
 //! [`Name`]: bevy::core::Name
-//! [custom schematics]: custom
+#![cfg_attr(feature = "custom_schematics", doc = "[custom schematics]: custom")]
+#![cfg_attr(
+    not(feature = "custom_schematics"),
+    doc = "[custom schematics]: https://docs.rs/bevy_proto/latest/bevy_proto/custom/index.html"
+)]
+#![cfg_attr(feature = "json", doc = "[schema]: schema")]
+#![cfg_attr(not(feature = "json"), doc = "[schema]: https://json-schema.org/")]
 
+pub mod capture;
 mod conditions;
 pub mod config;
 #[cfg(feature = "custom_schematics")]
@@ -71,7 +86,11 @@ pub mod hooks;
 pub mod loader;
 mod plugin;
 pub mod proto;
+#[cfg(feature = "json")]
+pub mod schema;
 mod schematics;
+pub mod ser;
+pub mod validate;
 
 /// Provides the basics needed to use this crate.
 ///
@@ -83,14 +102,14 @@ pub mod prelude {
     pub use crate::config::ProtoConfig;
     pub use bevy_proto_backend::assets::{AssetSchematic, AssetSchematicAppExt};
     pub use bevy_proto_backend::deps::DependenciesBuilder;
-    pub use bevy_proto_backend::proto::Prototypical;
+    pub use bevy_proto_backend::proto::{ProtoRng, Prototypical};
     pub use bevy_proto_backend::schematics::{
         ReflectSchematic, Schematic, SchematicContext, SchematicId,
     };
 
     pub use super::conditions::*;
     pub use super::plugin::ProtoPlugin;
-    pub use super::proto::{Prototype, PrototypeError};
+    pub use super::proto::{Prototype, PrototypeBuilder, PrototypeError};
 
     /// A helper SystemParam for managing [prototypes].
     ///
@@ -131,6 +150,17 @@ pub mod prelude {
     /// [prototype]: Prototype
     /// [`AssetEvent`]: bevy::asset::AssetEvent
     pub type ProtoAssetEvent = bevy_proto_backend::assets::ProtoAssetEvent<Prototype>;
+
+    /// Event sent when a [prototype] that was waiting to finish loading
+    /// has been inserted onto its entity.
+    ///
+    /// [prototype]: Prototype
+    pub type ProtoSpawned = bevy_proto_backend::proto::ProtoSpawned<Prototype>;
+
+    /// Event sent when a [prototype] could not be spawned.
+    ///
+    /// [prototype]: Prototype
+    pub type ProtoSpawnFailed = bevy_proto_backend::proto::ProtoSpawnFailed<Prototype>;
 }
 
 /// Provides access to the [backend crate] that `bevy_proto` is built on.
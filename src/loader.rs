@@ -1,11 +1,194 @@
-use crate::de::PrototypeDeserializer;
-use crate::proto::{Prototype, PrototypeError};
-use bevy_proto_backend::load::{Loader, ProtoLoadContext};
-use bevy_proto_backend::path::ProtoPathContext;
+//! Loading prototypes from files using the [`ProtoLoader`].
+//!
+//! See the [`ProtoLoader`] for the supported formats and their extensions.
+//!
+//! # TOML
+//!
+//! TOML prototypes use the same fields as the other formats.
+//! Since TOML requires plain values to come before any tables,
+//! the `schematics` map is best written as a set of tables keyed by type name,
+//! while `children` is an array of paths or inline tables:
+//!
+//! ```toml
+//! name = "Player"
+//! templates = ["Character.prototype.toml"]
+//! children = ["Sword.prototype.toml"]
+//!
+//! [schematics."bevy_proto::custom::SpriteBundle"]
+//! texture = { AssetPath = "textures/player.png" }
+//! ```
+//!
+//! # Parameters
+//!
+//! Prototypes may declare `params` along with their default values.
+//! Any string of the form `"$name"` within their `schematics` or `patches`
+//! is then replaced by the value of that parameter:
+//!
+//! ```text
+//! (
+//!   name: "Enemy",
+//!   params: { "speed": 5.0, "texture": "textures/enemy.png" },
+//!   schematics: {
+//!     "my_game::Speed": (value: "$speed"),
+//!     "bevy_proto::custom::SpriteBundle": (texture: AssetPath("$texture")),
+//!   }
+//! )
+//! ```
+//!
+//! The defaults can be overridden by inheriting prototypes within their `templates` entry,
+//! such as `(path: "Enemy.prototype.ron", params: { "speed": 10.0 })`,
+//! or when spawning with [`ProtoCommands::spawn_with`].
+//!
+//! Since `params` affects how the schematics are read, it must come before them in the file.
+//! Parameters are only supported by the text formats and may not be declared by inline children.
+//! Also note that only the assets referenced by the default values are preloaded.
+//!
+//! # Random Children
+//!
+//! Besides regular children, the `children` list accepts forms that randomly select
+//! which children are spawned:
+//!
+//! ```text
+//! children: [
+//!   (OneOf: [(3.0, "Goblin.prototype.ron"), (1.0, "Orc.prototype.ron")]),
+//!   (Repeat: (2, "Torch.prototype.ron")),
+//!   (Range: (0, 3, "Coin.prototype.ron")),
+//!   (Chance: (0.25, "Chest.prototype.ron")),
+//! ]
+//! ```
+//!
+//! Forms may be nested and are resolved each time the prototype is spawned,
+//! using the [`ProtoRng`] resource (which can be reseeded for deterministic replays).
+//! Random forms are not supported by the binary format.
+//!
+//! # Variants
+//!
+//! Prototypes may list alternative sets of schematics under `variants`.
+//! Each spawned instance applies exactly one of them on top of its regular schematics:
+//!
+//! ```text
+//! (
+//!   name: "Slime",
+//!   schematics: { "my_game::Health": (value: 10) },
+//!   variants: [
+//!     { "bevy_proto::custom::SpriteBundle": (texture: AssetPath("textures/red_slime.png")) },
+//!     { "bevy_proto::custom::SpriteBundle": (texture: AssetPath("textures/blue_slime.png")) },
+//!   ],
+//! )
+//! ```
+//!
+//! The variant is picked using the same seed as the random children,
+//! which can be given explicitly with [`ProtoCommands::spawn_seeded`].
+//! Parameter references within variants are substituted just like in the regular schematics.
+//! Variants are not supported by the binary format.
+//!
+//! # Bundles
+//!
+//! Multiple prototypes may be defined in a single bundle file
+//! using the plural form of the extensions above (e.g. `.prototypes.ron`).
+//! A bundle is either a map of labels to prototypes or a list of prototypes,
+//! in which case each prototype is labeled by its name:
+//!
+//! ```text
+//! {
+//!   "Grunt": (name: "Grunt", templates: ["#Orc"]),
+//!   "Orc": (name: "Orc", schematics: { "my_game::Health": (value: 10) }),
+//! }
+//! ```
+//!
+//! Each entry is loaded as its own labeled asset, such as `units/orcs.prototypes.ron#Grunt`.
+//! Templates and children may reference an entry of the same bundle using just its label
+//! (e.g. `"#Orc"`), or an entry of another bundle using its full path
+//! (e.g. `"orcs.prototypes.ron#Orc"`).
+//! Bundles are not supported by the binary format.
+//!
+//! # References by ID
+//!
+//! Templates and children may also reference another prototype by its ID instead of its path,
+//! so that files can be moved around without breaking the prototypes that inherit from them:
+//!
+//! ```text
+//! (
+//!   name: "BigGoblin",
+//!   templates: [(Id: "Goblin"), (Id: "Big", params: { "scale": 2.0 })],
+//!   children: [(Id: "Torch"), (merge_key: "hat", value: Id("Hat"))],
+//! )
+//! ```
+//!
+//! Unlike paths, these references are not loaded automatically.
+//! Instead, registration is deferred until a prototype with the given ID has been registered,
+//! so the referenced prototypes should be loaded separately (e.g. by loading the whole folder).
+//! References by ID are not supported by the binary format.
+//!
+//! # Namespaces
+//!
+//! Prototypes from different sources (such as mods) may share the same name.
+//! To keep their IDs apart, a namespace can be assigned to a folder using [`with_namespace`].
+//! Every prototype loaded from within that folder then has its name prefixed by the namespace,
+//! so a prototype named `Sword` within the `base` namespace is registered as `base:Sword`:
+//!
+//! ```
+//! # use bevy_proto::loader::ProtoLoader;
+//! let loader = ProtoLoader::default()
+//!   .with_namespace("mods/base", "base")
+//!   .with_namespace("mods/extra", "extra");
+//! ```
+//!
+//! Names that already contain a namespace (e.g. `extra:Sword`) are left as-is.
+//! References by ID, as well as entity access paths, are first resolved within
+//! the namespace of the prototype that contains them before falling back to the ID as-is.
+//! Similarly, IDs passed to [`ProtoCommands`] are first resolved within the namespace
+//! returned by [`Config::namespace`] (see [`ProtoConfig::with_namespace`]).
+//!
+//! # Binary
+//!
+//! The binary format is meant for shipping pre-baked prototypes
+//! and is generated using [`PrototypeSerializer::to_binary`].
+//! Note that any template or child paths are kept as-is,
+//! so prototypes that reference each other should be converted together.
+//!
+//! Only a subset of prototype features can be represented in the binary format.
+//! Parameters, variants, schematic patches, random children, and references by ID
+//! are not supported and cause [`to_binary`] to return an error.
+//! Bundles are not supported either, as there is no binary bundle extension.
+//!
+//! [`to_binary`]: crate::ser::PrototypeSerializer::to_binary
+//! [`ProtoCommands::spawn_with`]: bevy_proto_backend::proto::ProtoCommands::spawn_with
+//! [`ProtoRng`]: bevy_proto_backend::proto::ProtoRng
+//! [`ProtoCommands::spawn_seeded`]: bevy_proto_backend::proto::ProtoCommands::spawn_seeded
+//! [`PrototypeSerializer::to_binary`]: crate::ser::PrototypeSerializer::to_binary
+//! [`with_namespace`]: ProtoLoader::with_namespace
+//! [`ProtoCommands`]: bevy_proto_backend::proto::ProtoCommands
+//! [`Config::namespace`]: bevy_proto_backend::proto::Config::namespace
+//! [`ProtoConfig::with_namespace`]: crate::config::ProtoConfig::with_namespace
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::config::find_by_folder;
+use crate::de::{
+    BundleEntryDeserializer, ParamSchematicsDeserializer, PrototypeBundleDeserializer,
+    PrototypeDeserializer,
+};
+use crate::proto::{Prototype, PrototypeError, PrototypeSource};
+use bevy::asset::AssetPath;
+use bevy::reflect::TypeRegistryInternal;
+use bevy_proto_backend::load::{Loader, ProtoLoadContext, ProtoLoadMeta};
+use bevy_proto_backend::path::{ProtoPath, ProtoPathContext};
+use bevy_proto_backend::proto::{qualify_id, ProtoParams};
+use bevy_proto_backend::schematics::{Schematics, Variants};
 use serde::de::DeserializeSeed;
 
 const RON_FORMATS: &[&str] = &["prototype.ron", "proto.ron"];
 const YAML_FORMATS: &[&str] = &["prototype.yaml", "proto.yaml"];
+const JSON_FORMATS: &[&str] = &["prototype.json", "proto.json"];
+const TOML_FORMATS: &[&str] = &["prototype.toml", "proto.toml"];
+const BINARY_FORMATS: &[&str] = &["prototype.bin", "proto.bin"];
+
+const RON_BUNDLE_FORMATS: &[&str] = &["prototypes.ron", "protos.ron"];
+const YAML_BUNDLE_FORMATS: &[&str] = &["prototypes.yaml", "protos.yaml"];
+const JSON_BUNDLE_FORMATS: &[&str] = &["prototypes.json", "protos.json"];
+const TOML_BUNDLE_FORMATS: &[&str] = &["prototypes.toml", "protos.toml"];
 
 /// The default prototype loader.
 ///
@@ -15,27 +198,79 @@ const YAML_FORMATS: &[&str] = &["prototype.yaml", "proto.yaml"];
 /// | ------ | ------- | ---------- |
 /// | [RON]    | `ron`   | `.prototype.ron`, `.proto.ron` |
 /// | [YAML]   | `yaml`  | `.prototype.yaml`, `.proto.yaml` |
+/// | [JSON]   | `json`  | `.prototype.json`, `.proto.json` |
+/// | [TOML]   | `toml`  | `.prototype.toml`, `.proto.toml` |
+/// | Binary   | `bincode` | `.prototype.bin`, `.proto.bin` |
 ///
+/// Each text format also supports [bundles] of multiple prototypes.
+/// See the [module documentation] for the features supported by each format.
+///
+/// [bundles]: crate::loader#bundles
+/// [module documentation]: crate::loader
 /// [RON]: https://github.com/ron-rs/ron
 /// [YAML]: https://github.com/dtolnay/serde-yaml
+/// [JSON]: https://github.com/serde-rs/json
+/// [TOML]: https://github.com/toml-rs/toml
 #[derive(Clone)]
 pub struct ProtoLoader {
     extensions: Vec<&'static str>,
+    bundle_extensions: Vec<&'static str>,
+    namespaces: Vec<(PathBuf, String)>,
 }
 
 impl Default for ProtoLoader {
     fn default() -> Self {
         let mut extensions = Vec::new();
+        let mut bundle_extensions = Vec::new();
 
         if cfg!(feature = "yaml") {
             extensions.extend(YAML_FORMATS);
+            bundle_extensions.extend(YAML_BUNDLE_FORMATS);
+        }
+
+        if cfg!(feature = "bincode") {
+            extensions.extend(BINARY_FORMATS);
+        }
+
+        if cfg!(feature = "toml") {
+            extensions.extend(TOML_FORMATS);
+            bundle_extensions.extend(TOML_BUNDLE_FORMATS);
+        }
+
+        if cfg!(feature = "json") {
+            extensions.extend(JSON_FORMATS);
+            bundle_extensions.extend(JSON_BUNDLE_FORMATS);
         }
 
         if cfg!(feature = "ron") {
             extensions.extend(RON_FORMATS);
+            bundle_extensions.extend(RON_BUNDLE_FORMATS);
         }
 
-        Self { extensions }
+        Self {
+            extensions,
+            bundle_extensions,
+            namespaces: Vec::new(),
+        }
+    }
+}
+
+impl ProtoLoader {
+    /// Assign the given namespace to all prototypes loaded from within the given folder.
+    ///
+    /// See the [namespaces](#namespaces) section for details.
+    pub fn with_namespace(
+        mut self,
+        folder: impl Into<PathBuf>,
+        namespace: impl Into<String>,
+    ) -> Self {
+        self.namespaces.push((folder.into(), namespace.into()));
+        self
+    }
+
+    /// Returns the namespace of the most specific folder containing the given path, if any.
+    fn namespace_of(&self, path: &Path) -> Option<&str> {
+        find_by_folder(&self.namespaces, path).map(String::as_str)
     }
 }
 
@@ -46,37 +281,231 @@ impl Loader<Prototype> for ProtoLoader {
         bytes: &[u8],
         ctx: &mut ProtoLoadContext<Prototype, Self>,
     ) -> Result<Prototype, Self::Error> {
-        let path = ctx.base_path().to_path_buf();
-
-        let ext = path
-            .extension()
-            .ok_or_else(|| PrototypeError::MissingExtension(path.clone()))?;
-
-        let ext = ext
-            .to_str()
-            .ok_or_else(|| PrototypeError::UnsupportedExtension(ext.to_string_lossy().to_string()))?
-            .to_lowercase();
-
-        let deserializer = PrototypeDeserializer::new(ctx);
-
-        match ext.as_str() {
-            #[cfg(feature = "ron")]
-            "ron" => {
-                let mut ron_de = ron::Deserializer::from_bytes(bytes)
-                    .map_err(|err| PrototypeError::SpannedRonError(path.clone(), err))?;
-                deserializer.deserialize(&mut ron_de).map_err(|err| {
-                    PrototypeError::SpannedRonError(path.clone(), ron_de.span_error(err))
-                })
-            }
-            #[cfg(feature = "yaml")]
-            "yaml" => deserializer
-                .deserialize(serde_yaml::Deserializer::from_slice(bytes))
-                .map_err(PrototypeError::from),
-            other => Err(PrototypeError::UnsupportedExtension(other.to_string())),
-        }
+        deserialize_prototype(bytes, ctx)
     }
 
     fn extensions(&self) -> &[&'static str] {
         &self.extensions
     }
+
+    fn bundle_extensions(&self) -> &[&'static str] {
+        &self.bundle_extensions
+    }
+
+    fn deserialize_bundle(
+        bytes: &[u8],
+        ctx: &mut ProtoLoadContext<Prototype, Self>,
+    ) -> Result<Vec<(String, Prototype)>, Self::Error> {
+        deserialize_prototype_bundle(bytes, ctx)
+    }
+
+    fn on_load_prototype(
+        &self,
+        mut prototype: Prototype,
+        meta: &ProtoLoadMeta<Prototype>,
+    ) -> Result<Prototype, Self::Error> {
+        if let Some(namespace) = self.namespace_of(meta.path.path()) {
+            if let Some(id) = qualify_id(&prototype.id, namespace) {
+                prototype.id = id;
+            }
+        }
+
+        Ok(prototype)
+    }
+}
+
+/// Deserialize a [`Prototype`] based on the extension of the file being loaded.
+///
+/// This allows other [`Loader`] implementations to reuse the formats supported by [`ProtoLoader`].
+pub(crate) fn deserialize_prototype<L: Loader<Prototype>>(
+    bytes: &[u8],
+    ctx: &mut ProtoLoadContext<Prototype, L>,
+) -> Result<Prototype, PrototypeError> {
+    let path = ctx.base_path().to_path_buf();
+    let ext = get_extension(&path)?;
+
+    match ext.as_str() {
+        #[cfg(feature = "bincode")]
+        "bin" => {
+            let mut bin_de =
+                bincode::Deserializer::from_slice(bytes, bincode::DefaultOptions::new());
+            crate::de::BinaryPrototypeDeserializer::new(ctx)
+                .deserialize(&mut bin_de)
+                .map_err(PrototypeError::from)
+        }
+        _ => {
+            let mut prototype =
+                deserialize_text(bytes, &path, &ext, PrototypeDeserializer::new(ctx))?;
+
+            if prototype.params.is_some() {
+                // Keep the source around so the schematics can be re-deserialized
+                // with different parameter values
+                prototype.source = Some(PrototypeSource {
+                    bytes: bytes.into(),
+                    entry: None,
+                });
+            }
+
+            Ok(prototype)
+        }
+    }
+}
+
+/// Deserialize a bundle of labeled [`Prototype`]s based on the extension of the file being loaded.
+pub(crate) fn deserialize_prototype_bundle<L: Loader<Prototype>>(
+    bytes: &[u8],
+    ctx: &mut ProtoLoadContext<Prototype, L>,
+) -> Result<Vec<(String, Prototype)>, PrototypeError> {
+    let path = ctx.base_path().to_path_buf();
+    let ext = get_extension(&path)?;
+
+    let mut entries = deserialize_text(bytes, &path, &ext, PrototypeBundleDeserializer::new(ctx))?;
+
+    let mut source: Option<Arc<[u8]>> = None;
+    for (index, (label, prototype)) in entries.iter_mut().enumerate() {
+        prototype.path = ProtoPath::from(AssetPath::new(path.clone(), Some(label.clone())));
+
+        if prototype.params.is_some() {
+            prototype.source = Some(PrototypeSource {
+                bytes: source.get_or_insert_with(|| bytes.into()).clone(),
+                entry: Some(index),
+            });
+        }
+    }
+
+    Ok(entries)
+}
+
+/// Deserialize the schematics and variants of the given [`Prototype`] file,
+/// substituting any parameter references with the given values.
+///
+/// If `entry` is given, the file is treated as a bundle and only that entry is read.
+pub(crate) fn deserialize_schematics_with_params(
+    bytes: &[u8],
+    entry: Option<usize>,
+    path: &Path,
+    params: &ProtoParams,
+    registry: &TypeRegistryInternal,
+) -> Result<(Schematics, Option<Variants>), PrototypeError> {
+    let ext = get_extension(path)?;
+    let seed = ParamSchematicsDeserializer::new(params, registry);
+    match entry {
+        Some(index) => {
+            deserialize_text(bytes, path, &ext, BundleEntryDeserializer::new(index, seed))
+        }
+        None => deserialize_text(bytes, path, &ext, seed),
+    }
+}
+
+/// Returns the lowercase extension of the given path.
+fn get_extension(path: &Path) -> Result<String, PrototypeError> {
+    let ext = path
+        .extension()
+        .ok_or_else(|| PrototypeError::MissingExtension(path.to_path_buf()))?;
+
+    Ok(ext
+        .to_str()
+        .ok_or_else(|| PrototypeError::UnsupportedExtension(ext.to_string_lossy().to_string()))?
+        .to_lowercase())
+}
+
+/// Deserialize the given seed using the text format matching the given extension.
+fn deserialize_text<T, S>(
+    bytes: &[u8],
+    path: &Path,
+    ext: &str,
+    seed: S,
+) -> Result<T, PrototypeError>
+where
+    S: for<'de> DeserializeSeed<'de, Value = T>,
+{
+    match ext {
+        #[cfg(feature = "ron")]
+        "ron" => {
+            let mut ron_de = ron::Deserializer::from_bytes(bytes)
+                .map_err(|err| PrototypeError::SpannedRonError(path.to_path_buf(), err))?;
+            seed.deserialize(&mut ron_de).map_err(|err| {
+                PrototypeError::SpannedRonError(path.to_path_buf(), ron_de.span_error(err))
+            })
+        }
+        #[cfg(feature = "yaml")]
+        "yaml" => seed
+            .deserialize(serde_yaml::Deserializer::from_slice(bytes))
+            .map_err(PrototypeError::from),
+        #[cfg(feature = "json")]
+        "json" => {
+            let mut json_de = serde_json::Deserializer::from_slice(bytes);
+            let value = seed
+                .deserialize(&mut json_de)
+                .map_err(|err| PrototypeError::spanned_json(path.to_path_buf(), err))?;
+            json_de
+                .end()
+                .map_err(|err| PrototypeError::spanned_json(path.to_path_buf(), err))?;
+            Ok(value)
+        }
+        #[cfg(feature = "toml")]
+        "toml" => {
+            let input = std::str::from_utf8(bytes).map_err(PrototypeError::custom)?;
+            seed.deserialize(toml::Deserializer::new(input))
+                .map_err(|err| PrototypeError::spanned_toml(path.to_path_buf(), input, err))
+        }
+        other => Err(PrototypeError::UnsupportedExtension(other.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::marker::PhantomData;
+
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Named {
+        name: String,
+    }
+
+    fn deserialize_named(input: &str, ext: &str) -> Result<Named, PrototypeError> {
+        let path = PathBuf::from(format!("Test.prototype.{ext}"));
+        deserialize_text(input.as_bytes(), &path, ext, PhantomData::<Named>)
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn should_report_json_error_location() {
+        let named = deserialize_named(r#"{ "name": "Test" }"#, "json").unwrap();
+        assert_eq!("Test", named.name);
+
+        let input = "{\n  \"name\": \"Test\",\n}";
+        match deserialize_named(input, "json") {
+            Err(PrototypeError::SpannedJsonError {
+                path, line, column, ..
+            }) => {
+                assert_eq!(PathBuf::from("Test.prototype.json"), path);
+                assert_eq!(3, line);
+                assert_eq!(1, column);
+            }
+            other => panic!("expected JSON error, found {other:?}"),
+        }
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn should_report_toml_error_location() {
+        let named = deserialize_named(r#"name = "Test""#, "toml").unwrap();
+        assert_eq!("Test", named.name);
+
+        let input = "# The name should be a string\nname = 5";
+        match deserialize_named(input, "toml") {
+            Err(PrototypeError::SpannedTomlError {
+                path, line, column, ..
+            }) => {
+                assert_eq!(PathBuf::from("Test.prototype.toml"), path);
+                assert_eq!(2, line);
+                assert_eq!(8, column);
+            }
+            other => panic!("expected TOML error, found {other:?}"),
+        }
+    }
 }
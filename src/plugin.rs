@@ -26,6 +26,7 @@ use crate::proto::Prototype;
 pub struct ProtoPlugin<L: Loader<Prototype> = ProtoLoader, C: Config<Prototype> = ProtoConfig> {
     loader: Mutex<Option<L>>,
     config: Mutex<Option<C>>,
+    reload_instances: bool,
 }
 
 impl ProtoPlugin {
@@ -34,6 +35,7 @@ impl ProtoPlugin {
         Self {
             loader: Mutex::new(None),
             config: Mutex::new(None),
+            reload_instances: false,
         }
     }
 }
@@ -44,6 +46,7 @@ impl<L: Loader<Prototype>> ProtoPlugin<L, ProtoConfig> {
         Self {
             loader: Mutex::new(Some(loader)),
             config: Mutex::new(None),
+            reload_instances: false,
         }
     }
 
@@ -67,6 +70,7 @@ impl<C: Config<Prototype>> ProtoPlugin<ProtoLoader, C> {
         Self {
             loader: Mutex::new(None),
             config: Mutex::new(Some(config)),
+            reload_instances: false,
         }
     }
 }
@@ -84,13 +88,26 @@ impl<L: Loader<Prototype>, C: Config<Prototype>> ProtoPlugin<L, C> {
         Self {
             loader: Mutex::new(Some(loader)),
             config: Mutex::new(Some(config)),
+            reload_instances: false,
         }
     }
+
+    /// Enable automatic reloading of existing [`Prototype`] instances.
+    ///
+    /// When enabled, entities spawned from a prototype (along with their children)
+    /// will automatically be updated whenever that prototype is modified.
+    ///
+    /// This is disabled by default.
+    pub fn with_instance_reloading(mut self, enabled: bool) -> Self {
+        self.reload_instances = enabled;
+        self
+    }
 }
 
 impl<L: Loader<Prototype>, C: Config<Prototype>> Plugin for ProtoPlugin<L, C> {
     fn build(&self, app: &mut App) {
-        let mut plugin = ProtoBackendPlugin::<Prototype, L, C>::new();
+        let mut plugin = ProtoBackendPlugin::<Prototype, L, C>::new()
+            .with_instance_reloading(self.reload_instances);
 
         if let Ok(Some(config)) = self.config.lock().map(|mut config| config.take()) {
             plugin = plugin.with_config(config);
@@ -0,0 +1,261 @@
+use bevy::asset::{AssetServer, Assets, Handle, HandleId};
+
+use bevy_proto_backend::children::Children;
+use bevy_proto_backend::deps::Dependencies;
+use bevy_proto_backend::path::ProtoPath;
+use bevy_proto_backend::proto::ProtoParams;
+use bevy_proto_backend::schematics::{DynamicSchematic, Schematic, Schematics};
+use bevy_proto_backend::templates::Templates;
+
+use crate::proto::{ProtoChild, Prototype};
+
+/// Builder used to construct a [`Prototype`] at runtime.
+///
+/// Once [built], the prototype is added to `Assets<Prototype>` where it will be
+/// registered just like any prototype loaded from a file,
+/// allowing it to be spawned with [`ProtoCommands`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_proto::prelude::*;
+/// #[derive(Component, Schematic, Reflect)]
+/// #[reflect(Schematic)]
+/// struct Health(u32);
+///
+/// fn create_enemy(mut prototypes: ResMut<Assets<Prototype>>, asset_server: Res<AssetServer>) {
+///   let handle = PrototypeBuilder::new("Enemy")
+///     .with_template("Creature")
+///     .with_schematic::<Health>(Health(25))
+///     .build(&mut prototypes, &asset_server);
+///   // Keep `handle` around for as long as the prototype should stay registered
+/// }
+/// ```
+///
+/// [built]: Self::build
+/// [`ProtoCommands`]: crate::prelude::ProtoCommands
+pub struct PrototypeBuilder {
+    id: String,
+    path: Option<ProtoPath>,
+    requires_entity: bool,
+    schematics: Schematics,
+    removed_schematics: Vec<String>,
+    variants: Vec<Schematics>,
+    params: Option<ProtoParams>,
+    templates: Vec<ProtoReference>,
+    children: Vec<ProtoChildReference>,
+}
+
+enum ProtoReference {
+    Id(String),
+    Path(ProtoPath),
+}
+
+enum ProtoChildReference {
+    Path(ProtoPath),
+    Inline(PrototypeBuilder),
+}
+
+impl PrototypeBuilder {
+    /// Create a new builder for a prototype with the given ID.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            path: None,
+            requires_entity: true,
+            schematics: Schematics::default(),
+            removed_schematics: Vec::new(),
+            variants: Vec::new(),
+            params: None,
+            templates: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Set the [path] used to identify the prototype in errors and when serialized.
+    ///
+    /// Defaults to `"{id}.prototype"`.
+    ///
+    /// [path]: bevy_proto_backend::proto::Prototypical::path
+    pub fn with_path(mut self, path: impl Into<ProtoPath>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Set whether or not the prototype requires an entity to be spawned.
+    ///
+    /// Defaults to `true`.
+    pub fn with_requires_entity(mut self, requires_entity: bool) -> Self {
+        self.requires_entity = requires_entity;
+        self
+    }
+
+    /// Add a [`Schematic`] using its [input] data.
+    ///
+    /// [input]: Schematic::Input
+    pub fn with_schematic<S: Schematic>(mut self, input: S::Input) -> Self {
+        self.schematics.insert::<S>(input);
+        self
+    }
+
+    /// Add a [`DynamicSchematic`].
+    pub fn with_dynamic_schematic(mut self, schematic: DynamicSchematic) -> Self {
+        self.schematics.insert_dynamic(schematic);
+        self
+    }
+
+    /// Prevent the given [`Schematic`] from being inherited from any templates.
+    pub fn with_removed_schematic<S: Schematic>(mut self) -> Self {
+        self.removed_schematics
+            .push(std::any::type_name::<S>().to_string());
+        self
+    }
+
+    /// Add an alternative set of [`Schematics`] as a new [variant].
+    ///
+    /// [variant]: bevy_proto_backend::proto::Prototypical::variants
+    pub fn with_variant(mut self, schematics: Schematics) -> Self {
+        self.variants.push(schematics);
+        self
+    }
+
+    /// Declare the [parameters] of the prototype along with their default values.
+    ///
+    /// Since schematics are given to this builder as typed inputs, they cannot
+    /// reference these parameters themselves.
+    /// However, the declared parameters are kept when the prototype is [serialized].
+    ///
+    /// [parameters]: bevy_proto_backend::proto::Prototypical::params
+    /// [serialized]: crate::ser::PrototypeSerializer
+    pub fn with_params(mut self, params: ProtoParams) -> Self {
+        self.params = Some(params);
+        self
+    }
+
+    /// Add the prototype with the given ID as a template.
+    ///
+    /// Registration of the built prototype is deferred until a prototype with this ID is registered.
+    pub fn with_template(mut self, id: impl Into<String>) -> Self {
+        self.templates.push(ProtoReference::Id(id.into()));
+        self
+    }
+
+    /// Add the prototype at the given asset path as a template.
+    ///
+    /// The template is loaded when this builder is [built],
+    /// and registration of the built prototype is deferred until the template is registered.
+    ///
+    /// [built]: Self::build
+    pub fn with_template_path(mut self, path: impl Into<ProtoPath>) -> Self {
+        self.templates.push(ProtoReference::Path(path.into()));
+        self
+    }
+
+    /// Add the prototype at the given asset path as a child.
+    ///
+    /// The child is loaded when this builder is [built],
+    /// and registration of the built prototype is deferred until the child is registered.
+    ///
+    /// [built]: Self::build
+    pub fn with_child_path(mut self, path: impl Into<ProtoPath>) -> Self {
+        self.children.push(ProtoChildReference::Path(path.into()));
+        self
+    }
+
+    /// Add an inline child prototype.
+    ///
+    /// The child will be built alongside this prototype.
+    pub fn with_child(mut self, child: PrototypeBuilder) -> Self {
+        self.children.push(ProtoChildReference::Inline(child));
+        self
+    }
+
+    /// Build the prototype and add it to the given `Assets<Prototype>`.
+    ///
+    /// The [`AssetServer`] is used to load any templates and children given by path.
+    ///
+    /// Any templates and children are tracked as dependencies of the built prototype,
+    /// so that changes to them are reflected in the built prototype.
+    ///
+    /// The returned handle is _strong_.
+    /// The prototype will remain registered for as long as this handle is kept alive.
+    pub fn build(
+        self,
+        prototypes: &mut Assets<Prototype>,
+        asset_server: &AssetServer,
+    ) -> Handle<Prototype> {
+        let prototype = self.build_prototype(prototypes, asset_server);
+        prototypes.add(prototype)
+    }
+
+    fn build_prototype(
+        self,
+        prototypes: &mut Assets<Prototype>,
+        asset_server: &AssetServer,
+    ) -> Prototype {
+        let templates = if self.templates.is_empty() {
+            None
+        } else {
+            let mut templates = Templates::default();
+            for template in self.templates {
+                match template {
+                    ProtoReference::Id(id) => {
+                        // Resolved by the registry, just like an ID reference within a file
+                        let path = ProtoPath::from_id(id);
+                        let handle: Handle<Prototype> =
+                            asset_server.get_handle(HandleId::from(&path));
+                        templates.insert(path, handle);
+                    }
+                    ProtoReference::Path(path) => {
+                        let handle = asset_server.load_untyped(path.asset_path().clone());
+                        templates.insert(path, handle);
+                    }
+                }
+            }
+            Some(templates)
+        };
+
+        let children = if self.children.is_empty() {
+            None
+        } else {
+            let mut children = Children::default();
+            for child in self.children {
+                children.insert(match child {
+                    ProtoChildReference::Path(path) => ProtoChild {
+                        merge_key: None,
+                        handle: asset_server.load(path.asset_path().clone()),
+                        path: Some(path),
+                    },
+                    ProtoChildReference::Inline(builder) => ProtoChild {
+                        merge_key: None,
+                        handle: builder.build(prototypes, asset_server),
+                        path: None,
+                    },
+                });
+            }
+            Some(children)
+        };
+
+        Prototype {
+            path: self
+                .path
+                .unwrap_or_else(|| ProtoPath::from(format!("{}.prototype", self.id))),
+            id: self.id,
+            requires_entity: self.requires_entity,
+            schematics: self.schematics,
+            removed_schematics: self.removed_schematics,
+            variants: if self.variants.is_empty() {
+                None
+            } else {
+                Some(self.variants.into_iter().collect())
+            },
+            templates,
+            params: self.params,
+            template_params: Default::default(),
+            source: None,
+            dependencies: Dependencies::default(),
+            children,
+        }
+    }
+}
@@ -11,6 +11,10 @@ use crate::proto::Prototype;
 pub struct ProtoChild {
     pub(crate) merge_key: Option<String>,
     pub(crate) handle: Handle<Prototype>,
+    /// The path this child was loaded from, if it was not defined inline.
+    ///
+    /// Children referenced by ID use [`ProtoPath::from_id`].
+    pub(crate) path: Option<ProtoPath>,
 }
 
 impl PrototypicalChild<Prototype> for ProtoChild {
@@ -23,12 +27,18 @@ impl PrototypicalChild<Prototype> for ProtoChild {
     fn merge_key(&self) -> Option<&Self::Key> {
         self.merge_key.as_ref()
     }
+
+    fn path(&self) -> Option<&ProtoPath> {
+        self.path.as_ref()
+    }
 }
 
 /// The enum representation of a serialized [`Prototype`] child.
 pub enum ProtoChildValue {
     /// The child is the prototype asset at the given path.
     Path(ProtoPath),
+    /// The child is the registered prototype with the given ID.
+    Id(String),
     /// The child is the contained prototype.
-    Inline(Prototype),
+    Inline(Box<Prototype>),
 }
@@ -3,6 +3,7 @@ use std::path::PathBuf;
 
 use thiserror::Error;
 
+use bevy_proto_backend::proto::ProtoLoadError;
 use bevy_proto_backend::schematics::SchematicError;
 
 /// Error type for a [`Prototype`].
@@ -22,16 +23,89 @@ pub enum PrototypeError {
     #[cfg(feature = "ron")]
     #[error("RON error in {0:?}: {1}")]
     SpannedRonError(PathBuf, ron::de::SpannedError),
-    /// Error loading YAML file.
+    /// Error serializing to RON.
+    #[cfg(feature = "ron")]
+    #[error(transparent)]
+    RonError(#[from] ron::Error),
+    /// Error loading or serializing YAML.
     #[cfg(feature = "yaml")]
     #[error(transparent)]
     YamlError(#[from] serde_yaml::Error),
+    /// Error loading JSON file.
+    #[cfg(feature = "json")]
+    #[error("JSON error in {path:?} at {line}:{column}: {error}")]
+    SpannedJsonError {
+        path: PathBuf,
+        line: usize,
+        column: usize,
+        error: serde_json::Error,
+    },
+    /// Error serializing to JSON.
+    #[cfg(feature = "json")]
+    #[error(transparent)]
+    JsonError(#[from] serde_json::Error),
+    /// Error loading TOML file.
+    ///
+    /// The line and column are `0` if the error could not be attributed to a location.
+    #[cfg(feature = "toml")]
+    #[error("TOML error in {path:?} at {line}:{column}: {error}")]
+    SpannedTomlError {
+        path: PathBuf,
+        line: usize,
+        column: usize,
+        error: Box<toml::de::Error>,
+    },
+    /// Error loading or serializing the binary format.
+    #[cfg(feature = "bincode")]
+    #[error(transparent)]
+    BincodeError(#[from] bincode::Error),
     #[error(transparent)]
     SchematicError(#[from] SchematicError),
+    /// Error loading a prototype asset.
+    #[error(transparent)]
+    LoadError(#[from] ProtoLoadError),
+    /// Error reading or writing a prototype file.
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
 }
 
 impl PrototypeError {
     pub fn custom(msg: impl Display) -> Self {
         Self::Custom(format!("{}", msg))
     }
+
+    /// Create a [`PrototypeError::SpannedJsonError`] from the given JSON error.
+    #[cfg(feature = "json")]
+    pub(crate) fn spanned_json(path: PathBuf, error: serde_json::Error) -> Self {
+        Self::SpannedJsonError {
+            path,
+            line: error.line(),
+            column: error.column(),
+            error,
+        }
+    }
+
+    /// Create a [`PrototypeError::SpannedTomlError`] from the given TOML error and its input.
+    #[cfg(feature = "toml")]
+    pub(crate) fn spanned_toml(path: PathBuf, input: &str, error: toml::de::Error) -> Self {
+        let (line, column) = error
+            .span()
+            .and_then(|span| input.get(..span.start))
+            .map_or((0, 0), |before| {
+                let line = before.matches('\n').count() + 1;
+                let column = before
+                    .rsplit('\n')
+                    .next()
+                    .map_or(0, |line| line.chars().count())
+                    + 1;
+                (line, column)
+            });
+
+        Self::SpannedTomlError {
+            path,
+            line,
+            column,
+            error: Box::new(error),
+        }
+    }
 }
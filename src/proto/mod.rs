@@ -1,9 +1,11 @@
 //! Items relating to the main [`Prototype`] struct.
 
+pub use builder::*;
 pub use child::*;
 pub use error::*;
 pub use prototype::*;
 
+mod builder;
 pub mod child;
 mod error;
 mod prototype;
@@ -1,10 +1,15 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use crate::loader::deserialize_schematics_with_params;
 use crate::proto::ProtoChild;
-use bevy::reflect::{TypePath, TypeUuid};
+use bevy::reflect::{TypePath, TypeRegistryInternal, TypeUuid};
+use bevy::utils::HashMap;
 use bevy_proto_backend::children::Children;
 use bevy_proto_backend::deps::Dependencies;
 use bevy_proto_backend::path::ProtoPath;
-use bevy_proto_backend::proto::Prototypical;
-use bevy_proto_backend::schematics::Schematics;
+use bevy_proto_backend::proto::{ProtoError, ProtoParams, Prototypical};
+use bevy_proto_backend::schematics::{Schematics, Variants};
 use bevy_proto_backend::templates::Templates;
 
 /// The core asset type used to create easily-configurable entity trees.
@@ -15,7 +20,12 @@ pub struct Prototype {
     pub(crate) path: ProtoPath,
     pub(crate) requires_entity: bool,
     pub(crate) schematics: Schematics,
+    pub(crate) removed_schematics: Vec<String>,
+    pub(crate) variants: Option<Variants>,
     pub(crate) templates: Option<Templates>,
+    pub(crate) params: Option<ProtoParams>,
+    pub(crate) template_params: HashMap<ProtoPath, ProtoParams>,
+    pub(crate) source: Option<PrototypeSource>,
     pub(crate) dependencies: Dependencies,
     pub(crate) children: Option<Children<Prototype>>,
 }
@@ -44,6 +54,49 @@ impl Prototypical for Prototype {
         &mut self.schematics
     }
 
+    fn removed_schematics(&self) -> &[String] {
+        &self.removed_schematics
+    }
+
+    fn variants(&self) -> Option<&Variants> {
+        self.variants.as_ref()
+    }
+
+    fn variants_mut(&mut self) -> Option<&mut Variants> {
+        self.variants.as_mut()
+    }
+
+    fn params(&self) -> Option<&ProtoParams> {
+        self.params.as_ref()
+    }
+
+    fn template_params(&self, template: &ProtoPath) -> Option<&ProtoParams> {
+        self.template_params.get(template)
+    }
+
+    fn schematics_with_params(
+        &self,
+        params: &ProtoParams,
+        registry: &TypeRegistryInternal,
+    ) -> Result<Option<(Schematics, Option<Variants>)>, ProtoError> {
+        let Some(source) = &self.source else {
+            return Ok(None);
+        };
+
+        deserialize_schematics_with_params(
+            &source.bytes,
+            source.entry,
+            self.path.path(),
+            params,
+            registry,
+        )
+        .map(Some)
+        .map_err(|err| ProtoError::InvalidParams {
+            id: self.id.clone(),
+            error: err.to_string(),
+        })
+    }
+
     fn templates(&self) -> Option<&Templates> {
         self.templates.as_ref()
     }
@@ -68,3 +121,32 @@ impl Prototypical for Prototype {
         self.children.as_mut()
     }
 }
+
+/// The raw contents of a parameterized [`Prototype`] file.
+///
+/// This is kept around so that the prototype's schematics can be deserialized again
+/// whenever it is spawned with non-default [parameters].
+///
+/// [parameters]: ProtoParams
+pub(crate) struct PrototypeSource {
+    /// The contents of the file.
+    ///
+    /// This is shared between all parameterized entries of a bundle.
+    pub bytes: Arc<[u8]>,
+    /// The index of the prototype within its bundle, if any.
+    pub entry: Option<usize>,
+}
+
+impl Debug for PrototypeSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.entry {
+            Some(entry) => write!(
+                f,
+                "PrototypeSource({} bytes, entry {})",
+                self.bytes.len(),
+                entry
+            ),
+            None => write!(f, "PrototypeSource({} bytes)", self.bytes.len()),
+        }
+    }
+}
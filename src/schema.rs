@@ -0,0 +1,414 @@
+//! Items for generating a [JSON Schema] describing prototype files.
+//!
+//! The generated schema can be given to editors (such as through the YAML or JSON
+//! language servers in VS Code) to provide autocompletion and validation for
+//! `.prototype.json` and `.prototype.yaml` files.
+//!
+//! [JSON Schema]: https://json-schema.org/
+
+use std::any::TypeId;
+use std::collections::BTreeMap;
+
+use bevy::reflect::serde::SerializationData;
+use bevy::reflect::{TypeInfo, TypeRegistryInternal, VariantInfo};
+use serde_json::{json, Map, Value};
+
+use bevy_proto_backend::schematics::ReflectSchematic;
+
+const DRAFT: &str = "http://json-schema.org/draft-07/schema#";
+const PROTO_CHILD: &str = "ProtoChild";
+const PROTO_PARAMS: &str = "ProtoParams";
+const PARAM_REFERENCE: &str = "ParamReference";
+
+/// Generate a [JSON Schema] for [`Prototype`] files.
+///
+/// The schema describes the `name`, `params`, `templates`, `schematics`, `patches`,
+/// `remove_schematics`, `variants`, `children`, and `entity` fields,
+/// as well as the [input] of every schematic registered in the given registry
+/// with [`ReflectSchematic`].
+///
+/// Since any field may be replaced by a parameter reference (such as `"$speed"`),
+/// every field also accepts a string starting with `$`.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_proto::prelude::*;
+/// # use bevy_proto::schema::generate_schema;
+/// fn write_schema(registry: Res<AppTypeRegistry>) {
+///   let schema = generate_schema(&registry.read());
+///   std::fs::write("prototype.schema.json", schema.to_string()).unwrap();
+/// }
+/// ```
+///
+/// [JSON Schema]: https://json-schema.org/
+/// [`Prototype`]: crate::proto::Prototype
+/// [input]: bevy_proto_backend::schematics::Schematic::Input
+pub fn generate_schema(registry: &TypeRegistryInternal) -> Value {
+    SchemaGenerator::new(registry).generate()
+}
+
+struct SchemaGenerator<'a> {
+    registry: &'a TypeRegistryInternal,
+    definitions: BTreeMap<String, Value>,
+}
+
+impl<'a> SchemaGenerator<'a> {
+    fn new(registry: &'a TypeRegistryInternal) -> Self {
+        Self {
+            registry,
+            definitions: BTreeMap::new(),
+        }
+    }
+
+    fn generate(mut self) -> Value {
+        let mut schematics = Map::new();
+        let mut schematic_names = Vec::new();
+        for registration in self.registry.iter() {
+            let Some(reflect_schematic) = registration.data::<ReflectSchematic>() else {
+                continue;
+            };
+
+            let input_registration = reflect_schematic.input_registration();
+            let schema = self.type_schema(input_registration.type_id());
+            schematics.insert(registration.type_name().to_string(), schema);
+            schematic_names.push(registration.type_name());
+        }
+
+        self.definitions.insert(
+            PROTO_CHILD.to_string(),
+            json!({
+                "oneOf": [
+                    { "type": "string" },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "merge_key": { "type": "string" },
+                            "value": {
+                                "oneOf": [
+                                    variant_schema("Path", json!({ "type": "string" })),
+                                    variant_schema("Inline", json!({ "$ref": "#" })),
+                                    variant_schema("Id", json!({ "type": "string" })),
+                                ]
+                            }
+                        },
+                        "required": ["value"],
+                        "additionalProperties": false
+                    },
+                    variant_schema("OneOf", json!({
+                        "type": "array",
+                        "items": form_args_schema(&[json!({ "type": "number", "minimum": 0 })]),
+                    })),
+                    variant_schema("Repeat", form_args_schema(&[
+                        json!({ "type": "integer", "minimum": 0 }),
+                    ])),
+                    variant_schema("Range", form_args_schema(&[
+                        json!({ "type": "integer", "minimum": 0 }),
+                        json!({ "type": "integer", "minimum": 0 }),
+                    ])),
+                    variant_schema("Chance", form_args_schema(&[
+                        json!({ "type": "number", "minimum": 0, "maximum": 1 }),
+                    ])),
+                    variant_schema("Id", json!({ "type": "string" })),
+                ]
+            }),
+        );
+
+        self.definitions.insert(
+            PARAM_REFERENCE.to_string(),
+            json!({ "type": "string", "pattern": "^\\$" }),
+        );
+
+        self.definitions.insert(
+            PROTO_PARAMS.to_string(),
+            json!({
+                "type": "object",
+                "additionalProperties": { "type": ["boolean", "number", "string"] }
+            }),
+        );
+
+        json!({
+            "$schema": DRAFT,
+            "title": "Prototype",
+            "type": "object",
+            "properties": {
+                "name": { "type": "string" },
+                "params": { "$ref": definition_ref(PROTO_PARAMS) },
+                "templates": {
+                    "type": "array",
+                    "items": {
+                        "oneOf": [
+                            { "type": "string" },
+                            template_schema("path"),
+                            template_schema("Id"),
+                        ]
+                    }
+                },
+                "schematics": {
+                    "type": "object",
+                    "properties": schematics.clone(),
+                    "additionalProperties": false
+                },
+                "patches": {
+                    "type": "object",
+                    "properties": schematics.clone(),
+                    "additionalProperties": false
+                },
+                "remove_schematics": {
+                    "type": "array",
+                    "items": { "enum": schematic_names }
+                },
+                "variants": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": schematics,
+                        "additionalProperties": false
+                    }
+                },
+                "children": {
+                    "type": "array",
+                    "items": { "$ref": definition_ref(PROTO_CHILD) }
+                },
+                "entity": { "type": "boolean" }
+            },
+            "required": ["name"],
+            "additionalProperties": false,
+            "definitions": self.definitions,
+        })
+    }
+
+    /// Returns the schema for the type with the given [`TypeId`].
+    ///
+    /// Structs, tuple structs, and enums are stored as definitions and referenced,
+    /// which allows recursive types to be described.
+    fn type_schema(&mut self, type_id: TypeId) -> Value {
+        let Some(registration) = self.registry.get(type_id) else {
+            // Unregistered types cannot be described
+            return json!({});
+        };
+
+        let type_info = registration.type_info();
+        match type_info {
+            TypeInfo::Struct(_) | TypeInfo::TupleStruct(_) | TypeInfo::Enum(_)
+                if !is_option(type_info) =>
+            {
+                let key = definition_key(type_info.type_name());
+                if !self.definitions.contains_key(&key) {
+                    // Insert a placeholder to prevent infinite recursion
+                    self.definitions.insert(key.clone(), json!({}));
+                    let schema = self.complex_schema(type_info);
+                    self.definitions.insert(key.clone(), schema);
+                }
+                json!({ "$ref": definition_ref(&key) })
+            }
+            _ => self.complex_schema(type_info),
+        }
+    }
+
+    /// Returns the schema for a field of the type with the given [`TypeId`],
+    /// which may also be replaced by a parameter reference.
+    fn field_schema(&mut self, type_id: TypeId) -> Value {
+        json!({
+            "anyOf": [
+                self.type_schema(type_id),
+                { "$ref": definition_ref(PARAM_REFERENCE) }
+            ]
+        })
+    }
+
+    fn complex_schema(&mut self, type_info: &TypeInfo) -> Value {
+        let serialization_data = self
+            .registry
+            .get(type_info.type_id())
+            .and_then(|registration| registration.data::<SerializationData>());
+        let is_ignored = |index: usize| {
+            serialization_data
+                .map(|data| data.is_ignored_field(index))
+                .unwrap_or_default()
+        };
+
+        match type_info {
+            TypeInfo::Struct(info) => {
+                let mut properties = Map::new();
+                for (index, field) in info.iter().enumerate() {
+                    if !is_ignored(index) {
+                        properties
+                            .insert(field.name().to_string(), self.field_schema(field.type_id()));
+                    }
+                }
+                json!({
+                    "type": "object",
+                    "properties": properties,
+                    "additionalProperties": false
+                })
+            }
+            TypeInfo::TupleStruct(info) => {
+                let items = info
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| !is_ignored(*index))
+                    .map(|(_, field)| field.type_id())
+                    .collect::<Vec<_>>();
+                self.tuple_schema(items)
+            }
+            TypeInfo::Tuple(info) => {
+                let items = info.iter().map(|field| field.type_id()).collect();
+                self.tuple_schema(items)
+            }
+            TypeInfo::List(info) => json!({
+                "type": "array",
+                "items": self.field_schema(info.item_type_id())
+            }),
+            TypeInfo::Array(info) => json!({
+                "type": "array",
+                "items": self.field_schema(info.item_type_id()),
+                "minItems": info.capacity(),
+                "maxItems": info.capacity()
+            }),
+            TypeInfo::Map(info) => json!({
+                "type": "object",
+                "additionalProperties": self.field_schema(info.value_type_id())
+            }),
+            TypeInfo::Enum(info) if is_option(type_info) => {
+                let some = info.variant("Some").and_then(|variant| match variant {
+                    VariantInfo::Tuple(tuple) => tuple.field_at(0).map(|field| field.type_id()),
+                    _ => None,
+                });
+                match some {
+                    Some(type_id) => json!({
+                        "anyOf": [{ "type": "null" }, self.type_schema(type_id)]
+                    }),
+                    None => json!({}),
+                }
+            }
+            TypeInfo::Enum(info) => {
+                let variants = info
+                    .iter()
+                    .map(|variant| match variant {
+                        VariantInfo::Unit(unit) => json!({ "const": unit.name() }),
+                        VariantInfo::Tuple(tuple) if tuple.field_len() == 1 => {
+                            let field = tuple.field_at(0).unwrap();
+                            variant_schema(tuple.name(), self.field_schema(field.type_id()))
+                        }
+                        VariantInfo::Tuple(tuple) => {
+                            let items = tuple.iter().map(|field| field.type_id()).collect();
+                            variant_schema(tuple.name(), self.tuple_schema(items))
+                        }
+                        VariantInfo::Struct(info) => {
+                            let mut properties = Map::new();
+                            for field in info.iter() {
+                                properties.insert(
+                                    field.name().to_string(),
+                                    self.field_schema(field.type_id()),
+                                );
+                            }
+                            variant_schema(
+                                info.name(),
+                                json!({
+                                    "type": "object",
+                                    "properties": properties,
+                                    "additionalProperties": false
+                                }),
+                            )
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                json!({ "oneOf": variants })
+            }
+            TypeInfo::Value(info) => value_schema(info.type_name()),
+        }
+    }
+
+    fn tuple_schema(&mut self, items: Vec<TypeId>) -> Value {
+        let len = items.len();
+        let items = items
+            .into_iter()
+            .map(|type_id| self.field_schema(type_id))
+            .collect::<Vec<_>>();
+        json!({
+            "type": "array",
+            "items": items,
+            "minItems": len,
+            "maxItems": len
+        })
+    }
+}
+
+/// Returns the schema for the tuple arguments of a child form,
+/// which end with the nested child form itself.
+fn form_args_schema(args: &[Value]) -> Value {
+    let mut items = args.to_vec();
+    items.push(json!({ "$ref": definition_ref(PROTO_CHILD) }));
+    let len = items.len();
+    json!({
+        "type": "array",
+        "items": items,
+        "minItems": len,
+        "maxItems": len
+    })
+}
+
+/// Returns the schema for a template referenced by the given key (either `path` or `Id`),
+/// along with any parameters passed to it.
+fn template_schema(key: &str) -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            key: { "type": "string" },
+            "params": { "$ref": definition_ref(PROTO_PARAMS) }
+        },
+        "required": [key],
+        "additionalProperties": false
+    })
+}
+
+/// Returns the schema for an externally tagged enum variant.
+fn variant_schema(name: &str, value: Value) -> Value {
+    json!({
+        "type": "object",
+        "properties": { name: value },
+        "required": [name],
+        "additionalProperties": false
+    })
+}
+
+/// Returns the schema for a primitive value type.
+fn value_schema(type_name: &str) -> Value {
+    match type_name {
+        "bool" => json!({ "type": "boolean" }),
+        "u8" | "u16" | "u32" | "u64" | "u128" | "usize" => {
+            json!({ "type": "integer", "minimum": 0 })
+        }
+        "i8" | "i16" | "i32" | "i64" | "i128" | "isize" => json!({ "type": "integer" }),
+        "f32" | "f64" => json!({ "type": "number" }),
+        "char" => json!({ "type": "string", "minLength": 1, "maxLength": 1 }),
+        "alloc::string::String"
+        | "&str"
+        | "alloc::borrow::Cow<str>"
+        | "std::path::PathBuf"
+        | "bevy_asset::path::AssetPath" => json!({ "type": "string" }),
+        _ => json!({}),
+    }
+}
+
+fn is_option(type_info: &TypeInfo) -> bool {
+    matches!(type_info, TypeInfo::Enum(_))
+        && type_info.type_name().starts_with("core::option::Option")
+}
+
+/// Converts a type name into a key that is safe to use within a JSON pointer.
+fn definition_key(type_name: &str) -> String {
+    type_name
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '_' | ':' | '.' | '-' => c,
+            _ => '_',
+        })
+        .collect()
+}
+
+fn definition_ref(key: &str) -> String {
+    format!("#/definitions/{key}")
+}
@@ -1,19 +1,50 @@
 use std::fmt::Formatter;
 
-use bevy::reflect::serde::{TypeRegistrationDeserializer, TypedReflectDeserializer};
-use bevy::reflect::TypeRegistryInternal;
-use serde::de::{DeserializeSeed, Error, MapAccess, Visitor};
-use serde::Deserializer;
+use bevy::reflect::serde::{
+    TypeRegistrationDeserializer, TypedReflectDeserializer, TypedReflectSerializer,
+};
+use bevy::reflect::{TypeRegistration, TypeRegistryInternal};
+use serde::de::{DeserializeSeed, Error, MapAccess, SeqAccess, Visitor};
+use serde::ser::{SerializeMap, SerializeSeq};
+use serde::{Deserializer, Serialize, Serializer};
 
-use bevy_proto_backend::schematics::{ReflectSchematic, Schematics};
+use bevy_proto_backend::proto::ProtoParams;
+use bevy_proto_backend::schematics::{ReflectSchematic, Schematics, Variants};
+
+use crate::de::ParamSeed;
 
 pub(crate) struct SchematicsDeserializer<'a> {
     registry: &'a TypeRegistryInternal,
+    params: Option<&'a ProtoParams>,
+    is_patch: bool,
 }
 
 impl<'a> SchematicsDeserializer<'a> {
     pub fn new(registry: &'a TypeRegistryInternal) -> Self {
-        Self { registry }
+        Self {
+            registry,
+            params: None,
+            is_patch: false,
+        }
+    }
+
+    /// Create a deserializer for schematic [patches].
+    ///
+    /// [patches]: bevy_proto_backend::schematics::ReflectSchematic::create_patch
+    pub fn patches(registry: &'a TypeRegistryInternal) -> Self {
+        Self {
+            registry,
+            params: None,
+            is_patch: true,
+        }
+    }
+
+    /// Substitute any references to the given [parameters] within the schematic inputs.
+    ///
+    /// [parameters]: ProtoParams
+    pub fn with_params(mut self, params: &'a ProtoParams) -> Self {
+        self.params = Some(params);
+        self
     }
 }
 
@@ -26,6 +57,8 @@ impl<'de, 'a> DeserializeSeed<'de> for SchematicsDeserializer<'a> {
     {
         struct SchematicsVisitor<'a> {
             registry: &'a TypeRegistryInternal,
+            params: Option<&'a ProtoParams>,
+            is_patch: bool,
         }
         impl<'de, 'a> Visitor<'de> for SchematicsVisitor<'a> {
             type Value = Schematics;
@@ -51,24 +84,26 @@ impl<'de, 'a> DeserializeSeed<'de> for SchematicsDeserializer<'a> {
                         )));
                     }
 
-                    let reflect_schematic =
-                        registration.data::<ReflectSchematic>().ok_or_else(|| {
-                            Error::custom(format_args!(
-                                "missing `ReflectSchematic` registration for schematic: `{}`",
-                                registration.type_name()
-                            ))
-                        })?;
+                    let reflect_schematic = get_reflect_schematic(registration)?;
 
                     let input_registration = reflect_schematic.input_registration();
 
-                    let input = map.next_value_seed(TypedReflectDeserializer::new(
-                        &input_registration,
-                        self.registry,
-                    ))?;
+                    let input_deserializer =
+                        TypedReflectDeserializer::new(&input_registration, self.registry);
+                    let input = match self.params {
+                        Some(params) => {
+                            map.next_value_seed(ParamSeed::new(input_deserializer, params))?
+                        }
+                        None => map.next_value_seed(input_deserializer)?,
+                    };
 
-                    let schematic = reflect_schematic
-                        .create_dynamic(input)
-                        .map_err(Error::custom)?;
+                    let schematic = if self.is_patch {
+                        reflect_schematic.create_patch(input)
+                    } else {
+                        reflect_schematic
+                            .create_dynamic(input)
+                            .map_err(Error::custom)?
+                    };
 
                     schematics.insert_dynamic(schematic);
                 }
@@ -79,10 +114,167 @@ impl<'de, 'a> DeserializeSeed<'de> for SchematicsDeserializer<'a> {
 
         deserializer.deserialize_map(SchematicsVisitor {
             registry: self.registry,
+            params: self.params,
+            is_patch: self.is_patch,
         })
     }
 }
 
+/// Deserializer for a list of schematic [`Variants`].
+pub(crate) struct VariantsDeserializer<'a> {
+    registry: &'a TypeRegistryInternal,
+    params: Option<&'a ProtoParams>,
+}
+
+impl<'a> VariantsDeserializer<'a> {
+    pub fn new(registry: &'a TypeRegistryInternal) -> Self {
+        Self {
+            registry,
+            params: None,
+        }
+    }
+
+    /// Substitute any references to the given [parameters] within the schematic inputs.
+    ///
+    /// [parameters]: ProtoParams
+    pub fn with_params(mut self, params: &'a ProtoParams) -> Self {
+        self.params = Some(params);
+        self
+    }
+}
+
+impl<'de, 'a> DeserializeSeed<'de> for VariantsDeserializer<'a> {
+    type Value = Variants;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct VariantsVisitor<'a> {
+            registry: &'a TypeRegistryInternal,
+            params: Option<&'a ProtoParams>,
+        }
+
+        impl<'de, 'a> Visitor<'de> for VariantsVisitor<'a> {
+            type Value = Variants;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                write!(formatter, "a list of schematic maps")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut variants = Variants::with_capacity(seq.size_hint().unwrap_or_default());
+
+                while let Some(schematics) = seq.next_element_seed(SchematicsDeserializer {
+                    registry: self.registry,
+                    params: self.params,
+                    is_patch: false,
+                })? {
+                    variants.push(schematics);
+                }
+
+                Ok(variants)
+            }
+        }
+
+        deserializer.deserialize_seq(VariantsVisitor {
+            registry: self.registry,
+            params: self.params,
+        })
+    }
+}
+
+/// Get the [`ReflectSchematic`] of the given schematic registration.
+pub(crate) fn get_reflect_schematic<E: Error>(
+    registration: &TypeRegistration,
+) -> Result<&ReflectSchematic, E> {
+    registration.data::<ReflectSchematic>().ok_or_else(|| {
+        Error::custom(format_args!(
+            "missing `ReflectSchematic` registration for schematic: `{}`",
+            registration.type_name()
+        ))
+    })
+}
+
+pub(crate) struct SchematicsSerializer<'a> {
+    schematics: &'a Schematics,
+    registry: &'a TypeRegistryInternal,
+    is_patch: bool,
+}
+
+impl<'a> SchematicsSerializer<'a> {
+    pub fn new(schematics: &'a Schematics, registry: &'a TypeRegistryInternal) -> Self {
+        Self {
+            schematics,
+            registry,
+            is_patch: false,
+        }
+    }
+
+    /// Create a serializer for only the schematic [patches] in the given collection.
+    ///
+    /// [patches]: bevy_proto_backend::schematics::DynamicSchematic::is_patch
+    pub fn patches(schematics: &'a Schematics, registry: &'a TypeRegistryInternal) -> Self {
+        Self {
+            schematics,
+            registry,
+            is_patch: true,
+        }
+    }
+}
+
+impl<'a> Serialize for SchematicsSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // Sort by type name so the output is stable
+        let mut schematics = self
+            .schematics
+            .iter()
+            .filter(|(_, schematic)| schematic.is_patch() == self.is_patch)
+            .collect::<Vec<_>>();
+        schematics.sort_by_key(|(type_name, _)| *type_name);
+
+        let mut map = serializer.serialize_map(Some(schematics.len()))?;
+        for (type_name, schematic) in schematics {
+            map.serialize_entry(
+                type_name.as_ref(),
+                &TypedReflectSerializer::new(schematic.input(), self.registry),
+            )?;
+        }
+        map.end()
+    }
+}
+
+/// Serializer for a list of schematic [`Variants`].
+pub(crate) struct VariantsSerializer<'a> {
+    variants: &'a Variants,
+    registry: &'a TypeRegistryInternal,
+}
+
+impl<'a> VariantsSerializer<'a> {
+    pub fn new(variants: &'a Variants, registry: &'a TypeRegistryInternal) -> Self {
+        Self { variants, registry }
+    }
+}
+
+impl<'a> Serialize for VariantsSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.variants.len()))?;
+        for schematics in self.variants.iter() {
+            seq.serialize_element(&SchematicsSerializer::new(schematics, self.registry))?;
+        }
+        seq.end()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use bevy::prelude::Component;
@@ -97,6 +289,12 @@ mod tests {
         foo: usize,
     }
 
+    #[derive(Reflect, Component, Schematic, Eq, PartialEq, Debug)]
+    struct MyPatchedSchematic {
+        foo: usize,
+        bar: usize,
+    }
+
     #[test]
     fn should_deserialize_schematics() {
         let mut registry = TypeRegistryInternal::new();
@@ -127,6 +325,71 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_serialize_schematics() {
+        let mut registry = TypeRegistryInternal::new();
+        registry.register::<MySchematic>();
+        registry.register_type_data::<MySchematic, ReflectSchematic>();
+
+        let mut schematics = Schematics::default();
+        schematics.insert::<MySchematic>(MySchematic { foo: 123 });
+
+        let output = ron::to_string(&SchematicsSerializer::new(&schematics, &registry)).unwrap();
+
+        let deserializer = SchematicsDeserializer::new(&registry);
+        let schematics = deserializer
+            .deserialize(&mut ron::de::Deserializer::from_str(&output).unwrap())
+            .unwrap();
+
+        assert_eq!(
+            &MySchematic { foo: 123 },
+            schematics
+                .get::<MySchematic>()
+                .unwrap()
+                .input()
+                .downcast_ref::<MySchematic>()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn should_deserialize_patches() {
+        let mut registry = TypeRegistryInternal::new();
+        registry.register::<MyPatchedSchematic>();
+        registry.register_type_data::<MyPatchedSchematic, ReflectSchematic>();
+
+        let input = r#"
+{
+    "bevy_proto::schematics::tests::MyPatchedSchematic": (
+        bar: 456
+    )
+}"#;
+
+        let deserializer = SchematicsDeserializer::patches(&registry);
+        let patches = deserializer
+            .deserialize(&mut ron::de::Deserializer::from_str(input).unwrap())
+            .unwrap();
+        let patch = patches.get::<MyPatchedSchematic>().unwrap();
+        assert!(patch.is_patch());
+
+        let mut schematics = Schematics::default();
+        schematics.insert::<MyPatchedSchematic>(MyPatchedSchematic { foo: 123, bar: 0 });
+        let patched = schematics
+            .get::<MyPatchedSchematic>()
+            .unwrap()
+            .with_patch(patch)
+            .unwrap();
+
+        assert!(!patched.is_patch());
+        assert_eq!(
+            &MyPatchedSchematic { foo: 123, bar: 456 },
+            patched
+                .input()
+                .downcast_ref::<MyPatchedSchematic>()
+                .unwrap()
+        );
+    }
+
     #[test]
     #[should_panic(expected = "missing `ReflectSchematic` registration for schematic")]
     fn should_not_deserialize_schematics() {
@@ -0,0 +1,201 @@
+use bevy::reflect::serde::TypedReflectSerializer;
+use serde::ser::{Error, SerializeSeq, SerializeTuple};
+use serde::{Serialize, Serializer};
+
+use bevy_proto_backend::children::{Children, PrototypicalChild};
+use bevy_proto_backend::schematics::Schematics;
+
+use crate::proto::{ProtoChild, Prototype};
+use crate::ser::proto::{to_absolute_path, TemplatesSerializer};
+use crate::ser::PrototypeSerializer;
+
+pub(crate) const BINARY_PROTOTYPE_LEN: usize = 6;
+pub(crate) const BINARY_CHILD_LEN: usize = 2;
+pub(crate) const BINARY_SCHEMATIC_LEN: usize = 2;
+pub(crate) const BINARY_CHILD_VALUE: &str = "ProtoChildValue";
+pub(crate) const BINARY_CHILD_VALUE_PATH: &str = "Path";
+pub(crate) const BINARY_CHILD_VALUE_INLINE: &str = "Inline";
+
+/// Serializes a [`PrototypeSerializer`] using the compact binary layout.
+///
+/// Unlike the human-readable layout, every field is always written
+/// (in a fixed order) so that it can be read by non-self-describing formats:
+///
+/// 1. The prototype's name
+/// 2. Whether the prototype requires an entity
+/// 3. The absolute paths of its templates
+/// 4. Its schematics as a list of `(type name, input)` pairs
+/// 5. The type names of the schematics it removes from its templates
+/// 6. Its children as a list of `(merge key, value)` pairs
+pub(crate) struct BinaryPrototypeSerializer<'a, 'b>(pub &'a PrototypeSerializer<'b>);
+
+impl<'a, 'b> Serialize for BinaryPrototypeSerializer<'a, 'b> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let parent = self.0;
+        let prototype = parent.prototype();
+        let empty_children = Children::default();
+
+        if prototype
+            .variants
+            .as_ref()
+            .is_some_and(|variants| !variants.is_empty())
+        {
+            return Err(Error::custom(format_args!(
+                "prototypes with variants cannot be serialized in the binary format: {:?}",
+                prototype.id
+            )));
+        }
+
+        if prototype.params.is_some() || !prototype.template_params.is_empty() {
+            return Err(Error::custom(format_args!(
+                "parameterized prototypes cannot be serialized in the binary format: {:?}",
+                prototype.id
+            )));
+        }
+
+        if prototype
+            .templates
+            .as_ref()
+            .is_some_and(|templates| templates.iter().any(|(path, _)| path.id().is_some()))
+        {
+            return Err(Error::custom(format_args!(
+                "prototypes with templates referenced by ID cannot be serialized in the binary format: {:?}",
+                prototype.id
+            )));
+        }
+
+        let mut tuple = serializer.serialize_tuple(BINARY_PROTOTYPE_LEN)?;
+        tuple.serialize_element(&prototype.id)?;
+        tuple.serialize_element(&prototype.requires_entity)?;
+        match &prototype.templates {
+            Some(templates) => tuple.serialize_element(&TemplatesSerializer::new(templates))?,
+            None => tuple.serialize_element(&[] as &[String])?,
+        }
+        tuple.serialize_element(&BinarySchematicsSerializer {
+            schematics: &prototype.schematics,
+            parent,
+        })?;
+        tuple.serialize_element(&prototype.removed_schematics)?;
+        tuple.serialize_element(&BinaryChildrenSerializer {
+            children: prototype.children.as_ref().unwrap_or(&empty_children),
+            parent,
+        })?;
+        tuple.end()
+    }
+}
+
+struct BinarySchematicsSerializer<'a, 'b> {
+    schematics: &'a Schematics,
+    parent: &'a PrototypeSerializer<'b>,
+}
+
+impl<'a, 'b> Serialize for BinarySchematicsSerializer<'a, 'b> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // Sort by type name so the output is stable
+        let mut schematics = self.schematics.iter().collect::<Vec<_>>();
+        schematics.sort_by_key(|(type_name, _)| *type_name);
+
+        let mut seq = serializer.serialize_seq(Some(schematics.len()))?;
+        for (type_name, schematic) in schematics {
+            if schematic.is_patch() {
+                return Err(Error::custom(format_args!(
+                    "schematic patches cannot be serialized in the binary format: `{}`",
+                    type_name
+                )));
+            }
+
+            seq.serialize_element(&(
+                type_name.as_ref(),
+                TypedReflectSerializer::new(schematic.input(), self.parent.registry()),
+            ))?;
+        }
+        seq.end()
+    }
+}
+
+struct BinaryChildrenSerializer<'a, 'b> {
+    children: &'a Children<Prototype>,
+    parent: &'a PrototypeSerializer<'b>,
+}
+
+impl<'a, 'b> Serialize for BinaryChildrenSerializer<'a, 'b> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if self.children.forms().any(|form| !form.is_single()) {
+            return Err(Error::custom(
+                "random child forms cannot be serialized in the binary format",
+            ));
+        }
+
+        let mut seq = serializer.serialize_seq(Some(self.children.len()))?;
+        for child in self.children.iter() {
+            seq.serialize_element(&(
+                &child.merge_key,
+                BinaryChildValueSerializer {
+                    child,
+                    parent: self.parent,
+                },
+            ))?;
+        }
+        seq.end()
+    }
+}
+
+struct BinaryChildValueSerializer<'a, 'b> {
+    child: &'a ProtoChild,
+    parent: &'a PrototypeSerializer<'b>,
+}
+
+impl<'a, 'b> Serialize for BinaryChildValueSerializer<'a, 'b> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if let Some(path) = &self.child.path {
+            if let Some(id) = path.id() {
+                return Err(Error::custom(format_args!(
+                    "children referenced by ID cannot be serialized in the binary format: {:?}",
+                    id
+                )));
+            }
+
+            return serializer.serialize_newtype_variant(
+                BINARY_CHILD_VALUE,
+                0,
+                BINARY_CHILD_VALUE_PATH,
+                &to_absolute_path(path),
+            );
+        }
+
+        let prototype = self
+            .parent
+            .prototypes()
+            .and_then(|prototypes| prototypes.get(self.child.handle()))
+            .ok_or_else(|| {
+                Error::custom(format_args!(
+                    "could not find inline child prototype with handle {:?}",
+                    self.child.handle()
+                ))
+            })?;
+
+        let mut child_serializer = PrototypeSerializer::new(prototype, self.parent.registry());
+        if let Some(prototypes) = self.parent.prototypes() {
+            child_serializer = child_serializer.with_prototypes(prototypes);
+        }
+
+        serializer.serialize_newtype_variant(
+            BINARY_CHILD_VALUE,
+            1,
+            BINARY_CHILD_VALUE_INLINE,
+            &BinaryPrototypeSerializer(&child_serializer),
+        )
+    }
+}
@@ -0,0 +1,285 @@
+use bevy::reflect::TypeRegistryInternal;
+use serde::ser::{Error, SerializeSeq, SerializeStruct};
+use serde::{Serialize, Serializer};
+
+use bevy_proto_backend::children::{ChildForm, Children, PrototypicalChild};
+
+use crate::capture::CapturedPrototype;
+use crate::proto::{ProtoChild, Prototype};
+use crate::ser::proto::to_absolute_path;
+use crate::ser::PrototypeSerializer;
+
+const PROTO_CHILD: &str = "ProtoChild";
+const PROTO_CHILD_MERGE_KEY: &str = "merge_key";
+const PROTO_CHILD_VALUE: &str = "value";
+const PROTO_CHILD_VALUE_ENUM: &str = "ProtoChildValue";
+const PROTO_CHILD_VALUE_PATH: &str = "Path";
+const PROTO_CHILD_VALUE_INLINE: &str = "Inline";
+const PROTO_CHILD_VALUE_ID: &str = "Id";
+const ONE_OF: &str = "OneOf";
+const REPEAT: &str = "Repeat";
+const RANGE: &str = "Range";
+const CHANCE: &str = "Chance";
+
+/// Serializer for the [`Children`] of a [`Prototype`].
+pub struct ProtoChildrenSerializer<'a, 'b> {
+    children: &'a Children<Prototype>,
+    parent: &'a PrototypeSerializer<'b>,
+}
+
+impl<'a, 'b> ProtoChildrenSerializer<'a, 'b> {
+    pub fn new(children: &'a Children<Prototype>, parent: &'a PrototypeSerializer<'b>) -> Self {
+        Self { children, parent }
+    }
+}
+
+impl<'a, 'b> Serialize for ProtoChildrenSerializer<'a, 'b> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let forms = self.children.forms().collect::<Vec<_>>();
+        let mut seq = serializer.serialize_seq(Some(forms.len()))?;
+        for form in &forms {
+            seq.serialize_element(&ProtoChildFormSerializer::new(form, self.parent))?;
+        }
+        seq.end()
+    }
+}
+
+/// Serializer for a [`ChildForm`] of [`ProtoChild`].
+///
+/// Single children are written using [`ProtoChildSerializer`].
+/// All other forms are written as a single-field `ProtoChild` struct
+/// named after the form (e.g. `Repeat: (3, "path/to/child.prototype.ron")`).
+pub struct ProtoChildFormSerializer<'a, 'b> {
+    form: &'a ChildForm<&'a ProtoChild>,
+    parent: &'a PrototypeSerializer<'b>,
+}
+
+impl<'a, 'b> ProtoChildFormSerializer<'a, 'b> {
+    pub fn new(form: &'a ChildForm<&'a ProtoChild>, parent: &'a PrototypeSerializer<'b>) -> Self {
+        Self { form, parent }
+    }
+}
+
+impl<'a, 'b> Serialize for ProtoChildFormSerializer<'a, 'b> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let parent = self.parent;
+        let state = match self.form {
+            ChildForm::Single(child) => {
+                return ProtoChildSerializer::new(child, parent).serialize(serializer);
+            }
+            ChildForm::OneOf(forms) => {
+                let forms = forms
+                    .iter()
+                    .map(|(weight, form)| (weight, ProtoChildFormSerializer::new(form, parent)))
+                    .collect::<Vec<_>>();
+
+                let mut state = serializer.serialize_struct(PROTO_CHILD, 1)?;
+                state.serialize_field(ONE_OF, &forms)?;
+                state
+            }
+            ChildForm::Repeat(count, form) => {
+                let mut state = serializer.serialize_struct(PROTO_CHILD, 1)?;
+                state.serialize_field(
+                    REPEAT,
+                    &(count, ProtoChildFormSerializer::new(form, parent)),
+                )?;
+                state
+            }
+            ChildForm::Range(min, max, form) => {
+                let mut state = serializer.serialize_struct(PROTO_CHILD, 1)?;
+                state.serialize_field(
+                    RANGE,
+                    &(min, max, ProtoChildFormSerializer::new(form, parent)),
+                )?;
+                state
+            }
+            ChildForm::Chance(probability, form) => {
+                let mut state = serializer.serialize_struct(PROTO_CHILD, 1)?;
+                state.serialize_field(
+                    CHANCE,
+                    &(probability, ProtoChildFormSerializer::new(form, parent)),
+                )?;
+                state
+            }
+        };
+
+        state.end()
+    }
+}
+
+/// Serializer for a [`ProtoChild`].
+///
+/// Children loaded by path without a merge key are written as a plain path string.
+/// Children referenced by ID are written with an `Id` value.
+/// All others are written as a `ProtoChild` struct.
+pub struct ProtoChildSerializer<'a, 'b> {
+    child: &'a ProtoChild,
+    parent: &'a PrototypeSerializer<'b>,
+}
+
+impl<'a, 'b> ProtoChildSerializer<'a, 'b> {
+    pub fn new(child: &'a ProtoChild, parent: &'a PrototypeSerializer<'b>) -> Self {
+        Self { child, parent }
+    }
+}
+
+impl<'a, 'b> Serialize for ProtoChildSerializer<'a, 'b> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if let (Some(path), None) = (&self.child.path, &self.child.merge_key) {
+            if path.id().is_none() {
+                return serializer.serialize_str(&to_absolute_path(path));
+            }
+        }
+
+        let len = 1 + usize::from(self.child.merge_key.is_some());
+        let mut state = serializer.serialize_struct(PROTO_CHILD, len)?;
+
+        if let Some(merge_key) = &self.child.merge_key {
+            state.serialize_field(PROTO_CHILD_MERGE_KEY, merge_key)?;
+        }
+
+        state.serialize_field(
+            PROTO_CHILD_VALUE,
+            &ProtoChildValueSerializer {
+                child: self.child,
+                parent: self.parent,
+            },
+        )?;
+
+        state.end()
+    }
+}
+
+struct ProtoChildValueSerializer<'a, 'b> {
+    child: &'a ProtoChild,
+    parent: &'a PrototypeSerializer<'b>,
+}
+
+impl<'a, 'b> Serialize for ProtoChildValueSerializer<'a, 'b> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if let Some(path) = &self.child.path {
+            return match path.id() {
+                Some(id) => serializer.serialize_newtype_variant(
+                    PROTO_CHILD_VALUE_ENUM,
+                    2,
+                    PROTO_CHILD_VALUE_ID,
+                    id,
+                ),
+                None => serializer.serialize_newtype_variant(
+                    PROTO_CHILD_VALUE_ENUM,
+                    0,
+                    PROTO_CHILD_VALUE_PATH,
+                    &to_absolute_path(path),
+                ),
+            };
+        }
+
+        let prototype = self
+            .parent
+            .prototypes()
+            .and_then(|prototypes| prototypes.get(self.child.handle()))
+            .ok_or_else(|| {
+                Error::custom(format_args!(
+                    "could not find inline child prototype with handle {:?}",
+                    self.child.handle()
+                ))
+            })?;
+
+        let mut child_serializer = PrototypeSerializer::new(prototype, self.parent.registry());
+        if let Some(prototypes) = self.parent.prototypes() {
+            child_serializer = child_serializer.with_prototypes(prototypes);
+        }
+
+        serializer.serialize_newtype_variant(
+            PROTO_CHILD_VALUE_ENUM,
+            1,
+            PROTO_CHILD_VALUE_INLINE,
+            &child_serializer,
+        )
+    }
+}
+
+/// Serializer for the inline children of a [`CapturedPrototype`].
+pub(crate) struct CapturedChildrenSerializer<'a> {
+    children: &'a [CapturedPrototype],
+    registry: &'a TypeRegistryInternal,
+}
+
+impl<'a> CapturedChildrenSerializer<'a> {
+    pub fn new(children: &'a [CapturedPrototype], registry: &'a TypeRegistryInternal) -> Self {
+        Self { children, registry }
+    }
+}
+
+impl<'a> Serialize for CapturedChildrenSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.children.len()))?;
+        for child in self.children {
+            seq.serialize_element(&CapturedChildSerializer {
+                child,
+                registry: self.registry,
+            })?;
+        }
+        seq.end()
+    }
+}
+
+struct CapturedChildSerializer<'a> {
+    child: &'a CapturedPrototype,
+    registry: &'a TypeRegistryInternal,
+}
+
+impl<'a> Serialize for CapturedChildSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct(PROTO_CHILD, 1)?;
+        state.serialize_field(
+            PROTO_CHILD_VALUE,
+            &CapturedChildValueSerializer {
+                child: self.child,
+                registry: self.registry,
+            },
+        )?;
+        state.end()
+    }
+}
+
+struct CapturedChildValueSerializer<'a> {
+    child: &'a CapturedPrototype,
+    registry: &'a TypeRegistryInternal,
+}
+
+impl<'a> Serialize for CapturedChildValueSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_newtype_variant(
+            PROTO_CHILD_VALUE_ENUM,
+            1,
+            PROTO_CHILD_VALUE_INLINE,
+            &PrototypeSerializer::captured(
+                self.child.prototype(),
+                self.child.children(),
+                self.registry,
+            ),
+        )
+    }
+}
@@ -0,0 +1,7 @@
+pub use child::*;
+pub use proto::*;
+
+#[cfg(feature = "bincode")]
+pub(crate) mod binary;
+mod child;
+mod proto;
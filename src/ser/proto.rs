@@ -0,0 +1,332 @@
+use std::path::Component;
+
+use bevy::asset::Assets;
+use bevy::reflect::TypeRegistryInternal;
+use bevy::utils::HashMap;
+use serde::ser::{Error, SerializeSeq, SerializeStruct};
+use serde::{Serialize, Serializer};
+
+use bevy_proto_backend::path::ProtoPath;
+use bevy_proto_backend::proto::ProtoParams;
+use bevy_proto_backend::templates::Templates;
+
+use crate::capture::CapturedPrototype;
+use crate::proto::{Prototype, PrototypeError};
+use crate::schematics::{SchematicsSerializer, VariantsSerializer};
+use crate::ser::{CapturedChildrenSerializer, ProtoChildrenSerializer};
+
+const NAME: &str = "name";
+const PARAMS: &str = "params";
+const TEMPLATES: &str = "templates";
+const SCHEMATICS: &str = "schematics";
+const PATCHES: &str = "patches";
+const REMOVE_SCHEMATICS: &str = "remove_schematics";
+const VARIANTS: &str = "variants";
+const CHILDREN: &str = "children";
+const ENTITY: &str = "entity";
+const PROTO_TEMPLATE: &str = "ProtoTemplate";
+const PROTO_TEMPLATE_PATH: &str = "path";
+const PROTO_TEMPLATE_ID: &str = "Id";
+const PROTO_TEMPLATE_PARAMS: &str = "params";
+
+/// Serializer for a [`Prototype`].
+///
+/// The output mirrors the format expected by [`PrototypeDeserializer`],
+/// allowing a prototype to be written back out as a `.prototype.*` file.
+///
+/// Templates and children loaded by path are written as absolute asset paths.
+/// Inline children can only be serialized if the [`Assets`] they are stored in
+/// are provided using [`with_prototypes`].
+///
+/// Prototypes loaded with [parameters] cannot be serialized,
+/// since their schematics only contain the substituted default values
+/// rather than the parameter references (such as `"$speed"`) themselves.
+/// Parameters declared using [`PrototypeBuilder::with_params`] are written as-is.
+///
+/// [parameters]: bevy_proto_backend::proto::Prototypical::params
+/// [`PrototypeBuilder::with_params`]: crate::proto::PrototypeBuilder::with_params
+/// [`PrototypeDeserializer`]: crate::de::PrototypeDeserializer
+/// [`with_prototypes`]: Self::with_prototypes
+pub struct PrototypeSerializer<'a> {
+    prototype: &'a Prototype,
+    registry: &'a TypeRegistryInternal,
+    prototypes: Option<&'a Assets<Prototype>>,
+    captured: &'a [CapturedPrototype],
+}
+
+impl<'a> PrototypeSerializer<'a> {
+    pub fn new(prototype: &'a Prototype, registry: &'a TypeRegistryInternal) -> Self {
+        Self {
+            prototype,
+            registry,
+            prototypes: None,
+            captured: &[],
+        }
+    }
+
+    /// Create a serializer for a [captured] prototype and its inline children.
+    ///
+    /// [captured]: crate::capture::capture_prototype
+    pub(crate) fn captured(
+        prototype: &'a Prototype,
+        children: &'a [CapturedPrototype],
+        registry: &'a TypeRegistryInternal,
+    ) -> Self {
+        Self {
+            prototype,
+            registry,
+            prototypes: None,
+            captured: children,
+        }
+    }
+
+    /// Provide the prototype [`Assets`] used to look up inline children.
+    pub fn with_prototypes(mut self, prototypes: &'a Assets<Prototype>) -> Self {
+        self.prototypes = Some(prototypes);
+        self
+    }
+
+    /// Serialize the prototype as a pretty-printed [RON] string.
+    ///
+    /// [RON]: https://github.com/ron-rs/ron
+    #[cfg(feature = "ron")]
+    pub fn to_ron(&self) -> Result<String, PrototypeError> {
+        Ok(ron::ser::to_string_pretty(
+            self,
+            ron::ser::PrettyConfig::default(),
+        )?)
+    }
+
+    /// Serialize the prototype as a [YAML] string.
+    ///
+    /// [YAML]: https://github.com/dtolnay/serde-yaml
+    #[cfg(feature = "yaml")]
+    pub fn to_yaml(&self) -> Result<String, PrototypeError> {
+        Ok(serde_yaml::to_string(self)?)
+    }
+
+    /// Serialize the prototype as a pretty-printed [JSON] string.
+    ///
+    /// [JSON]: https://github.com/serde-rs/json
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> Result<String, PrototypeError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Serialize the prototype into a compact binary format.
+    ///
+    /// The output can be loaded from a `.prototype.bin` file,
+    /// allowing prototypes to be pre-baked for release builds.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the prototype (or any of its inline children) uses a feature
+    /// the binary format does not support: parameters, variants, schematic patches,
+    /// random children, or templates and children referenced by ID.
+    #[cfg(feature = "bincode")]
+    pub fn to_binary(&self) -> Result<Vec<u8>, PrototypeError> {
+        use bincode::Options;
+
+        Ok(bincode::DefaultOptions::new()
+            .serialize(&crate::ser::binary::BinaryPrototypeSerializer(self))?)
+    }
+
+    pub(crate) fn prototype(&self) -> &'a Prototype {
+        self.prototype
+    }
+
+    pub(crate) fn registry(&self) -> &'a TypeRegistryInternal {
+        self.registry
+    }
+
+    pub(crate) fn prototypes(&self) -> Option<&'a Assets<Prototype>> {
+        self.prototypes
+    }
+}
+
+impl<'a> Serialize for PrototypeSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let prototype = self.prototype;
+
+        if prototype.source.is_some() {
+            return Err(Error::custom(format_args!(
+                "prototypes loaded with parameters cannot be serialized: {:?}",
+                prototype.id
+            )));
+        }
+
+        let templates = prototype
+            .templates
+            .as_ref()
+            .filter(|templates| !templates.is_empty());
+        let children = prototype
+            .children
+            .as_ref()
+            .filter(|children| !children.is_empty());
+        let has_captured = children.is_none() && !self.captured.is_empty();
+        let patch_count = prototype
+            .schematics
+            .iter()
+            .filter(|(_, schematic)| schematic.is_patch())
+            .count();
+        let has_schematics = prototype.schematics.len() > patch_count;
+        let has_patches = patch_count > 0;
+        let has_removals = !prototype.removed_schematics.is_empty();
+        let variants = prototype
+            .variants
+            .as_ref()
+            .filter(|variants| !variants.is_empty());
+
+        let len = 1
+            + usize::from(prototype.params.is_some())
+            + usize::from(templates.is_some())
+            + usize::from(has_schematics)
+            + usize::from(has_patches)
+            + usize::from(has_removals)
+            + usize::from(variants.is_some())
+            + usize::from(children.is_some() || has_captured)
+            + usize::from(!prototype.requires_entity);
+
+        let mut state = serializer.serialize_struct(std::any::type_name::<Prototype>(), len)?;
+
+        state.serialize_field(NAME, &prototype.id)?;
+
+        if let Some(params) = &prototype.params {
+            state.serialize_field(PARAMS, params)?;
+        }
+
+        if let Some(templates) = templates {
+            state.serialize_field(
+                TEMPLATES,
+                &TemplatesSerializer::new(templates).with_params(&prototype.template_params),
+            )?;
+        }
+
+        if has_schematics {
+            state.serialize_field(
+                SCHEMATICS,
+                &SchematicsSerializer::new(&prototype.schematics, self.registry),
+            )?;
+        }
+
+        if has_patches {
+            state.serialize_field(
+                PATCHES,
+                &SchematicsSerializer::patches(&prototype.schematics, self.registry),
+            )?;
+        }
+
+        if has_removals {
+            state.serialize_field(REMOVE_SCHEMATICS, &prototype.removed_schematics)?;
+        }
+
+        if let Some(variants) = variants {
+            state.serialize_field(VARIANTS, &VariantsSerializer::new(variants, self.registry))?;
+        }
+
+        if let Some(children) = children {
+            state.serialize_field(CHILDREN, &ProtoChildrenSerializer::new(children, self))?;
+        } else if has_captured {
+            state.serialize_field(
+                CHILDREN,
+                &CapturedChildrenSerializer::new(self.captured, self.registry),
+            )?;
+        }
+
+        if !prototype.requires_entity {
+            state.serialize_field(ENTITY, &false)?;
+        }
+
+        state.end()
+    }
+}
+
+pub(crate) struct TemplatesSerializer<'a> {
+    templates: &'a Templates,
+    params: Option<&'a HashMap<ProtoPath, ProtoParams>>,
+}
+
+impl<'a> TemplatesSerializer<'a> {
+    pub fn new(templates: &'a Templates) -> Self {
+        Self {
+            templates,
+            params: None,
+        }
+    }
+
+    /// Include the [parameters] passed down to each template.
+    ///
+    /// [parameters]: ProtoParams
+    pub fn with_params(mut self, params: &'a HashMap<ProtoPath, ProtoParams>) -> Self {
+        self.params = Some(params);
+        self
+    }
+}
+
+impl<'a> Serialize for TemplatesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.templates.len()))?;
+        for (path, _) in self.templates.iter() {
+            let params = self.params.and_then(|params| params.get(path));
+            if params.is_none() && path.id().is_none() {
+                seq.serialize_element(&to_absolute_path(path))?;
+            } else {
+                seq.serialize_element(&TemplateSerializer { path, params })?;
+            }
+        }
+        seq.end()
+    }
+}
+
+struct TemplateSerializer<'a> {
+    path: &'a ProtoPath,
+    params: Option<&'a ProtoParams>,
+}
+
+impl<'a> Serialize for TemplateSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let len = 1 + usize::from(self.params.is_some());
+        let mut state = serializer.serialize_struct(PROTO_TEMPLATE, len)?;
+        match self.path.id() {
+            Some(id) => state.serialize_field(PROTO_TEMPLATE_ID, id)?,
+            None => state.serialize_field(PROTO_TEMPLATE_PATH, &to_absolute_path(self.path))?,
+        }
+        if let Some(params) = self.params {
+            state.serialize_field(PROTO_TEMPLATE_PARAMS, params)?;
+        }
+        state.end()
+    }
+}
+
+/// Converts the given [`ProtoPath`] into an absolute asset path string.
+///
+/// Asset paths always use `/` as their separator, regardless of platform.
+pub(crate) fn to_absolute_path(path: &ProtoPath) -> String {
+    let mut absolute = String::new();
+    for component in path.path().components() {
+        match component {
+            Component::Normal(name) => {
+                absolute.push('/');
+                absolute.push_str(&name.to_string_lossy());
+            }
+            Component::ParentDir => absolute.push_str("/.."),
+            Component::Prefix(_) | Component::RootDir | Component::CurDir => {}
+        }
+    }
+
+    if let Some(label) = path.label() {
+        absolute.push('#');
+        absolute.push_str(label);
+    }
+
+    absolute
+}
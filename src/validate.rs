@@ -0,0 +1,490 @@
+//! Items for validating prototype files without running the game.
+//!
+//! This is useful for catching broken content in CI before it ever reaches playtesters:
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_proto::validate::ProtoValidator;
+//!
+//! #[derive(Component, Reflect)]
+//! struct Health(u32);
+//!
+//! let report = ProtoValidator::new("assets", "prototypes")
+//!   .with_setup(|app| {
+//!     app.register_type::<Health>();
+//!   })
+//!   .validate();
+//!
+//! report.assert_valid();
+//! ```
+//!
+//! Projects that only rely on the schematics provided by Bevy and this crate
+//! can also use the `validate_prototypes` binary:
+//!
+//! ```text
+//! cargo run --bin validate_prototypes -- assets prototypes
+//! ```
+
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use bevy::app::App;
+use bevy::asset::{AssetPlugin, AssetServer, Assets, HandleId, LoadState};
+use bevy::ecs::system::SystemState;
+use bevy::prelude::{FromWorld, MinimalPlugins, World};
+use bevy::utils::{HashMap, HashSet};
+use thiserror::Error;
+
+use bevy_proto_backend::children::PrototypicalChild;
+use bevy_proto_backend::cycles::CycleResponse;
+use bevy_proto_backend::load::{Loader, ProtoLoadContext, ProtoLoadMeta};
+use bevy_proto_backend::path::ProtoPathContext;
+use bevy_proto_backend::proto::{qualify_id, split_namespace, Config, Prototypical};
+
+use crate::config::ProtoConfig;
+use crate::loader::{deserialize_prototype, deserialize_prototype_bundle, ProtoLoader};
+use crate::plugin::ProtoPlugin;
+use crate::prelude::Prototypes;
+use crate::proto::{Prototype, PrototypeError};
+
+/// A single problem found while [validating] prototypes.
+///
+/// [validating]: ProtoValidator
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ValidationIssue {
+    /// The prototype file could not be loaded or deserialized.
+    #[error("could not load {path:?}: {error}")]
+    LoadFailed { path: PathBuf, error: String },
+    /// The prototype inherits from a template that could not be found.
+    #[error("{path:?} references missing template {template:?}")]
+    MissingTemplate { path: PathBuf, template: PathBuf },
+    /// The prototype contains a child that could not be found.
+    #[error("{path:?} references missing child {child:?}")]
+    MissingChild { path: PathBuf, child: PathBuf },
+    /// The prototype references a template or child by an ID that no prototype has.
+    #[error("{path:?} references missing prototype ID {id:?}")]
+    MissingReference { path: PathBuf, id: String },
+    /// The prototype depends on an asset that does not exist.
+    #[error("{path:?} references missing asset {asset:?}")]
+    MissingAsset { path: PathBuf, asset: PathBuf },
+    /// Multiple prototypes share the same ID.
+    #[error("prototype ID {id:?} is used by multiple prototypes: {paths:?}")]
+    DuplicateId { id: String, paths: Vec<PathBuf> },
+    /// A prototype cycle was found.
+    #[error("found prototype cycle: `{cycle}`")]
+    Cycle {
+        cycle: String,
+        /// The IDs of every prototype involved in the cycle,
+        /// including those that led to it.
+        ids: Vec<String>,
+    },
+    /// The prototype loaded but could not be registered for any other reason.
+    #[error("prototype {id:?} ({path:?}) could not be registered")]
+    NotRegistered { id: String, path: PathBuf },
+}
+
+/// The result of [validating] a folder of prototypes.
+///
+/// [validating]: ProtoValidator
+#[derive(Debug, Default, Clone)]
+pub struct ValidationReport {
+    /// The number of prototypes that were successfully registered.
+    pub registered: usize,
+    /// Every issue that was found.
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    /// Returns true if no issues were found.
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Panics with the full report if any issues were found.
+    ///
+    /// This is meant to be used within tests.
+    pub fn assert_valid(&self) {
+        assert!(self.is_valid(), "{}", self);
+    }
+}
+
+impl Display for ValidationReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "registered {} prototype(s) with {} issue(s)",
+            self.registered,
+            self.issues.len()
+        )?;
+        for issue in &self.issues {
+            writeln!(f, "  - {issue}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Validates a folder of prototypes using a minimal headless [`App`].
+///
+/// The app is set up with [`MinimalPlugins`], [`AssetPlugin`], and [`ProtoPlugin`].
+/// Any additional type registrations or plugins the prototypes rely on should be
+/// added using [`with_setup`].
+///
+/// The [`ProtoLoader`] and [`ProtoConfig`] should match the ones used by the game,
+/// so that namespaces and override policies are taken into account.
+/// These can be given using [`with_loader`] and [`with_config`].
+///
+/// [`with_setup`]: Self::with_setup
+/// [`with_loader`]: Self::with_loader
+/// [`with_config`]: Self::with_config
+pub struct ProtoValidator {
+    asset_folder: String,
+    prototypes_folder: PathBuf,
+    timeout: Duration,
+    loader: ProtoLoader,
+    config: ProtoConfig,
+    setup: Vec<SetupFn>,
+}
+
+/// A function run on the validation app before any prototypes are loaded.
+type SetupFn = Box<dyn FnOnce(&mut App)>;
+
+impl ProtoValidator {
+    /// Create a new validator for the prototypes in the given folder.
+    ///
+    /// The `prototypes_folder` is relative to the `asset_folder`,
+    /// which itself is treated the same as [`AssetPlugin::asset_folder`].
+    pub fn new(asset_folder: impl Into<String>, prototypes_folder: impl Into<PathBuf>) -> Self {
+        Self {
+            asset_folder: asset_folder.into(),
+            prototypes_folder: prototypes_folder.into(),
+            timeout: Duration::from_secs(30),
+            loader: ProtoLoader::default(),
+            config: ProtoConfig::default(),
+            setup: Vec::new(),
+        }
+    }
+
+    /// Set the [`ProtoLoader`] used to load the prototypes.
+    ///
+    /// Defaults to [`ProtoLoader::default`].
+    pub fn with_loader(mut self, loader: ProtoLoader) -> Self {
+        self.loader = loader;
+        self
+    }
+
+    /// Set the [`ProtoConfig`] used to register the prototypes.
+    ///
+    /// Note that its [cycle callback] is replaced in order to report cycles.
+    ///
+    /// Defaults to [`ProtoConfig::default`].
+    ///
+    /// [cycle callback]: ProtoConfig::on_cycle
+    pub fn with_config(mut self, config: ProtoConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Add a function used to set up the [`App`] before any prototypes are loaded.
+    ///
+    /// This should be used to register any custom schematics.
+    pub fn with_setup(mut self, setup: impl FnOnce(&mut App) + 'static) -> Self {
+        self.setup.push(Box::new(setup));
+        self
+    }
+
+    /// Set the maximum amount of time to wait for prototypes to load.
+    ///
+    /// Defaults to 30 seconds.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Load all prototypes and report any issues.
+    pub fn validate(self) -> ValidationReport {
+        let issues = Arc::new(Mutex::new(Vec::new()));
+
+        let cycle_issues = issues.clone();
+        let config = self.config.on_cycle(Box::new(move |cycle| {
+            let issue = ValidationIssue::Cycle {
+                cycle: cycle.to_string(),
+                ids: cycle.iter_full().map(ToString::to_string).collect(),
+            };
+            let mut issues = cycle_issues.lock().unwrap();
+            if !issues.contains(&issue) {
+                issues.push(issue);
+            }
+            CycleResponse::Cancel
+        }));
+        let loader = ValidationLoader {
+            inner: self.loader,
+            issues: issues.clone(),
+        };
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(AssetPlugin {
+                asset_folder: self.asset_folder,
+                watch_for_changes: None,
+            })
+            .add_plugins(ProtoPlugin::new_with_loader_and_config(loader, config));
+
+        for setup in self.setup {
+            setup(&mut app);
+        }
+
+        let handles = match app
+            .world
+            .resource::<AssetServer>()
+            .load_folder(&self.prototypes_folder)
+        {
+            Ok(handles) => handles,
+            Err(err) => {
+                return ValidationReport {
+                    registered: 0,
+                    issues: vec![ValidationIssue::LoadFailed {
+                        path: self.prototypes_folder,
+                        error: err.to_string(),
+                    }],
+                }
+            }
+        };
+
+        let roots = handles.iter().map(|handle| handle.id()).collect::<Vec<_>>();
+        wait_for_load(&mut app, &roots, self.timeout);
+
+        let mut issues = std::mem::take(&mut *issues.lock().unwrap());
+        let registered = collect_issues(&mut app.world, &mut issues);
+
+        ValidationReport { registered, issues }
+    }
+}
+
+/// Update the app until all prototypes (and their templates and children) are done loading.
+fn wait_for_load(app: &mut App, roots: &[HandleId], timeout: Duration) {
+    let start = Instant::now();
+    loop {
+        app.update();
+
+        let mut handles = roots.to_vec();
+        for (_, prototype) in app.world.resource::<Assets<Prototype>>().iter() {
+            // References by ID are never loaded directly
+            if let Some(templates) = prototype.templates() {
+                handles.extend(
+                    templates
+                        .iter()
+                        .filter(|(path, _)| path.id().is_none())
+                        .map(|(_, handle)| handle.id()),
+                );
+            }
+            if let Some(children) = prototype.children() {
+                handles.extend(
+                    children
+                        .iter()
+                        .filter(|child| child.path().and_then(|path| path.id()).is_none())
+                        .map(|child| child.handle().id()),
+                );
+            }
+        }
+
+        let state = app
+            .world
+            .resource::<AssetServer>()
+            .get_group_load_state(handles);
+        if state != LoadState::Loading || start.elapsed() > timeout {
+            break;
+        }
+
+        std::thread::sleep(Duration::from_millis(1));
+    }
+
+    // Run a few more updates to allow all loaded prototypes to be registered
+    for _ in 0..3 {
+        app.update();
+    }
+}
+
+/// Inspect all loaded prototypes, adding any issues found.
+///
+/// Returns the number of registered prototypes.
+fn collect_issues(world: &mut World, issues: &mut Vec<ValidationIssue>) -> usize {
+    let mut state = SystemState::<Prototypes>::new(world);
+    let registry = state.get(world);
+    let prototypes = world.resource::<Assets<Prototype>>();
+    let asset_io = world.resource::<AssetServer>().asset_io();
+    let config = world.resource::<ProtoConfig>();
+
+    let mut registered = 0;
+    let mut ids = HashMap::<&String, Vec<PathBuf>>::new();
+    // IDs shared by a prototype without an override policy
+    let mut conflicting = HashSet::<&String>::new();
+    let mut references = Vec::<(PathBuf, Option<&str>, &str)>::new();
+    for (handle_id, prototype) in prototypes.iter() {
+        let path = prototype.path().path().to_path_buf();
+        ids.entry(prototype.id()).or_default().push(path.clone());
+        if config.override_policy(prototype).is_none() {
+            conflicting.insert(prototype.id());
+        }
+        let (namespace, _) = split_namespace(prototype.id());
+
+        if let Some(templates) = prototype.templates() {
+            for (template, handle) in templates.iter() {
+                if let Some(id) = template.id() {
+                    references.push((path.clone(), namespace, id));
+                } else if prototypes.get(&handle.typed_weak()).is_none() {
+                    issues.push(ValidationIssue::MissingTemplate {
+                        path: path.clone(),
+                        template: template.path().to_path_buf(),
+                    });
+                }
+            }
+        }
+
+        if let Some(children) = prototype.children() {
+            for child in children.iter() {
+                if let Some(id) = child.path().and_then(|path| path.id()) {
+                    references.push((path.clone(), namespace, id));
+                } else if prototypes.get(child.handle()).is_none() {
+                    issues.push(ValidationIssue::MissingChild {
+                        path: path.clone(),
+                        child: child
+                            .path
+                            .as_ref()
+                            .map(|child| child.path().to_path_buf())
+                            .unwrap_or_default(),
+                    });
+                }
+            }
+        }
+
+        for (asset_path, _) in prototype.dependencies().iter() {
+            if !asset_io.is_file(asset_path.path()) {
+                issues.push(ValidationIssue::MissingAsset {
+                    path: path.clone(),
+                    asset: asset_path.path().to_path_buf(),
+                });
+            }
+        }
+
+        if registry.is_ready_handle(handle_id) {
+            registered += 1;
+        }
+    }
+
+    for (path, namespace, id) in references {
+        let qualified = namespace.and_then(|namespace| qualify_id(id, namespace));
+        let exists = qualified.is_some_and(|qualified| ids.contains_key(&qualified))
+            || ids.contains_key(&id.to_string());
+        if !exists {
+            issues.push(ValidationIssue::MissingReference {
+                path,
+                id: id.to_string(),
+            });
+        }
+    }
+
+    let mut duplicates = ids
+        .iter()
+        .filter(|(id, paths)| paths.len() > 1 && conflicting.contains(*id))
+        .map(|(id, paths)| {
+            let mut paths = paths.clone();
+            paths.sort();
+            ValidationIssue::DuplicateId {
+                id: id.to_string(),
+                paths,
+            }
+        })
+        .collect::<Vec<_>>();
+    duplicates.sort_by_key(ToString::to_string);
+
+    // Any prototypes that failed to register without a known reason
+    for (handle_id, prototype) in prototypes.iter() {
+        let path = prototype.path().path().to_path_buf();
+        let is_explained = ids[prototype.id()].len() > 1
+            || issues.iter().any(|issue| match issue {
+                ValidationIssue::MissingTemplate { path: other, .. }
+                | ValidationIssue::MissingChild { path: other, .. }
+                | ValidationIssue::MissingReference { path: other, .. } => other == &path,
+                ValidationIssue::Cycle { ids, .. } => ids.contains(prototype.id()),
+                _ => false,
+            });
+
+        if !registry.is_ready_handle(handle_id) && !is_explained {
+            issues.push(ValidationIssue::NotRegistered {
+                id: prototype.id().clone(),
+                path,
+            });
+        }
+    }
+
+    issues.extend(duplicates);
+    registered
+}
+
+/// A [`Loader`] that wraps [`ProtoLoader`] in order to record load errors.
+#[derive(Clone)]
+struct ValidationLoader {
+    inner: ProtoLoader,
+    issues: Arc<Mutex<Vec<ValidationIssue>>>,
+}
+
+impl FromWorld for ValidationLoader {
+    fn from_world(world: &mut World) -> Self {
+        Self {
+            inner: ProtoLoader::from_world(world),
+            issues: Arc::default(),
+        }
+    }
+}
+
+impl Loader<Prototype> for ValidationLoader {
+    type Error = PrototypeError;
+
+    fn deserialize(
+        bytes: &[u8],
+        ctx: &mut ProtoLoadContext<Prototype, Self>,
+    ) -> Result<Prototype, Self::Error> {
+        deserialize_prototype(bytes, ctx).map_err(|err| record_load_error(ctx, err))
+    }
+
+    fn extensions(&self) -> &[&'static str] {
+        self.inner.extensions()
+    }
+
+    fn bundle_extensions(&self) -> &[&'static str] {
+        self.inner.bundle_extensions()
+    }
+
+    fn deserialize_bundle(
+        bytes: &[u8],
+        ctx: &mut ProtoLoadContext<Prototype, Self>,
+    ) -> Result<Vec<(String, Prototype)>, Self::Error> {
+        deserialize_prototype_bundle(bytes, ctx).map_err(|err| record_load_error(ctx, err))
+    }
+
+    fn on_load_prototype(
+        &self,
+        prototype: Prototype,
+        meta: &ProtoLoadMeta<Prototype>,
+    ) -> Result<Prototype, Self::Error> {
+        self.inner.on_load_prototype(prototype, meta)
+    }
+}
+
+/// Record the given load error as a [`ValidationIssue::LoadFailed`].
+fn record_load_error(
+    ctx: &ProtoLoadContext<Prototype, ValidationLoader>,
+    err: PrototypeError,
+) -> PrototypeError {
+    ctx.loader()
+        .issues
+        .lock()
+        .unwrap()
+        .push(ValidationIssue::LoadFailed {
+            path: ctx.base_path().to_path_buf(),
+            error: err.to_string(),
+        });
+    err
+}
@@ -0,0 +1,151 @@
+//! Tests for spawning prototypes in batches.
+
+use bevy::prelude::*;
+
+use bevy_proto::backend::proto::{ProtoInstance, ProtoParams};
+use bevy_proto::backend::schematics::{SchematicContext, SchematicId, Schematics};
+use bevy_proto::prelude::*;
+
+use common::*;
+
+mod common;
+
+/// Records whether the entity already contained [`Speed`] when this schematic was applied.
+///
+/// Since this schematic isn't compiled, it's applied to each instance individually.
+#[derive(Component, Reflect, Debug, Default, PartialEq)]
+#[reflect(Schematic)]
+struct SawSpeed(bool);
+
+impl Schematic for SawSpeed {
+    type Input = Self;
+
+    fn apply(_: &Self::Input, _: SchematicId, context: &mut SchematicContext) {
+        let mut entity = context.entity_mut().unwrap();
+        let saw_speed = entity.contains::<Speed>();
+        entity.insert(Self(saw_speed));
+    }
+
+    fn remove(_: &Self::Input, _: SchematicId, context: &mut SchematicContext) {
+        context.entity_mut().unwrap().remove::<Self>();
+    }
+}
+
+fn builder() -> PrototypeBuilder {
+    PrototypeBuilder::new("Root")
+        .with_schematic::<Health>(Health(5))
+        .with_child(PrototypeBuilder::new("Left").with_schematic::<Speed>(Speed(1)))
+        .with_child(PrototypeBuilder::new("Right").with_schematic::<Speed>(Speed(2)))
+}
+
+/// Returns all entities tracked as an instance of the given prototype.
+fn instances(app: &mut App, handle: &Handle<Prototype>) -> Vec<Entity> {
+    let mut query = app.world.query::<(Entity, &ProtoInstance)>();
+    query
+        .iter(&app.world)
+        .filter(|(_, instance)| instance.handle() == handle.id())
+        .map(|(entity, _)| entity)
+        .collect()
+}
+
+#[test]
+fn should_spawn_batch() {
+    let mut app = app();
+    let handle = build(&mut app, builder());
+
+    with_commands(&mut app, |commands| commands.spawn_batch("Root", 4));
+
+    let roots = instances(&mut app, &handle);
+    assert_eq!(4, roots.len());
+
+    for root in roots {
+        assert_eq!(Some(&Health(5)), app.world.get::<Health>(root));
+        assert!(app.world.get::<Speed>(root).is_none());
+
+        let children = app.world.get::<Children>(root).unwrap();
+        let speeds = children
+            .iter()
+            .map(|child| {
+                assert_eq!(Some(root), app.world.get::<Parent>(*child).map(Parent::get));
+                assert!(app.world.get::<Health>(*child).is_none());
+                app.world.get::<Speed>(*child).cloned()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![Some(Speed(1)), Some(Speed(2))], speeds);
+    }
+}
+
+#[test]
+fn should_spawn_batch_with_overrides() {
+    let mut app = app();
+    let handle = build(&mut app, builder());
+
+    let mut overrides = Schematics::default();
+    overrides.insert::<Health>(Health(9));
+
+    with_commands(&mut app, |commands| {
+        commands.spawn_batch_with_overrides("Root", [overrides, Schematics::default()])
+    });
+
+    let mut health = instances(&mut app, &handle)
+        .into_iter()
+        .map(|root| app.world.get::<Health>(root).unwrap().0)
+        .collect::<Vec<_>>();
+    health.sort();
+
+    assert_eq!(vec![5, 9], health);
+}
+
+#[test]
+fn should_spawn_batch_with_params() {
+    let health = std::any::type_name::<Health>();
+    let folder = asset_folder("batch_params");
+    std::fs::write(
+        folder.join("Base.prototype.ron"),
+        format!(
+            r#"(
+                name: "Base",
+                params: {{ "hp": 5 }},
+                schematics: {{ "{health}": ("$hp") }},
+            )"#
+        ),
+    )
+    .unwrap();
+
+    let mut app = app_in(&folder);
+    let handle = load(&mut app, "Base.prototype.ron", "Base");
+
+    with_commands(&mut app, |commands| {
+        commands.spawn_batch_with("Base", 2, ProtoParams::new().with("hp", 7))
+    });
+
+    let roots = instances(&mut app, &handle);
+    assert_eq!(2, roots.len());
+    for root in roots {
+        assert_eq!(Some(&Health(7)), app.world.get::<Health>(root));
+    }
+}
+
+#[test]
+fn should_keep_schematic_order_in_batch() {
+    let mut app = app();
+    app.register_type::<SawSpeed>();
+    let handle = build(
+        &mut app,
+        PrototypeBuilder::new("Ordered")
+            .with_schematic::<SawSpeed>(SawSpeed(false))
+            .with_schematic::<Speed>(Speed(1)),
+    );
+
+    // Schematics aren't ordered, so compare against the order used when spawning normally
+    let single = with_commands(&mut app, |commands| commands.spawn("Ordered").id());
+    let expected = app.world.get::<SawSpeed>(single).unwrap().0;
+
+    with_commands(&mut app, |commands| commands.spawn_batch("Ordered", 2));
+
+    for root in instances(&mut app, &handle) {
+        assert_eq!(Some(&SawSpeed(expected)), app.world.get::<SawSpeed>(root));
+        assert_eq!(Some(&Speed(1)), app.world.get::<Speed>(root));
+    }
+}
@@ -0,0 +1,117 @@
+use std::path::Path;
+
+use bevy::prelude::*;
+
+use bevy_proto::prelude::*;
+
+use common::*;
+
+mod common;
+
+/// Write the prototype files shared by these tests to the given folder.
+fn write_files(folder: &Path) {
+    let health = std::any::type_name::<Health>();
+    let speed = std::any::type_name::<Speed>();
+
+    std::fs::write(
+        folder.join("Base.prototype.ron"),
+        format!(r#"(name: "Base", schematics: {{ "{health}": (5) }})"#),
+    )
+    .unwrap();
+    std::fs::write(
+        folder.join("Child.prototype.ron"),
+        format!(r#"(name: "Child", schematics: {{ "{speed}": (1) }})"#),
+    )
+    .unwrap();
+    std::fs::write(
+        folder.join("Loaded.prototype.ron"),
+        format!(
+            r#"(
+                name: "Loaded",
+                templates: ["Base.prototype.ron"],
+                schematics: {{ "{speed}": (2) }},
+                children: ["Child.prototype.ron"],
+            )"#
+        ),
+    )
+    .unwrap();
+}
+
+fn builder(id: &str) -> PrototypeBuilder {
+    PrototypeBuilder::new(id)
+        .with_template_path("Base.prototype.ron")
+        .with_schematic::<Speed>(Speed(2))
+        .with_child_path("Child.prototype.ron")
+}
+
+#[test]
+fn should_spawn_like_loaded_prototype() {
+    let folder = asset_folder("builder_spawn");
+    write_files(&folder);
+    let mut app = app_in(&folder);
+
+    let _loaded = load(&mut app, "Loaded.prototype.ron", "Loaded");
+    let _built = build(&mut app, builder("Built"));
+    wait_for(&mut app, "Built");
+
+    let loaded = with_commands(&mut app, |commands| commands.spawn("Loaded").id());
+    let built = with_commands(&mut app, |commands| commands.spawn("Built").id());
+
+    for entity in [loaded, built] {
+        assert_eq!(Some(&Health(5)), app.world.get::<Health>(entity));
+        assert_eq!(Some(&Speed(2)), app.world.get::<Speed>(entity));
+
+        let children = app.world.get::<Children>(entity).unwrap();
+        assert_eq!(1, children.len());
+        assert_eq!(Some(&Speed(1)), app.world.get::<Speed>(children[0]));
+    }
+}
+
+#[test]
+fn should_reload_when_template_changes() {
+    let folder = asset_folder("builder_reload");
+    write_files(&folder);
+    let mut app = app_in(&folder);
+
+    let _built = build(&mut app, builder("Built"));
+    wait_for(&mut app, "Built");
+
+    let base = app
+        .world
+        .resource::<AssetServer>()
+        .load::<Prototype, _>("Base.prototype.ron");
+    app.world
+        .resource_mut::<Assets<Prototype>>()
+        .get_mut(&base)
+        .unwrap()
+        .schematics_mut()
+        .insert::<Health>(Health(9));
+    settle(&mut app);
+
+    let entity = with_commands(&mut app, |commands| commands.spawn("Built").id());
+    assert_eq!(Some(&Health(9)), app.world.get::<Health>(entity));
+}
+
+#[test]
+fn should_resolve_template_id_through_registry() {
+    let mut app = app();
+
+    // Templates given by ID are resolved once they're registered
+    let _built = build(
+        &mut app,
+        PrototypeBuilder::new("Built")
+            .with_template("Base")
+            .with_schematic::<Speed>(Speed(2)),
+    );
+    assert!(!is_ready(&mut app, "Built"));
+
+    let _base = build(
+        &mut app,
+        PrototypeBuilder::new("Base").with_schematic::<Health>(Health(5)),
+    );
+    assert!(is_ready(&mut app, "Built"));
+
+    let entity = with_commands(&mut app, |commands| commands.spawn("Built").id());
+    assert_eq!(Some(&Health(5)), app.world.get::<Health>(entity));
+    assert_eq!(Some(&Speed(2)), app.world.get::<Speed>(entity));
+}
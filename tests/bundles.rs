@@ -0,0 +1,94 @@
+//! Tests for loading multiple prototypes from a single bundle file.
+
+use bevy::ecs::system::SystemState;
+use bevy::prelude::*;
+
+use bevy_proto::prelude::*;
+
+use common::*;
+
+mod common;
+
+/// Write the given bundle to a new asset folder and create an app that loads from it.
+fn app_with_bundle(name: &str, file: &str, contents: String) -> App {
+    let folder = asset_folder(name);
+    std::fs::write(folder.join(file), contents).unwrap();
+    app_in(&folder)
+}
+
+/// Load the bundle entry at the given path and wait for it to be registered with the given ID.
+fn load_entry(app: &mut App, path: &str, id: &str) -> Handle<Prototype> {
+    let mut state = SystemState::<PrototypesMut>::new(&mut app.world);
+    let handle = state.get_mut(&mut app.world).load(path);
+    state.apply(&mut app.world);
+    wait_for(app, id);
+    handle
+}
+
+#[test]
+fn should_load_bundle_map() {
+    let health = std::any::type_name::<Health>();
+    let mut app = app_with_bundle(
+        "bundles_map",
+        "orcs.prototypes.ron",
+        format!(
+            r##"{{
+                "Grunt": (name: "Grunt", templates: ["#Orc"]),
+                "Orc": (name: "Orc", schematics: {{ "{health}": (10) }}),
+            }}"##
+        ),
+    );
+    let _handle = load_entry(&mut app, "orcs.prototypes.ron#Grunt", "Grunt");
+
+    let entity = with_commands(&mut app, |commands| commands.spawn("Grunt").id());
+
+    assert!(is_ready(&mut app, "Orc"));
+    assert_eq!(Some(&Health(10)), app.world.get::<Health>(entity));
+}
+
+#[test]
+fn should_label_bundle_list_by_name() {
+    let speed = std::any::type_name::<Speed>();
+    let mut app = app_with_bundle(
+        "bundles_list",
+        "wolves.prototypes.ron",
+        format!(
+            r##"[
+                (name: "Wolf", schematics: {{ "{speed}": (4) }}),
+                (name: "Alpha", templates: ["#Wolf"]),
+            ]"##
+        ),
+    );
+    let _handle = load_entry(&mut app, "wolves.prototypes.ron#Alpha", "Alpha");
+
+    let entity = with_commands(&mut app, |commands| commands.spawn("Alpha").id());
+
+    assert_eq!(Some(&Speed(4)), app.world.get::<Speed>(entity));
+}
+
+#[test]
+fn should_spawn_sibling_children() {
+    let health = std::any::type_name::<Health>();
+    let speed = std::any::type_name::<Speed>();
+    let mut app = app_with_bundle(
+        "bundles_siblings",
+        "camp.prototypes.ron",
+        format!(
+            r##"{{
+                "Camp": (name: "Camp", schematics: {{ "{health}": (1) }}, children: ["#Guard", "#Guard"]),
+                "Guard": (name: "Guard", schematics: {{ "{speed}": (2) }}),
+            }}"##
+        ),
+    );
+    let _handle = load_entry(&mut app, "camp.prototypes.ron#Camp", "Camp");
+
+    let entity = with_commands(&mut app, |commands| commands.spawn("Camp").id());
+
+    assert_eq!(Some(&Health(1)), app.world.get::<Health>(entity));
+    let children = app.world.get::<Children>(entity).unwrap();
+    assert_eq!(2, children.len());
+    for child in children.iter() {
+        assert_eq!(Some(&Speed(2)), app.world.get::<Speed>(*child));
+        assert!(app.world.get::<Health>(*child).is_none());
+    }
+}
@@ -0,0 +1,86 @@
+use bevy::prelude::*;
+
+use bevy_proto::capture::{capture_prototype, save_prototype};
+use bevy_proto::prelude::*;
+
+use common::*;
+
+mod common;
+
+/// Spawn a root with a child, which itself has a child.
+fn spawn_hierarchy(world: &mut World) -> Entity {
+    world
+        .spawn(Health(1))
+        .with_children(|parent| {
+            parent.spawn(Speed(2)).with_children(|parent| {
+                parent.spawn(Health(3));
+            });
+        })
+        .id()
+}
+
+/// Assert that the given entity matches the hierarchy created by [`spawn_hierarchy`].
+fn assert_hierarchy(world: &World, root: Entity) {
+    assert_eq!(Some(&Health(1)), world.get::<Health>(root));
+
+    let children = world.get::<Children>(root).unwrap();
+    assert_eq!(1, children.len());
+    let child = children[0];
+    assert_eq!(Some(&Speed(2)), world.get::<Speed>(child));
+
+    let grandchildren = world.get::<Children>(child).unwrap();
+    assert_eq!(1, grandchildren.len());
+    assert_eq!(Some(&Health(3)), world.get::<Health>(grandchildren[0]));
+}
+
+#[test]
+fn should_capture_without_registering() {
+    let mut app = app();
+    let entity = spawn_hierarchy(&mut app.world);
+
+    let first = capture_prototype(&app.world, entity, "Captured").unwrap();
+    let second = capture_prototype(&app.world, entity, "Captured").unwrap();
+
+    assert_eq!("Captured", first.prototype().id());
+    assert!(first.prototype().schematics().contains::<Health>());
+    assert_eq!(1, first.children().len());
+    assert!(first.children()[0]
+        .prototype()
+        .schematics()
+        .contains::<Speed>());
+    assert_eq!("Captured/0", first.children()[0].prototype().id());
+    assert_eq!(
+        "Captured/0/0",
+        first.children()[0].children()[0].prototype().id()
+    );
+    assert_eq!(1, second.children().len());
+    assert!(app.world.resource::<Assets<Prototype>>().is_empty());
+}
+
+#[test]
+fn should_spawn_registered_capture() {
+    let mut app = app();
+    let entity = spawn_hierarchy(&mut app.world);
+
+    let captured = capture_prototype(&app.world, entity, "Captured").unwrap();
+    let _handle = captured.register(&mut app.world.resource_mut::<Assets<Prototype>>());
+    settle(&mut app);
+
+    let spawned = with_commands(&mut app, |commands| commands.spawn("Captured").id());
+    assert_hierarchy(&app.world, spawned);
+}
+
+#[test]
+fn should_round_trip_captured_hierarchy() {
+    let folder = asset_folder("capture");
+    let mut app = app_in(&folder);
+    let entity = spawn_hierarchy(&mut app.world);
+
+    let captured = capture_prototype(&app.world, entity, "Captured").unwrap();
+    save_prototype(&app.world, &captured, folder.join("Captured.prototype.ron")).unwrap();
+
+    let _handle = load(&mut app, "Captured.prototype.ron", "Captured");
+
+    let spawned = with_commands(&mut app, |commands| commands.spawn("Captured").id());
+    assert_hierarchy(&app.world, spawned);
+}
@@ -0,0 +1,135 @@
+//! Utilities shared between the integration tests.
+#![allow(dead_code)]
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use bevy::ecs::system::SystemState;
+use bevy::prelude::*;
+
+use bevy_proto::prelude::*;
+
+#[derive(Component, Schematic, Reflect, Debug, Default, Clone, PartialEq)]
+#[reflect(Component, Schematic)]
+pub struct Health(pub u32);
+
+#[derive(Component, Schematic, Reflect, Debug, Default, Clone, PartialEq)]
+#[reflect(Component, Schematic)]
+pub struct Speed(pub u32);
+
+/// Create an app with the default [`ProtoPlugin`] and the test schematics registered.
+pub fn app() -> App {
+    app_with(ProtoPlugin::new())
+}
+
+/// Create an app with the given [`ProtoPlugin`] and the test schematics registered.
+pub fn app_with(plugin: ProtoPlugin) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default(), plugin))
+        .register_type::<Health>()
+        .register_type::<Speed>();
+    app
+}
+
+/// Create an app with the default [`ProtoPlugin`] that loads its assets from the given folder.
+pub fn app_in(folder: &Path) -> App {
+    app_in_with(folder, ProtoPlugin::new())
+}
+
+/// Create an app with the given [`ProtoPlugin`] that loads its assets from the given folder.
+pub fn app_in_with(folder: &Path, plugin: ProtoPlugin) -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin {
+            asset_folder: folder.to_string_lossy().into_owned(),
+            ..default()
+        },
+        plugin,
+    ))
+    .register_type::<Health>()
+    .register_type::<Speed>();
+    app
+}
+
+/// Create an empty asset folder for the test with the given name.
+pub fn asset_folder(name: &str) -> PathBuf {
+    let folder = std::env::temp_dir()
+        .join("bevy_proto_tests")
+        .join(format!("{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&folder);
+    std::fs::create_dir_all(&folder).unwrap();
+    folder
+}
+
+/// Load the prototype at the given path and wait for it to be registered with the given ID.
+pub fn load(app: &mut App, path: &str, id: &str) -> Handle<Prototype> {
+    let handle = app.world.resource::<AssetServer>().load(path);
+    wait_for(app, id);
+    handle
+}
+
+/// Run updates until the prototype with the given ID is ready.
+///
+/// # Panics
+///
+/// Panics if the prototype is not ready after a few seconds.
+pub fn wait_for(app: &mut App, id: &str) {
+    for _ in 0..300 {
+        app.update();
+        if is_ready(app, id) {
+            return;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    panic!("prototype {id:?} was never registered");
+}
+
+/// Returns true if the prototype with the given ID is ready to be spawned.
+pub fn is_ready(app: &mut App, id: &str) -> bool {
+    let mut state = SystemState::<Prototypes>::new(&mut app.world);
+    state.get(&app.world).is_ready(id)
+}
+
+/// Build the given prototype and wait for it to be registered.
+///
+/// The returned handle must be kept alive for the prototype to stay registered.
+pub fn build(app: &mut App, builder: PrototypeBuilder) -> Handle<Prototype> {
+    let handle = app
+        .world
+        .resource_scope(|world, mut prototypes: Mut<Assets<Prototype>>| {
+            builder.build(&mut prototypes, world.resource::<AssetServer>())
+        });
+    settle(app);
+    handle
+}
+
+/// Run enough updates for asset events to be processed.
+pub fn settle(app: &mut App) {
+    for _ in 0..3 {
+        app.update();
+    }
+}
+
+/// Mark the given prototype as modified so that it gets reloaded.
+pub fn touch(app: &mut App, handle: &Handle<Prototype>) {
+    app.world
+        .resource_mut::<Assets<Prototype>>()
+        .get_mut(handle)
+        .unwrap();
+    settle(app);
+}
+
+/// Run the given closure with [`ProtoCommands`] and apply them to the world.
+pub fn with_commands<R>(app: &mut App, f: impl FnOnce(&mut ProtoCommands) -> R) -> R {
+    let mut state = SystemState::<ProtoCommands>::new(&mut app.world);
+    let output = f(&mut state.get_mut(&mut app.world));
+    state.apply(&mut app.world);
+    output
+}
+
+/// Map the events of the given type sent during the last two updates.
+pub fn events<E: Event, R>(app: &App, f: impl Fn(&E) -> R) -> Vec<R> {
+    let events = app.world.resource::<Events<E>>();
+    events.get_reader().iter(events).map(f).collect()
+}
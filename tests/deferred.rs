@@ -0,0 +1,91 @@
+//! Tests for spawning prototypes once they're ready.
+
+use std::time::Duration;
+
+use bevy::ecs::system::SystemState;
+use bevy::prelude::*;
+
+use bevy_proto::backend::proto::ProtoSpawnError;
+use bevy_proto::prelude::*;
+
+use common::*;
+
+mod common;
+
+/// Run updates until an event of the given type is mapped to `Some`.
+///
+/// # Panics
+///
+/// Panics if no such event is sent within a few seconds.
+fn wait_for_event<E: Event, R>(app: &mut App, f: impl Fn(&E) -> Option<R>) -> R {
+    for _ in 0..300 {
+        app.update();
+        if let Some(output) = events(app, &f).into_iter().flatten().next() {
+            return output;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    panic!("event was never sent");
+}
+
+#[test]
+fn should_spawn_when_ready() {
+    let health = std::any::type_name::<Health>();
+    let folder = asset_folder("deferred_ready");
+    std::fs::write(
+        folder.join("Base.prototype.ron"),
+        format!(r#"(name: "Base", schematics: {{ "{health}": (5) }})"#),
+    )
+    .unwrap();
+    let mut app = app_in(&folder);
+
+    let mut state = SystemState::<PrototypesMut>::new(&mut app.world);
+    let _handle = state.get_mut(&mut app.world).load("Base.prototype.ron");
+    state.apply(&mut app.world);
+
+    let entity = with_commands(&mut app, |commands| commands.spawn_when_ready("Base").id());
+    let spawned = wait_for_event(&mut app, |event: &ProtoSpawned| {
+        Some((event.id.clone(), event.entity))
+    });
+
+    assert_eq!((String::from("Base"), entity), spawned);
+    // The event is only sent once the prototype has been inserted
+    assert_eq!(Some(&Health(5)), app.world.get::<Health>(entity));
+}
+
+#[test]
+fn should_fail_unknown_id() {
+    let mut app = app();
+
+    let entity = with_commands(&mut app, |commands| {
+        commands.spawn_when_ready("Missing").id()
+    });
+    let error = wait_for_event(&mut app, |event: &ProtoSpawnFailed| {
+        (event.entity == Some(entity)).then(|| event.error.to_string())
+    });
+
+    assert_eq!(
+        ProtoSpawnError::NotLoaded(String::from("Missing")).to_string(),
+        error
+    );
+    assert!(events(&app, |_: &ProtoSpawned| ()).is_empty());
+}
+
+#[test]
+fn should_fail_failed_load() {
+    let folder = asset_folder("deferred_failed");
+    std::fs::write(folder.join("Broken.prototype.ron"), "(name: ").unwrap();
+    let mut app = app_in(&folder);
+
+    let entity = with_commands(&mut app, |commands| {
+        commands.spawn_path_when_ready("Broken.prototype.ron").id()
+    });
+    let error = wait_for_event(&mut app, |event: &ProtoSpawnFailed| {
+        (event.entity == Some(entity)).then(|| event.error.to_string())
+    });
+
+    assert!(
+        error.contains("failed to load"),
+        "unexpected error: {error}"
+    );
+}
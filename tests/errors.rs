@@ -0,0 +1,207 @@
+//! Tests for the errors reported when a prototype fails to spawn.
+//!
+//! Load failures are only reported when waiting on a prototype,
+//! so they are tested alongside the other deferred commands.
+
+use std::time::Duration;
+
+use bevy::asset::LoadState;
+use bevy::prelude::*;
+
+use bevy_proto::backend::proto::ProtoSpawnError;
+use bevy_proto::backend::schematics::{CompiledSchematic, SchematicError};
+use bevy_proto::backend::tree::EntityAccess;
+use bevy_proto::prelude::*;
+
+use common::*;
+
+mod common;
+
+#[derive(Component, Schematic, Reflect, Debug)]
+#[reflect(Schematic)]
+#[schematic(input(vis = pub(crate)))]
+struct Target {
+    #[schematic(entity)]
+    entity: Entity,
+}
+
+/// A component that can never be created from reflection.
+#[derive(Component, Reflect, Debug)]
+#[reflect(from_reflect = false)]
+struct Unconvertible;
+
+impl FromReflect for Unconvertible {
+    fn from_reflect(_: &dyn Reflect) -> Option<Self> {
+        None
+    }
+}
+
+#[derive(Reflect, Debug)]
+struct Uncopyable;
+
+impl Schematic for Uncopyable {
+    type Input = Self;
+
+    fn apply(_: &Self::Input, _: SchematicId, context: &mut SchematicContext) {
+        context.entity_mut().unwrap().insert(Unconvertible);
+    }
+
+    fn remove(_: &Self::Input, _: SchematicId, context: &mut SchematicContext) {
+        context.entity_mut().unwrap().remove::<Unconvertible>();
+    }
+
+    fn compile(_: &Self::Input, _: SchematicId) -> Option<CompiledSchematic> {
+        Some(CompiledSchematic::from_reflect(Unconvertible))
+    }
+}
+
+/// Returns the errors of all [`ProtoSpawnFailed`] events sent for the given entity.
+fn errors(app: &App, entity: Entity) -> Vec<String> {
+    events(app, |event: &ProtoSpawnFailed| {
+        (event.entity == Some(entity)).then(|| event.error.to_string())
+    })
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+#[test]
+fn should_fail_not_loaded() {
+    let mut app = app();
+
+    let entity = with_commands(&mut app, |commands| commands.try_spawn("Missing").id());
+
+    assert_eq!(
+        vec![ProtoSpawnError::NotLoaded(String::from("Missing")).to_string()],
+        errors(&app, entity)
+    );
+}
+
+#[test]
+fn should_fail_still_loading() {
+    let folder = asset_folder("errors_still_loading");
+    // The referenced ID is never loaded, so this prototype is never registered
+    std::fs::write(
+        folder.join("Waiting.prototype.ron"),
+        r#"(name: "Waiting", templates: [(Id: "Absent")])"#,
+    )
+    .unwrap();
+    let mut app = app_in(&folder);
+
+    let handle: Handle<Prototype> = app
+        .world
+        .resource::<AssetServer>()
+        .load("Waiting.prototype.ron");
+    for _ in 0..300 {
+        app.update();
+        if app.world.resource::<AssetServer>().get_load_state(&handle) == LoadState::Loaded {
+            break;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    settle(&mut app);
+
+    let entity = with_commands(&mut app, |commands| commands.try_spawn("Waiting").id());
+
+    assert_eq!(
+        vec![ProtoSpawnError::StillLoading(String::from("Waiting")).to_string()],
+        errors(&app, entity)
+    );
+}
+
+#[test]
+fn should_fail_missing_entity() {
+    let mut app = app();
+    let _handle = build(
+        &mut app,
+        PrototypeBuilder::new("A").with_schematic::<Health>(Health(1)),
+    );
+
+    let entity = with_commands(&mut app, |commands| {
+        let entity = commands.spawn_empty().id();
+        commands.commands().entity(entity).despawn();
+        commands.entity(entity).try_insert("A");
+        entity
+    });
+
+    assert_eq!(
+        vec![ProtoSpawnError::MissingEntity(entity).to_string()],
+        errors(&app, entity)
+    );
+}
+
+#[test]
+#[should_panic(expected = "could not apply command for prototype")]
+fn should_panic_on_proto_error_without_entity() {
+    let mut app = app();
+    let _handle = build(
+        &mut app,
+        PrototypeBuilder::new("A").with_schematic::<Health>(Health(1)),
+    );
+
+    // Without an entity there is nothing to report the `ProtoError` on
+    with_commands(&mut app, |commands| commands.apply("A"));
+}
+
+#[test]
+fn should_fail_schematic() {
+    let mut app = app();
+    app.register_type::<Target>();
+    let missing = EntityAccess::from("./Missing");
+    let _handle = build(
+        &mut app,
+        PrototypeBuilder::new("A").with_schematic::<Target>(TargetInput {
+            entity: missing.clone(),
+        }),
+    );
+
+    let entity = with_commands(&mut app, |commands| commands.try_spawn("A").id());
+
+    let expected = ProtoSpawnError::Schematic {
+        type_name: std::any::type_name::<Target>().to_string(),
+        error: SchematicError::MissingEntity(missing.to_path()),
+    };
+    assert_eq!(vec![expected.to_string()], errors(&app, entity));
+    // The placeholder entity should never be inserted
+    assert!(app.world.get::<Target>(entity).is_none());
+}
+
+#[test]
+fn should_fail_compiled_copy() {
+    let mut app = app();
+    app.register_type::<Uncopyable>();
+    let _handle = build(
+        &mut app,
+        PrototypeBuilder::new("A").with_schematic::<Uncopyable>(Uncopyable),
+    );
+
+    let entity = with_commands(&mut app, |commands| commands.try_spawn("A").id());
+
+    let expected = ProtoSpawnError::Schematic {
+        type_name: std::any::type_name::<Uncopyable>().to_string(),
+        error: SchematicError::FromReflectFail,
+    };
+    assert_eq!(vec![expected.to_string()], errors(&app, entity));
+    assert!(app.world.get::<Unconvertible>(entity).is_none());
+}
+
+#[test]
+fn should_fail_batch_without_entity() {
+    let mut app = app();
+    app.register_type::<Uncopyable>();
+    let _handle = build(
+        &mut app,
+        PrototypeBuilder::new("A").with_schematic::<Uncopyable>(Uncopyable),
+    );
+
+    with_commands(&mut app, |commands| commands.try_spawn_batch("A", 3));
+
+    let expected = ProtoSpawnError::Schematic {
+        type_name: std::any::type_name::<Uncopyable>().to_string(),
+        error: SchematicError::FromReflectFail,
+    };
+    let failed = events(&app, |event: &ProtoSpawnFailed| {
+        (event.entity, event.error.to_string())
+    });
+    assert_eq!(vec![(None, expected.to_string())], failed);
+}
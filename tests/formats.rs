@@ -0,0 +1,120 @@
+//! Tests for loading prototypes from each of the supported formats.
+
+use common::*;
+
+mod common;
+
+/// Load the given prototype file and return the [`Health`] of a spawned instance.
+fn spawn_health(name: &str, file: &str, contents: &str) -> Option<Health> {
+    let folder = asset_folder(name);
+    std::fs::write(folder.join(file), contents).unwrap();
+
+    let mut app = app_in(&folder);
+    let _handle = load(&mut app, file, "Test");
+
+    let entity = with_commands(&mut app, |commands| commands.spawn("Test").id());
+    app.world.get::<Health>(entity).cloned()
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn should_load_json() {
+    let health = std::any::type_name::<Health>();
+    let contents = format!(
+        r#"{{
+            "name": "Test",
+            "schematics": {{ "{health}": [5] }}
+        }}"#
+    );
+
+    assert_eq!(
+        Some(Health(5)),
+        spawn_health("format_json", "Test.prototype.json", &contents)
+    );
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn should_load_toml() {
+    let health = std::any::type_name::<Health>();
+    let contents = format!(
+        r#"
+        name = "Test"
+
+        [schematics]
+        "{health}" = [5]
+        "#
+    );
+
+    assert_eq!(
+        Some(Health(5)),
+        spawn_health("format_toml", "Test.prototype.toml", &contents)
+    );
+}
+
+#[cfg(feature = "bincode")]
+mod binary {
+    use bevy::prelude::*;
+
+    use bevy_proto::backend::schematics::Schematics;
+    use bevy_proto::prelude::*;
+    use bevy_proto::ser::PrototypeSerializer;
+
+    use crate::common::*;
+
+    /// Build the given prototype and serialize it to the binary format.
+    ///
+    /// The built prototype is freed once this returns.
+    fn to_binary(app: &mut App, builder: PrototypeBuilder) -> Result<Vec<u8>, PrototypeError> {
+        let handle = build(app, builder);
+        let registry = app.world.resource::<AppTypeRegistry>().read();
+        let prototypes = app.world.resource::<Assets<Prototype>>();
+        PrototypeSerializer::new(prototypes.get(&handle).unwrap(), &registry)
+            .with_prototypes(prototypes)
+            .to_binary()
+    }
+
+    #[test]
+    fn should_round_trip_binary() {
+        let folder = asset_folder("format_binary");
+        let mut app = app_in(&folder);
+
+        let bytes = to_binary(
+            &mut app,
+            PrototypeBuilder::new("Test")
+                .with_schematic::<Health>(Health(5))
+                .with_child(PrototypeBuilder::new("Child").with_schematic::<Speed>(Speed(1))),
+        )
+        .unwrap();
+        settle(&mut app);
+
+        std::fs::write(folder.join("Test.prototype.bin"), bytes).unwrap();
+        let _handle = load(&mut app, "Test.prototype.bin", "Test");
+
+        let entity = with_commands(&mut app, |commands| commands.spawn("Test").id());
+        assert_eq!(Some(&Health(5)), app.world.get::<Health>(entity));
+        let children = app.world.get::<Children>(entity).unwrap();
+        assert_eq!(1, children.len());
+        assert_eq!(Some(&Speed(1)), app.world.get::<Speed>(children[0]));
+    }
+
+    #[test]
+    fn should_reject_unsupported_binary_features() {
+        let mut app = app();
+
+        let mut variant = Schematics::default();
+        variant.insert::<Health>(Health(1));
+        let variants = to_binary(
+            &mut app,
+            PrototypeBuilder::new("Variants").with_variant(variant),
+        );
+        assert!(variants.is_err());
+
+        let _base = build(&mut app, PrototypeBuilder::new("Base"));
+        let reference = to_binary(
+            &mut app,
+            PrototypeBuilder::new("Reference").with_template("Base"),
+        );
+        assert!(reference.is_err());
+    }
+}
@@ -0,0 +1,95 @@
+//! Tests for merging and removing schematics inherited from templates.
+
+use bevy::prelude::*;
+use bevy::reflect::DynamicStruct;
+
+use bevy_proto::backend::schematics::DynamicSchematic;
+use bevy_proto::prelude::*;
+
+use common::*;
+
+mod common;
+
+#[test]
+fn should_merge_patches_over_template() {
+    let mut app = app();
+
+    let mut translation = DynamicStruct::default();
+    translation.insert("y", 5.0_f32);
+    let mut patch = DynamicStruct::default();
+    patch.insert("translation", translation);
+
+    let _handles = [
+        build(
+            &mut app,
+            PrototypeBuilder::new("Base")
+                .with_schematic::<Transform>(Transform::from_xyz(1.0, 2.0, 3.0))
+                .with_schematic::<Health>(Health(1)),
+        ),
+        build(
+            &mut app,
+            PrototypeBuilder::new("Patched")
+                .with_template("Base")
+                .with_dynamic_schematic(DynamicSchematic::new_patch::<Transform>(patch))
+                .with_dynamic_schematic(DynamicSchematic::new_patch::<Speed>(Speed(4))),
+        ),
+    ];
+
+    let entity = with_commands(&mut app, |commands| commands.spawn("Patched").id());
+
+    let transform = app.world.get::<Transform>(entity).unwrap();
+    assert_eq!(Vec3::new(1.0, 5.0, 3.0), transform.translation);
+    assert_eq!(Vec3::ONE, transform.scale);
+    assert_eq!(Some(&Health(1)), app.world.get::<Health>(entity));
+    // Patches without a base are applied as-is
+    assert_eq!(Some(&Speed(4)), app.world.get::<Speed>(entity));
+}
+
+#[test]
+fn should_not_spawn_removed_schematics() {
+    let mut app = app();
+    let _handles = [
+        build(
+            &mut app,
+            PrototypeBuilder::new("Base")
+                .with_schematic::<Health>(Health(1))
+                .with_schematic::<Speed>(Speed(1)),
+        ),
+        build(
+            &mut app,
+            PrototypeBuilder::new("Removed")
+                .with_template("Base")
+                .with_removed_schematic::<Health>(),
+        ),
+    ];
+
+    let entity = with_commands(&mut app, |commands| commands.spawn("Removed").id());
+    assert!(app.world.get::<Health>(entity).is_none());
+    assert_eq!(Some(&Speed(1)), app.world.get::<Speed>(entity));
+}
+
+#[test]
+fn should_spawn_schematics_readded_by_inheritor() {
+    let mut app = app();
+    let _handles = [
+        build(
+            &mut app,
+            PrototypeBuilder::new("Base").with_schematic::<Health>(Health(1)),
+        ),
+        build(
+            &mut app,
+            PrototypeBuilder::new("Removed")
+                .with_template("Base")
+                .with_removed_schematic::<Health>(),
+        ),
+        build(
+            &mut app,
+            PrototypeBuilder::new("Readded")
+                .with_template("Removed")
+                .with_schematic::<Health>(Health(3)),
+        ),
+    ];
+
+    let entity = with_commands(&mut app, |commands| commands.spawn("Readded").id());
+    assert_eq!(Some(&Health(3)), app.world.get::<Health>(entity));
+}
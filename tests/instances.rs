@@ -0,0 +1,111 @@
+use bevy_proto::backend::proto::{ProtoInstance, ProtoParams};
+use bevy_proto::backend::schematics::Schematics;
+use bevy_proto::prelude::*;
+
+use common::*;
+
+mod common;
+
+#[test]
+fn should_track_last_inserted_prototype() {
+    let mut app = app();
+    let _a = build(
+        &mut app,
+        PrototypeBuilder::new("A").with_schematic::<Health>(Health(1)),
+    );
+    let b = build(
+        &mut app,
+        PrototypeBuilder::new("B").with_schematic::<Speed>(Speed(2)),
+    );
+
+    let entity = with_commands(&mut app, |commands| commands.spawn("A").id());
+    with_commands(&mut app, |commands| {
+        commands.entity(entity).insert("B");
+    });
+
+    let instance = app.world.get::<ProtoInstance>(entity).unwrap();
+    assert_eq!(b.id(), instance.handle());
+}
+
+#[test]
+fn should_stop_tracking_removed_prototype() {
+    let mut app = app_with(ProtoPlugin::new().with_instance_reloading(true));
+    let a = build(
+        &mut app,
+        PrototypeBuilder::new("A").with_schematic::<Health>(Health(1)),
+    );
+
+    let entity = with_commands(&mut app, |commands| commands.spawn("A").id());
+    assert_eq!(Some(&Health(1)), app.world.get::<Health>(entity));
+
+    with_commands(&mut app, |commands| {
+        commands.entity(entity).remove("A");
+    });
+    assert!(app.world.get::<ProtoInstance>(entity).is_none());
+    assert!(app.world.get::<Health>(entity).is_none());
+
+    // Reloading the prototype should no longer affect the entity
+    touch(&mut app, &a);
+    assert!(app.world.get::<Health>(entity).is_none());
+}
+
+#[test]
+fn should_keep_tracking_when_removing_other_prototype() {
+    let mut app = app();
+    let a = build(
+        &mut app,
+        PrototypeBuilder::new("A").with_schematic::<Health>(Health(1)),
+    );
+    let _b = build(
+        &mut app,
+        PrototypeBuilder::new("B").with_schematic::<Speed>(Speed(2)),
+    );
+
+    let entity = with_commands(&mut app, |commands| commands.spawn("A").id());
+    with_commands(&mut app, |commands| {
+        commands.entity(entity).remove("B");
+    });
+
+    let instance = app.world.get::<ProtoInstance>(entity).unwrap();
+    assert_eq!(a.id(), instance.handle());
+}
+
+#[test]
+fn should_keep_params_and_overrides_when_reinserting() {
+    let mut app = app_with(ProtoPlugin::new().with_instance_reloading(true));
+    // Prototypes with variants are always fully re-inserted when reloaded
+    let mut variant = Schematics::default();
+    variant.insert::<Speed>(Speed(1));
+    let a = build(
+        &mut app,
+        PrototypeBuilder::new("A")
+            .with_schematic::<Health>(Health(1))
+            .with_variant(variant),
+    );
+
+    let entity = with_commands(&mut app, |commands| {
+        let mut overrides = Schematics::default();
+        overrides.insert::<Health>(Health(9));
+        commands
+            .spawn_empty()
+            .insert_with_overrides("A", overrides)
+            .id()
+    });
+    assert_eq!(Some(&Health(9)), app.world.get::<Health>(entity));
+
+    touch(&mut app, &a);
+
+    assert_eq!(Some(&Health(9)), app.world.get::<Health>(entity));
+    let instance = app.world.get::<ProtoInstance>(entity).unwrap();
+    assert!(instance.overrides().unwrap().contains::<Health>());
+    assert_eq!(None, instance.params());
+
+    let entity = with_commands(&mut app, |commands| {
+        commands
+            .spawn_with("A", ProtoParams::new().with("hp", 3))
+            .id()
+    });
+    let instance = app.world.get::<ProtoInstance>(entity).unwrap();
+    assert_eq!(Some(&ProtoParams::new().with("hp", 3)), instance.params());
+    assert!(instance.overrides().is_none());
+}
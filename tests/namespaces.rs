@@ -0,0 +1,88 @@
+//! Tests for resolving prototype IDs within a namespace.
+
+use bevy_proto::prelude::*;
+
+use common::*;
+
+mod common;
+
+#[test]
+fn should_resolve_id_within_configured_namespace() {
+    let mut app =
+        app_with(ProtoPlugin::new().with_config(ProtoConfig::default().with_namespace("base")));
+    let _handles = [
+        build(
+            &mut app,
+            PrototypeBuilder::new("base:Sword").with_schematic::<Health>(Health(1)),
+        ),
+        build(
+            &mut app,
+            PrototypeBuilder::new("Sword").with_schematic::<Health>(Health(2)),
+        ),
+        build(
+            &mut app,
+            PrototypeBuilder::new("Shield").with_schematic::<Health>(Health(3)),
+        ),
+    ];
+
+    let (sword, shield, qualified) = with_commands(&mut app, |commands| {
+        (
+            commands.spawn("Sword").id(),
+            commands.spawn("Shield").id(),
+            commands.spawn("base:Sword").id(),
+        )
+    });
+
+    // The qualified ID takes precedence over the unqualified one
+    assert_eq!(Some(&Health(1)), app.world.get::<Health>(sword));
+    // Falls back to the ID as-is when there's no match within the namespace
+    assert_eq!(Some(&Health(3)), app.world.get::<Health>(shield));
+    assert_eq!(Some(&Health(1)), app.world.get::<Health>(qualified));
+}
+
+#[test]
+fn should_not_qualify_namespaced_id() {
+    let mut app =
+        app_with(ProtoPlugin::new().with_config(ProtoConfig::default().with_namespace("base")));
+    let _handle = build(
+        &mut app,
+        PrototypeBuilder::new("extra:Axe").with_schematic::<Health>(Health(1)),
+    );
+
+    assert!(is_ready(&mut app, "extra:Axe"));
+    assert!(!is_ready(&mut app, "Axe"));
+    assert!(!is_ready(&mut app, "base:extra:Axe"));
+}
+
+#[test]
+fn should_resolve_template_within_own_namespace() {
+    let mut app = app();
+    let _handles = [
+        build(
+            &mut app,
+            PrototypeBuilder::new("base:Sword").with_schematic::<Health>(Health(1)),
+        ),
+        build(
+            &mut app,
+            PrototypeBuilder::new("Sword").with_schematic::<Health>(Health(2)),
+        ),
+        build(
+            &mut app,
+            PrototypeBuilder::new("base:Knight").with_template("Sword"),
+        ),
+        build(
+            &mut app,
+            PrototypeBuilder::new("Knight").with_template("Sword"),
+        ),
+    ];
+
+    let (namespaced, plain) = with_commands(&mut app, |commands| {
+        (
+            commands.spawn("base:Knight").id(),
+            commands.spawn("Knight").id(),
+        )
+    });
+
+    assert_eq!(Some(&Health(1)), app.world.get::<Health>(namespaced));
+    assert_eq!(Some(&Health(2)), app.world.get::<Health>(plain));
+}
@@ -0,0 +1,178 @@
+//! Tests for prototypes overriding other prototypes with the same ID.
+
+use bevy::asset::HandleId;
+use bevy::prelude::*;
+
+use bevy_proto::backend::proto::OverridePolicy;
+use bevy_proto::prelude::*;
+
+use common::*;
+
+mod common;
+
+/// The kind and handle of every [`ProtoAssetEvent`] sent so far.
+#[derive(Resource, Default)]
+struct Recorded(Vec<(&'static str, HandleId)>);
+
+fn record(mut events: EventReader<ProtoAssetEvent>, mut recorded: ResMut<Recorded>) {
+    for event in events.iter() {
+        let kind = match event {
+            ProtoAssetEvent::Created { .. } => "Created",
+            ProtoAssetEvent::Modified { .. } => "Modified",
+            ProtoAssetEvent::Removed { .. } => "Removed",
+        };
+        recorded.0.push((kind, event.handle().id()));
+    }
+}
+
+/// Create an app where prototypes in the `base` and `other` folders can be replaced,
+/// prototypes in `mods` replace them, and prototypes in `patches` merge into them.
+fn app_with_policies() -> App {
+    let mut app = app_with(
+        ProtoPlugin::new().with_config(
+            ProtoConfig::default()
+                .with_override_policy("base", OverridePolicy::replace(0))
+                .with_override_policy("other", OverridePolicy::replace(0))
+                .with_override_policy("mods", OverridePolicy::replace(10))
+                .with_override_policy("patches", OverridePolicy::merge(10)),
+        ),
+    );
+    app.init_resource::<Recorded>().add_systems(Update, record);
+    app
+}
+
+/// Create a builder for the `Knight` prototype within the given folder.
+fn knight(folder: &str) -> PrototypeBuilder {
+    PrototypeBuilder::new("Knight").with_path(format!("{folder}/Knight.prototype"))
+}
+
+/// Take the events recorded so far.
+fn take_events(app: &mut App) -> Vec<(&'static str, HandleId)> {
+    std::mem::take(&mut app.world.resource_mut::<Recorded>().0)
+}
+
+/// Drop the given handle and run updates until its prototype has been unloaded.
+///
+/// Freeing the asset takes a few updates, so [`settle`] alone isn't enough.
+fn unload(app: &mut App, handle: Handle<Prototype>) {
+    let weak = handle.clone_weak();
+    drop(handle);
+    for _ in 0..300 {
+        app.update();
+        if app
+            .world
+            .resource::<Assets<Prototype>>()
+            .get(&weak)
+            .is_none()
+        {
+            break;
+        }
+    }
+    settle(app);
+}
+
+fn spawn_knight(app: &mut App) -> Entity {
+    with_commands(app, |commands| commands.spawn("Knight").id())
+}
+
+#[test]
+fn should_replace_lower_priority() {
+    let mut app = app_with_policies();
+    let base = build(
+        &mut app,
+        knight("base")
+            .with_schematic::<Health>(Health(1))
+            .with_schematic::<Speed>(Speed(1)),
+    );
+    assert_eq!(vec![("Created", base.id())], take_events(&mut app));
+
+    let mods = build(&mut app, knight("mods").with_schematic::<Health>(Health(2)));
+
+    assert_eq!(
+        vec![("Removed", base.id()), ("Created", mods.id())],
+        take_events(&mut app)
+    );
+    let entity = spawn_knight(&mut app);
+    assert_eq!(Some(&Health(2)), app.world.get::<Health>(entity));
+    assert!(app.world.get::<Speed>(entity).is_none());
+}
+
+#[test]
+fn should_merge_into_lower_priority() {
+    let mut app = app_with_policies();
+    let base = build(
+        &mut app,
+        knight("base")
+            .with_schematic::<Health>(Health(1))
+            .with_schematic::<Speed>(Speed(1)),
+    );
+    let patch = build(
+        &mut app,
+        knight("patches").with_schematic::<Health>(Health(2)),
+    );
+    assert_eq!(
+        vec![
+            ("Created", base.id()),
+            ("Removed", base.id()),
+            ("Created", patch.id())
+        ],
+        take_events(&mut app)
+    );
+
+    let entity = spawn_knight(&mut app);
+    assert_eq!(Some(&Health(2)), app.world.get::<Health>(entity));
+    assert_eq!(Some(&Speed(1)), app.world.get::<Speed>(entity));
+
+    // Modifying the base should reload the patch merged into it
+    app.world
+        .resource_mut::<Assets<Prototype>>()
+        .get_mut(&base)
+        .unwrap()
+        .schematics_mut()
+        .insert::<Speed>(Speed(5));
+    settle(&mut app);
+
+    assert_eq!(vec![("Modified", patch.id())], take_events(&mut app));
+    let entity = spawn_knight(&mut app);
+    assert_eq!(Some(&Health(2)), app.world.get::<Health>(entity));
+    assert_eq!(Some(&Speed(5)), app.world.get::<Speed>(entity));
+}
+
+#[test]
+fn should_prefer_later_with_equal_priority() {
+    let mut app = app_with_policies();
+    let base = build(&mut app, knight("base").with_schematic::<Health>(Health(1)));
+    let other = build(
+        &mut app,
+        knight("other").with_schematic::<Health>(Health(2)),
+    );
+
+    assert_eq!(
+        vec![
+            ("Created", base.id()),
+            ("Removed", base.id()),
+            ("Created", other.id())
+        ],
+        take_events(&mut app)
+    );
+    let entity = spawn_knight(&mut app);
+    assert_eq!(Some(&Health(2)), app.world.get::<Health>(entity));
+}
+
+#[test]
+fn should_restore_layer_when_top_removed() {
+    let mut app = app_with_policies();
+    let base = build(&mut app, knight("base").with_schematic::<Health>(Health(1)));
+    let mods = build(&mut app, knight("mods").with_schematic::<Health>(Health(2)));
+    let mods_id = mods.id();
+    take_events(&mut app);
+
+    unload(&mut app, mods);
+
+    assert_eq!(
+        vec![("Removed", mods_id), ("Created", base.id())],
+        take_events(&mut app)
+    );
+    let entity = spawn_knight(&mut app);
+    assert_eq!(Some(&Health(1)), app.world.get::<Health>(entity));
+}
@@ -0,0 +1,125 @@
+//! Tests for prototype parameters.
+
+use std::time::Duration;
+
+use bevy::asset::LoadState;
+use bevy::prelude::*;
+
+use bevy_proto::backend::proto::ProtoParams;
+use bevy_proto::prelude::*;
+
+use common::*;
+
+mod common;
+
+/// Write the given files to a new asset folder and create an app that loads from it.
+fn app_with_files(name: &str, files: &[(&str, String)]) -> App {
+    let folder = asset_folder(name);
+    for (file, contents) in files {
+        std::fs::write(folder.join(file), contents).unwrap();
+    }
+    app_in(&folder)
+}
+
+/// A prototype named `Base` that uses the `hp` parameter for its [`Health`].
+fn base() -> (&'static str, String) {
+    let health = std::any::type_name::<Health>();
+    (
+        "Base.prototype.ron",
+        format!(
+            r#"(
+                name: "Base",
+                params: {{ "hp": 5 }},
+                schematics: {{ "{health}": ("$hp") }},
+            )"#
+        ),
+    )
+}
+
+#[test]
+fn should_use_default_params() {
+    let mut app = app_with_files("params_default", &[base()]);
+    let _handle = load(&mut app, "Base.prototype.ron", "Base");
+
+    let entity = with_commands(&mut app, |commands| commands.spawn("Base").id());
+    assert_eq!(Some(&Health(5)), app.world.get::<Health>(entity));
+}
+
+#[test]
+fn should_override_params_when_spawning() {
+    let mut app = app_with_files("params_spawn_with", &[base()]);
+    let _handle = load(&mut app, "Base.prototype.ron", "Base");
+
+    let (overridden, default) = with_commands(&mut app, |commands| {
+        let overridden = commands
+            .spawn_with("Base", ProtoParams::new().with("hp", 7))
+            .id();
+        let default = commands.spawn("Base").id();
+        (overridden, default)
+    });
+
+    assert_eq!(Some(&Health(7)), app.world.get::<Health>(overridden));
+    assert_eq!(Some(&Health(5)), app.world.get::<Health>(default));
+}
+
+#[test]
+fn should_pass_params_through_templates() {
+    let child = (
+        "Child.prototype.ron",
+        r#"(
+            name: "Child",
+            templates: [(path: "Base.prototype.ron", params: { "hp": 9 })],
+        )"#
+        .to_string(),
+    );
+    let mut app = app_with_files("params_templates", &[base(), child]);
+    let _handle = load(&mut app, "Child.prototype.ron", "Child");
+
+    let (child, base, spawned) = with_commands(&mut app, |commands| {
+        let child = commands.spawn("Child").id();
+        let base = commands.spawn("Base").id();
+        let spawned = commands
+            .spawn_with("Child", ProtoParams::new().with("hp", 11))
+            .id();
+        (child, base, spawned)
+    });
+
+    assert_eq!(Some(&Health(9)), app.world.get::<Health>(child));
+    assert_eq!(Some(&Health(5)), app.world.get::<Health>(base));
+    // Values given at spawn time take precedence over inherited ones
+    assert_eq!(Some(&Health(11)), app.world.get::<Health>(spawned));
+}
+
+#[test]
+fn should_reject_params_after_schematics() {
+    let health = std::any::type_name::<Health>();
+    let late = (
+        "Late.prototype.ron",
+        format!(
+            r#"(
+                name: "Late",
+                schematics: {{ "{health}": ("$hp") }},
+                params: {{ "hp": 5 }},
+            )"#
+        ),
+    );
+    let mut app = app_with_files("params_after_schematics", &[late]);
+    let handle: Handle<Prototype> = app
+        .world
+        .resource::<AssetServer>()
+        .load("Late.prototype.ron");
+
+    for _ in 0..300 {
+        app.update();
+        if app.world.resource::<AssetServer>().get_load_state(&handle) == LoadState::Failed {
+            break;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    assert_eq!(
+        LoadState::Failed,
+        app.world.resource::<AssetServer>().get_load_state(&handle)
+    );
+    assert!(!is_ready(&mut app, "Late"));
+}
@@ -0,0 +1,69 @@
+//! Tests for the spawn plans compiled for prototypes.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use bevy::prelude::*;
+
+use bevy_proto::backend::schematics::CompiledSchematic;
+use bevy_proto::prelude::*;
+
+use common::*;
+
+mod common;
+
+/// The number of times [`Armor`] has been compiled.
+static COMPILED: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Component, Reflect, Debug, Default, Clone, PartialEq)]
+#[reflect(Schematic)]
+struct Armor(u32);
+
+impl Schematic for Armor {
+    type Input = Self;
+
+    fn apply(_input: &Self::Input, _id: SchematicId, _context: &mut SchematicContext) {
+        panic!("compiled schematics should not be applied dynamically");
+    }
+
+    fn remove(_input: &Self::Input, _id: SchematicId, context: &mut SchematicContext) {
+        context.entity_mut().unwrap().remove::<Self>();
+    }
+
+    fn compile(input: &Self::Input, _id: SchematicId) -> Option<CompiledSchematic> {
+        COMPILED.fetch_add(1, Ordering::SeqCst);
+        Some(CompiledSchematic::new(input.clone()))
+    }
+}
+
+#[test]
+fn should_reuse_plan_until_reloaded() {
+    let mut app = app();
+    app.register_type::<Armor>();
+    let handle = build(
+        &mut app,
+        PrototypeBuilder::new("Knight").with_schematic::<Armor>(Armor(3)),
+    );
+
+    let (first, second) = with_commands(&mut app, |commands| {
+        (commands.spawn("Knight").id(), commands.spawn("Knight").id())
+    });
+    let third = with_commands(&mut app, |commands| commands.spawn("Knight").id());
+
+    assert_eq!(1, COMPILED.load(Ordering::SeqCst));
+    for entity in [first, second, third] {
+        assert_eq!(Some(&Armor(3)), app.world.get::<Armor>(entity));
+    }
+
+    app.world
+        .resource_mut::<Assets<Prototype>>()
+        .get_mut(&handle)
+        .unwrap()
+        .schematics_mut()
+        .insert::<Armor>(Armor(8));
+    settle(&mut app);
+
+    let entity = with_commands(&mut app, |commands| commands.spawn("Knight").id());
+
+    assert_eq!(2, COMPILED.load(Ordering::SeqCst));
+    assert_eq!(Some(&Armor(8)), app.world.get::<Armor>(entity));
+}
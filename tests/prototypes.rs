@@ -0,0 +1,174 @@
+//! Tests for querying the registered prototypes.
+
+use bevy::ecs::system::SystemState;
+use bevy::prelude::*;
+
+use bevy_proto::backend::children::ChildForm;
+use bevy_proto::backend::schematics::DynamicSchematic;
+use bevy_proto::prelude::*;
+
+use common::*;
+
+mod common;
+
+/// Run the given closure with the [`Prototypes`] system param.
+fn with_prototypes<R>(app: &mut App, f: impl FnOnce(&Prototypes) -> R) -> R {
+    let mut state = SystemState::<Prototypes>::new(&mut app.world);
+    f(&state.get(&app.world))
+}
+
+/// Returns the sorted IDs of all prototypes containing the [`Health`] schematic.
+fn with_health(app: &mut App) -> Vec<String> {
+    with_prototypes(app, |prototypes| {
+        let mut ids = prototypes
+            .with_schematic::<Health>()
+            .cloned()
+            .collect::<Vec<_>>();
+        ids.sort();
+        ids
+    })
+}
+
+#[test]
+fn should_list_registered_prototypes() {
+    let mut app = app();
+    let a = build(
+        &mut app,
+        PrototypeBuilder::new("A").with_schematic::<Health>(Health(1)),
+    );
+    let b = build(&mut app, PrototypeBuilder::new("B"));
+
+    with_prototypes(&mut app, |prototypes| {
+        let mut ids = prototypes.ids().cloned().collect::<Vec<_>>();
+        ids.sort();
+        assert_eq!(vec![String::from("A"), String::from("B")], ids);
+        assert_eq!(Some(a.id()), prototypes.get_by_id("A").map(Handle::id));
+        assert_eq!(Some(b.id()), prototypes.get_by_id("B").map(Handle::id));
+        assert_eq!(None, prototypes.get_by_id("C"));
+    });
+}
+
+#[test]
+fn should_find_with_inherited_schematic() {
+    let mut app = app();
+    let _handles = [
+        build(
+            &mut app,
+            PrototypeBuilder::new("Base").with_schematic::<Health>(Health(1)),
+        ),
+        build(
+            &mut app,
+            PrototypeBuilder::new("Inherited").with_template("Base"),
+        ),
+        build(
+            &mut app,
+            PrototypeBuilder::new("Removed")
+                .with_template("Base")
+                .with_removed_schematic::<Health>(),
+        ),
+        build(
+            &mut app,
+            PrototypeBuilder::new("Other").with_schematic::<Speed>(Speed(1)),
+        ),
+    ];
+
+    assert_eq!(vec!["Base", "Inherited"], with_health(&mut app));
+    let with_speed = with_prototypes(&mut app, |prototypes| {
+        prototypes
+            .with_schematic::<Speed>()
+            .cloned()
+            .collect::<Vec<_>>()
+    });
+    assert_eq!(vec!["Other"], with_speed);
+}
+
+#[test]
+fn should_only_find_patches_with_base() {
+    let mut app = app();
+    let patch = || DynamicSchematic::new_patch::<Health>(Health(5));
+    let _handles = [
+        build(
+            &mut app,
+            PrototypeBuilder::new("Base").with_schematic::<Health>(Health(1)),
+        ),
+        build(
+            &mut app,
+            PrototypeBuilder::new("Patched")
+                .with_template("Base")
+                .with_dynamic_schematic(patch()),
+        ),
+        build(
+            &mut app,
+            PrototypeBuilder::new("Unpatched").with_dynamic_schematic(patch()),
+        ),
+        build(
+            &mut app,
+            PrototypeBuilder::new("Removed")
+                .with_template("Base")
+                .with_removed_schematic::<Health>()
+                .with_dynamic_schematic(patch()),
+        ),
+        build(
+            &mut app,
+            PrototypeBuilder::new("Inherited").with_template("Patched"),
+        ),
+    ];
+
+    assert_eq!(vec!["Base", "Inherited", "Patched"], with_health(&mut app));
+}
+
+#[test]
+fn should_list_templates_in_reverse_application_order() {
+    let mut app = app();
+    let _handles = [
+        build(&mut app, PrototypeBuilder::new("C")),
+        build(&mut app, PrototypeBuilder::new("B")),
+        build(&mut app, PrototypeBuilder::new("A").with_template("C")),
+        build(
+            &mut app,
+            PrototypeBuilder::new("Knight")
+                .with_template("A")
+                .with_template("B"),
+        ),
+    ];
+
+    with_prototypes(&mut app, |prototypes| {
+        // The prototype itself is never included
+        assert_eq!(
+            Some(vec!["A", "C", "B"]),
+            prototypes
+                .get_templates("Knight")
+                .map(|ids| ids.into_iter().map(String::as_str).collect())
+        );
+        assert_eq!(Some(Vec::new()), prototypes.get_templates("C"));
+        assert_eq!(None, prototypes.get_templates("Missing"));
+    });
+}
+
+#[test]
+fn should_list_inherited_children() {
+    let mut app = app();
+    let _handles = [
+        build(
+            &mut app,
+            PrototypeBuilder::new("Base").with_child(PrototypeBuilder::new("Flag")),
+        ),
+        build(
+            &mut app,
+            PrototypeBuilder::new("Camp")
+                .with_template("Base")
+                .with_child(PrototypeBuilder::new("Tent")),
+        ),
+    ];
+
+    with_prototypes(&mut app, |prototypes| {
+        assert_eq!(
+            Some(vec![
+                ChildForm::Single(String::from("Tent")),
+                ChildForm::Single(String::from("Flag")),
+            ]),
+            prototypes.get_children("Camp")
+        );
+        assert_eq!(None, prototypes.get_children("Missing"));
+    });
+}
@@ -0,0 +1,80 @@
+//! Tests for randomly selected children.
+
+use bevy::prelude::*;
+
+use bevy_proto::prelude::*;
+
+use common::*;
+
+mod common;
+
+/// Load a prototype named `Root` with randomly selected children.
+fn random_app(name: &str) -> (App, Handle<Prototype>) {
+    let folder = asset_folder(name);
+    let health = std::any::type_name::<Health>();
+
+    for (name, value) in [("A", 1), ("B", 2)] {
+        std::fs::write(
+            folder.join(format!("{name}.prototype.ron")),
+            format!(r#"(name: "{name}", schematics: {{ "{health}": ({value}) }})"#),
+        )
+        .unwrap();
+    }
+    std::fs::write(
+        folder.join("Root.prototype.ron"),
+        r#"(
+            name: "Root",
+            children: [
+                (OneOf: [(1.0, "A.prototype.ron"), (1.0, "B.prototype.ron")]),
+                (Range: (0, 3, "A.prototype.ron")),
+                (Chance: (0.5, (Repeat: (2, "B.prototype.ron")))),
+            ],
+        )"#,
+    )
+    .unwrap();
+
+    let mut app = app_in(&folder);
+    let handle = load(&mut app, "Root.prototype.ron", "Root");
+    (app, handle)
+}
+
+/// Returns the [`Health`] of each child of the given entity, in order.
+fn child_health(app: &App, entity: Entity) -> Vec<u32> {
+    app.world
+        .get::<Children>(entity)
+        .map(|children| {
+            children
+                .iter()
+                .map(|child| app.world.get::<Health>(*child).unwrap().0)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[test]
+fn should_spawn_same_children_for_same_seed() {
+    let (mut app, _handle) = random_app("random_seed");
+
+    let mut selections = Vec::new();
+    for seed in 0..20 {
+        let (first, second) = with_commands(&mut app, |commands| {
+            (
+                commands.spawn_seeded("Root", seed).id(),
+                commands.spawn_seeded("Root", seed).id(),
+            )
+        });
+
+        let children = child_health(&app, first);
+        assert_eq!(children, child_health(&app, second));
+
+        // The `OneOf` child always comes first
+        assert!(matches!(children.first(), Some(1 | 2)));
+        // At most 3 from the `Range` and 2 from the `Repeat`
+        assert!((1..=6).contains(&children.len()));
+
+        selections.push(children);
+    }
+
+    selections.dedup();
+    assert!(selections.len() > 1);
+}
@@ -0,0 +1,88 @@
+//! Tests for referencing prototypes by ID.
+
+use std::time::Duration;
+
+use bevy::asset::LoadState;
+use bevy::ecs::system::SystemState;
+use bevy::prelude::*;
+
+use bevy_proto::prelude::*;
+
+use common::*;
+
+mod common;
+
+/// Load the prototype at the given path and wait for its file to finish loading,
+/// without waiting for it to be registered.
+fn load_file(app: &mut App, path: &str) -> Handle<Prototype> {
+    let mut state = SystemState::<PrototypesMut>::new(&mut app.world);
+    let handle = state.get_mut(&mut app.world).load(path);
+    state.apply(&mut app.world);
+
+    for _ in 0..300 {
+        app.update();
+        if app.world.resource::<AssetServer>().get_load_state(&handle) == LoadState::Loaded {
+            settle(app);
+            return handle;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    panic!("{path:?} was never loaded");
+}
+
+#[test]
+fn should_register_template_after_its_target() {
+    let health = std::any::type_name::<Health>();
+    let folder = asset_folder("references_template");
+    std::fs::write(
+        folder.join("Goblin.prototype.ron"),
+        r#"(name: "Goblin", templates: [(Id: "Base")])"#,
+    )
+    .unwrap();
+    std::fs::write(
+        folder.join("Base.prototype.ron"),
+        format!(r#"(name: "Base", schematics: {{ "{health}": (3) }})"#),
+    )
+    .unwrap();
+    let mut app = app_in(&folder);
+
+    let _goblin = load_file(&mut app, "Goblin.prototype.ron");
+    // The template isn't loaded yet, so the prototype waits on it
+    assert!(!is_ready(&mut app, "Goblin"));
+
+    let _base = load_file(&mut app, "Base.prototype.ron");
+    wait_for(&mut app, "Goblin");
+
+    let entity = with_commands(&mut app, |commands| commands.spawn("Goblin").id());
+    assert_eq!(Some(&Health(3)), app.world.get::<Health>(entity));
+}
+
+#[test]
+fn should_register_child_after_its_target() {
+    let speed = std::any::type_name::<Speed>();
+    let folder = asset_folder("references_child");
+    std::fs::write(
+        folder.join("Camp.prototype.ron"),
+        r#"(name: "Camp", children: [(Id: "Tent"), (value: Id("Tent"))])"#,
+    )
+    .unwrap();
+    std::fs::write(
+        folder.join("Tent.prototype.ron"),
+        format!(r#"(name: "Tent", schematics: {{ "{speed}": (1) }})"#),
+    )
+    .unwrap();
+    let mut app = app_in(&folder);
+
+    let _camp = load_file(&mut app, "Camp.prototype.ron");
+    assert!(!is_ready(&mut app, "Camp"));
+
+    let _tent = load_file(&mut app, "Tent.prototype.ron");
+    wait_for(&mut app, "Camp");
+
+    let entity = with_commands(&mut app, |commands| commands.spawn("Camp").id());
+    let children = app.world.get::<Children>(entity).unwrap();
+    assert_eq!(2, children.len());
+    for child in children.iter() {
+        assert_eq!(Some(&Speed(1)), app.world.get::<Speed>(*child));
+    }
+}
@@ -0,0 +1,166 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::reflect::DynamicStruct;
+
+use bevy_proto::backend::proto::ProtoParams;
+use bevy_proto::backend::schematics::DynamicSchematic;
+use bevy_proto::prelude::*;
+
+use common::*;
+
+mod common;
+
+/// Create an app that reloads the instances of reloaded prototypes.
+fn reloading_app() -> App {
+    app_with(ProtoPlugin::new().with_instance_reloading(true))
+}
+
+/// Create a [`Transform`] patch that only sets the scale.
+fn scale_patch(scale: f32) -> DynamicStruct {
+    let mut patch = DynamicStruct::default();
+    patch.insert("scale", Vec3::splat(scale));
+    patch
+}
+
+#[test]
+fn should_only_reapply_changed_schematics() {
+    let mut app = reloading_app();
+    let handle = build(
+        &mut app,
+        PrototypeBuilder::new("A")
+            .with_schematic::<Transform>(Transform::from_xyz(1.0, 0.0, 0.0))
+            .with_schematic::<Health>(Health(1)),
+    );
+
+    let entity = with_commands(&mut app, |commands| commands.spawn("A").id());
+    app.world
+        .get_mut::<Transform>(entity)
+        .unwrap()
+        .translation
+        .x = 5.0;
+
+    app.world
+        .resource_mut::<Assets<Prototype>>()
+        .get_mut(&handle)
+        .unwrap()
+        .schematics_mut()
+        .insert::<Health>(Health(2));
+    settle(&mut app);
+
+    assert_eq!(Some(&Health(2)), app.world.get::<Health>(entity));
+    assert_eq!(
+        5.0,
+        app.world.get::<Transform>(entity).unwrap().translation.x
+    );
+}
+
+#[test]
+fn should_merge_reloaded_patches() {
+    let mut app = reloading_app();
+    let _base = build(
+        &mut app,
+        PrototypeBuilder::new("Base")
+            .with_schematic::<Transform>(Transform::from_xyz(1.0, 0.0, 0.0)),
+    );
+    let handle = build(
+        &mut app,
+        PrototypeBuilder::new("A")
+            .with_template("Base")
+            .with_dynamic_schematic(DynamicSchematic::new_patch::<Transform>(scale_patch(2.0))),
+    );
+
+    let entity = with_commands(&mut app, |commands| commands.spawn("A").id());
+    assert_eq!(
+        Vec3::splat(2.0),
+        app.world.get::<Transform>(entity).unwrap().scale
+    );
+
+    app.world
+        .resource_mut::<Assets<Prototype>>()
+        .get_mut(&handle)
+        .unwrap()
+        .schematics_mut()
+        .insert_patch::<Transform>(scale_patch(3.0));
+    settle(&mut app);
+
+    let transform = app.world.get::<Transform>(entity).unwrap();
+    assert_eq!(Vec3::splat(3.0), transform.scale);
+    assert_eq!(1.0, transform.translation.x);
+}
+
+#[test]
+fn should_not_reapply_removed_schematics() {
+    let mut app = reloading_app();
+    let _base = build(
+        &mut app,
+        PrototypeBuilder::new("Base").with_schematic::<Health>(Health(1)),
+    );
+    let handle = build(
+        &mut app,
+        PrototypeBuilder::new("A")
+            .with_template("Base")
+            .with_removed_schematic::<Health>()
+            .with_schematic::<Health>(Health(5)),
+    );
+
+    let entity = with_commands(&mut app, |commands| commands.spawn("A").id());
+    assert_eq!(Some(&Health(5)), app.world.get::<Health>(entity));
+
+    app.world
+        .resource_mut::<Assets<Prototype>>()
+        .get_mut(&handle)
+        .unwrap()
+        .schematics_mut()
+        .remove::<Health>();
+    settle(&mut app);
+
+    assert!(app.world.get::<Health>(entity).is_none());
+}
+
+#[test]
+fn should_keep_spawn_params_when_reloading() {
+    let health = std::any::type_name::<Health>();
+    let speed = std::any::type_name::<Speed>();
+    let folder = asset_folder("reload_params");
+    let path = folder.join("Base.prototype.ron");
+    let write = |default: u32, extra: &str| {
+        std::fs::write(
+            &path,
+            format!(
+                r#"(
+                    name: "Base",
+                    params: {{ "hp": {default} }},
+                    schematics: {{ "{health}": ("$hp"), {extra} }},
+                )"#
+            ),
+        )
+        .unwrap();
+    };
+
+    write(5, "");
+    let mut app = app_in_with(&folder, ProtoPlugin::new().with_instance_reloading(true));
+    let _handle = load(&mut app, "Base.prototype.ron", "Base");
+
+    let entity = with_commands(&mut app, |commands| {
+        commands
+            .spawn_with("Base", ProtoParams::new().with("hp", 7))
+            .id()
+    });
+    assert_eq!(Some(&Health(7)), app.world.get::<Health>(entity));
+
+    write(6, &format!(r#""{speed}": (2)"#));
+    app.world
+        .resource::<AssetServer>()
+        .reload_asset("Base.prototype.ron");
+    for _ in 0..300 {
+        app.update();
+        if app.world.get::<Speed>(entity).is_some() {
+            break;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    assert_eq!(Some(&Speed(2)), app.world.get::<Speed>(entity));
+    assert_eq!(Some(&Health(7)), app.world.get::<Health>(entity));
+}
@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+
+use bevy_proto::schema::generate_schema;
+
+use common::*;
+
+mod common;
+
+#[test]
+fn should_describe_registered_schematics() {
+    let app = app();
+    let schema = generate_schema(&app.world.resource::<AppTypeRegistry>().read());
+
+    let schematics = &schema["properties"]["schematics"];
+    assert_eq!(false, schematics["additionalProperties"]);
+    assert!(schematics["properties"]
+        .get(std::any::type_name::<Speed>())
+        .is_some());
+
+    let health = &schematics["properties"][std::any::type_name::<Health>()];
+    let key = health["$ref"]
+        .as_str()
+        .and_then(|reference| reference.strip_prefix("#/definitions/"))
+        .unwrap();
+
+    let definition = &schema["definitions"][key];
+    assert_eq!("array", definition["type"]);
+    assert_eq!(1, definition["minItems"]);
+    assert_eq!(1, definition["maxItems"]);
+
+    let field = &definition["items"][0]["anyOf"];
+    assert_eq!("integer", field[0]["type"]);
+    assert_eq!("#/definitions/ParamReference", field[1]["$ref"]);
+}
@@ -0,0 +1,130 @@
+use std::path::Path;
+
+use bevy::prelude::*;
+
+use bevy_proto::backend::children::PrototypicalChild;
+use bevy_proto::backend::proto::ProtoParams;
+use bevy_proto::prelude::*;
+use bevy_proto::ser::PrototypeSerializer;
+
+use common::*;
+
+mod common;
+
+#[test]
+fn should_round_trip_through_loader() {
+    let folder = asset_folder("round_trip");
+    std::fs::write(folder.join("Base.prototype.ron"), r#"(name: "Base")"#).unwrap();
+    std::fs::write(folder.join("Child.prototype.ron"), r#"(name: "Child")"#).unwrap();
+
+    let mut app = app_in(&folder);
+    let _base = load(&mut app, "Base.prototype.ron", "Base");
+    let _child = load(&mut app, "Child.prototype.ron", "Child");
+
+    let original = build(
+        &mut app,
+        PrototypeBuilder::new("Root")
+            .with_schematic::<Speed>(Speed(3))
+            .with_template_path("Base.prototype.ron")
+            .with_child_path("Child.prototype.ron")
+            .with_child(
+                PrototypeBuilder::new("Inline")
+                    .with_requires_entity(false)
+                    .with_schematic::<Health>(Health(10)),
+            ),
+    );
+
+    let output = {
+        let registry = app.world.resource::<AppTypeRegistry>().read();
+        let prototypes = app.world.resource::<Assets<Prototype>>();
+        PrototypeSerializer::new(prototypes.get(&original).unwrap(), &registry)
+            .with_prototypes(prototypes)
+            .to_ron()
+            .unwrap()
+    };
+
+    // Free the original so the serialized copy can be registered under the same ID
+    drop(original);
+    settle(&mut app);
+
+    std::fs::write(folder.join("Root.prototype.ron"), output).unwrap();
+    let handle = load(&mut app, "Root.prototype.ron", "Root");
+
+    let prototypes = app.world.resource::<Assets<Prototype>>();
+    let prototype = prototypes.get(&handle).unwrap();
+    assert!(prototype.requires_entity());
+    assert!(prototype.schematics().contains::<Speed>());
+
+    let templates = prototype.templates().unwrap();
+    assert_eq!(1, templates.len());
+    let (template, _) = templates.iter().next().unwrap();
+    assert_eq!(Path::new("Base.prototype.ron"), template.path());
+
+    let children = prototype.children().unwrap().iter().collect::<Vec<_>>();
+    assert_eq!(2, children.len());
+    assert_eq!(
+        Path::new("Child.prototype.ron"),
+        children[0].path().unwrap().path()
+    );
+    assert!(children[1].path().is_none());
+
+    let inline = prototypes.get(children[1].handle()).unwrap();
+    assert_eq!("Inline", inline.id());
+    assert!(!inline.requires_entity());
+    assert!(inline.schematics().contains::<Health>());
+}
+
+/// Serialize the given prototype as RON.
+fn to_ron(app: &App, handle: &Handle<Prototype>) -> Result<String, PrototypeError> {
+    let registry = app.world.resource::<AppTypeRegistry>().read();
+    let prototypes = app.world.resource::<Assets<Prototype>>();
+    PrototypeSerializer::new(prototypes.get(handle).unwrap(), &registry).to_ron()
+}
+
+#[test]
+fn should_reject_loaded_params() {
+    let health = std::any::type_name::<Health>();
+    let folder = asset_folder("serialize_params");
+    std::fs::write(
+        folder.join("Base.prototype.ron"),
+        format!(
+            r#"(
+                name: "Base",
+                params: {{ "hp": 5 }},
+                schematics: {{ "{health}": ("$hp") }},
+            )"#
+        ),
+    )
+    .unwrap();
+
+    let mut app = app_in(&folder);
+    let handle = load(&mut app, "Base.prototype.ron", "Base");
+
+    assert!(to_ron(&app, &handle).is_err());
+}
+
+#[test]
+fn should_keep_declared_params() {
+    let folder = asset_folder("serialize_declared_params");
+    let mut app = app_in(&folder);
+
+    let original = build(
+        &mut app,
+        PrototypeBuilder::new("Built")
+            .with_params(ProtoParams::new().with("hp", 5))
+            .with_schematic::<Health>(Health(5)),
+    );
+    let output = to_ron(&app, &original).unwrap();
+
+    // Free the original so the serialized copy can be registered under the same ID
+    drop(original);
+    settle(&mut app);
+
+    std::fs::write(folder.join("Built.prototype.ron"), output).unwrap();
+    let handle = load(&mut app, "Built.prototype.ron", "Built");
+
+    let prototypes = app.world.resource::<Assets<Prototype>>();
+    let prototype = prototypes.get(&handle).unwrap();
+    assert_eq!(Some(&ProtoParams::new().with("hp", 5)), prototype.params());
+    assert!(prototype.schematics().contains::<Health>());
+}
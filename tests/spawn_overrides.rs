@@ -0,0 +1,93 @@
+//! Tests for overriding schematics when inserting a prototype.
+
+use bevy::prelude::*;
+use bevy::reflect::DynamicStruct;
+
+use bevy_proto::backend::schematics::{DynamicSchematic, Schematics};
+use bevy_proto::prelude::*;
+
+use common::*;
+
+mod common;
+
+/// Spawn the prototype with the given ID using the given overrides.
+fn spawn_with_overrides(app: &mut App, id: &str, overrides: Schematics) -> Entity {
+    with_commands(app, |commands| {
+        commands
+            .spawn_empty()
+            .insert_with_overrides(id, overrides)
+            .id()
+    })
+}
+
+#[test]
+fn should_only_override_root() {
+    let mut app = app();
+    let _handle = build(
+        &mut app,
+        PrototypeBuilder::new("Root")
+            .with_schematic::<Health>(Health(1))
+            .with_child(PrototypeBuilder::new("Child").with_schematic::<Health>(Health(2))),
+    );
+
+    let mut overrides = Schematics::default();
+    overrides.insert::<Health>(Health(9));
+    let entity = spawn_with_overrides(&mut app, "Root", overrides);
+
+    assert_eq!(Some(&Health(9)), app.world.get::<Health>(entity));
+    let children = app.world.get::<Children>(entity).unwrap();
+    assert_eq!(Some(&Health(2)), app.world.get::<Health>(children[0]));
+}
+
+#[test]
+fn should_patch_inherited_schematics() {
+    let mut app = app();
+    let _handles = [
+        build(
+            &mut app,
+            PrototypeBuilder::new("Base")
+                .with_schematic::<Transform>(Transform::from_xyz(1.0, 2.0, 3.0)),
+        ),
+        build(&mut app, PrototypeBuilder::new("A").with_template("Base")),
+    ];
+
+    let mut translation = DynamicStruct::default();
+    translation.insert("y", 5.0_f32);
+    let mut patch = DynamicStruct::default();
+    patch.insert("translation", translation);
+
+    let mut overrides = Schematics::default();
+    overrides.insert_dynamic(DynamicSchematic::new_patch::<Transform>(patch));
+    let entity = spawn_with_overrides(&mut app, "A", overrides);
+
+    let transform = app.world.get::<Transform>(entity).unwrap();
+    assert_eq!(Vec3::new(1.0, 5.0, 3.0), transform.translation);
+}
+
+#[test]
+fn should_override_removed_schematics() {
+    let mut app = app();
+    let _handles = [
+        build(
+            &mut app,
+            PrototypeBuilder::new("Base").with_schematic::<Health>(Health(1)),
+        ),
+        build(
+            &mut app,
+            PrototypeBuilder::new("A")
+                .with_template("Base")
+                .with_removed_schematic::<Health>(),
+        ),
+    ];
+
+    let mut overrides = Schematics::default();
+    overrides.insert::<Health>(Health(9));
+    let overridden = spawn_with_overrides(&mut app, "A", overrides);
+    assert_eq!(Some(&Health(9)), app.world.get::<Health>(overridden));
+
+    // Removed schematics are not used as a base, so patches are applied as-is
+    let mut overrides = Schematics::default();
+    overrides.insert_patch::<Health>(Health(4));
+    let patched = spawn_with_overrides(&mut app, "A", overrides);
+    assert_eq!(Some(&Health(4)), app.world.get::<Health>(patched));
+}
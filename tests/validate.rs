@@ -0,0 +1,38 @@
+use bevy_proto::validate::{ProtoValidator, ValidationIssue};
+
+#[test]
+fn should_report_cycles_in_examples() {
+    let report = ProtoValidator::new("assets", "examples/cycles").validate();
+
+    assert_eq!(0, report.registered);
+    assert!(!report.issues.is_empty());
+    for issue in &report.issues {
+        let ValidationIssue::Cycle { ids, .. } = issue else {
+            panic!("expected only cycles, found {issue}");
+        };
+        assert!(ids
+            .iter()
+            .all(|id| ["CycleA", "CycleB", "CycleC"].contains(&id.as_str())));
+    }
+}
+
+#[test]
+fn should_validate_examples() {
+    let report = ProtoValidator::new("assets", "examples").validate();
+
+    // Every example is loaded, even though their custom schematics aren't registered here
+    assert!(report.registered > 0);
+
+    let cycles = report
+        .issues
+        .iter()
+        .filter(|issue| matches!(issue, ValidationIssue::Cycle { .. }))
+        .count();
+    assert!(cycles > 0);
+
+    // The cycles are the only reason the cycle examples fail to register
+    assert!(!report.issues.iter().any(|issue| matches!(
+        issue,
+        ValidationIssue::NotRegistered { id, .. } if id.starts_with("Cycle")
+    )));
+}
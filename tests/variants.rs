@@ -0,0 +1,84 @@
+//! Tests for prototype variants.
+
+use bevy::prelude::*;
+
+use bevy_proto::backend::proto::ProtoParams;
+use bevy_proto::prelude::*;
+
+use common::*;
+
+mod common;
+
+/// Load a prototype named `Slime` with several variants.
+///
+/// Each variant sets a different [`Health`] and uses the `speed` parameter for its [`Speed`].
+fn variant_app(name: &str) -> (App, Handle<Prototype>) {
+    let folder = asset_folder(name);
+    let health = std::any::type_name::<Health>();
+    let speed = std::any::type_name::<Speed>();
+
+    let variants = (1..=4)
+        .map(|value| format!(r#"{{ "{health}": ({value}), "{speed}": ("$speed") }}"#))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    std::fs::write(
+        folder.join("Slime.prototype.ron"),
+        format!(
+            r#"(
+                name: "Slime",
+                params: {{ "speed": 3 }},
+                schematics: {{ "{health}": (0) }},
+                variants: [{variants}],
+            )"#
+        ),
+    )
+    .unwrap();
+
+    let mut app = app_in(&folder);
+    let handle = load(&mut app, "Slime.prototype.ron", "Slime");
+    (app, handle)
+}
+
+#[test]
+fn should_select_same_variant_for_same_seed() {
+    let (mut app, _handle) = variant_app("variants_seed");
+
+    let mut selected = Vec::new();
+    for seed in 0..20 {
+        let (first, second) = with_commands(&mut app, |commands| {
+            (
+                commands.spawn_seeded("Slime", seed).id(),
+                commands.spawn_seeded("Slime", seed).id(),
+            )
+        });
+
+        let health = app.world.get::<Health>(first).cloned().unwrap();
+        assert_eq!(Some(&health), app.world.get::<Health>(second));
+        // A variant is always applied on top of the regular schematics
+        assert!((1..=4).contains(&health.0));
+
+        if !selected.contains(&health) {
+            selected.push(health);
+        }
+    }
+
+    assert!(selected.len() > 1);
+}
+
+#[test]
+fn should_substitute_params_in_variants() {
+    let (mut app, _handle) = variant_app("variants_params");
+
+    let (default, overridden) = with_commands(&mut app, |commands| {
+        (
+            commands.spawn_seeded("Slime", 7).id(),
+            commands
+                .spawn_with("Slime", ProtoParams::new().with("speed", 8))
+                .id(),
+        )
+    });
+
+    assert_eq!(Some(&Speed(3)), app.world.get::<Speed>(default));
+    assert_eq!(Some(&Speed(8)), app.world.get::<Speed>(overridden));
+}